@@ -0,0 +1,427 @@
+//! Derive macros of `openai_rust` generating the JSON Schema and the function
+//! definition of a Rust type. Use them through `openai_rust::openai::chat`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Expr, ExprLit, Fields, FieldsNamed, Lit, LitStr, Meta, Token, Type};
+use syn::meta::ParseNestedMeta;
+
+/// Implement `ToJsonSchema` for a struct with named fields, a newtype struct or
+/// an enum with unit variants only.
+#[proc_macro_derive(ToJsonSchema, attributes(openai))]
+pub fn derive_to_json_schema(input: TokenStream) -> TokenStream {
+  let input: DeriveInput = syn::parse_macro_input!(input as DeriveInput);
+  to_json_schema_impl(&input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+/// Implement `OpenAiFunction`, and `ToJsonSchema`, for a struct with named fields
+/// holding the arguments of the function.
+#[proc_macro_derive(OpenAiFunction, attributes(openai))]
+pub fn derive_openai_function(input: TokenStream) -> TokenStream {
+  let input: DeriveInput = syn::parse_macro_input!(input as DeriveInput);
+  openai_function_impl(&input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+fn to_json_schema_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+  let ident = &input.ident;
+  let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+  let container: ContainerAttributes = ContainerAttributes::parse(&input.attrs)?;
+  let description: String = doc_comment(&input.attrs);
+  let schema: TokenStream2 = match &input.data {
+    Data::Struct(data) => match &data.fields {
+      Fields::Named(fields) => object_schema(fields, &container)?,
+      Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+        let field_type: &Type = &fields.unnamed[0].ty;
+        quote! { <#field_type as ::openai_rust::openai::chat::ToJsonSchema>::json_schema() }
+      },
+      _ => return Err(syn::Error::new_spanned(ident, "ToJsonSchema can only be derived for structs with named fields or a single unnamed field")),
+    },
+    Data::Enum(data) => {
+      let mut values: Vec<String> = Vec::new();
+      for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+          return Err(syn::Error::new_spanned(variant, "ToJsonSchema can only be derived for enums with unit variants"));
+        }
+        let attributes: FieldAttributes = FieldAttributes::parse(&variant.attrs)?;
+        if attributes.skip {
+          continue;
+        }
+        values.push(attributes.rename.unwrap_or_else(|| rename_variant(&variant.ident.to_string(), container.rename_all.as_deref())));
+      }
+      quote! { ::openai_rust::openai::chat::model::JsonSchema::enumeration(&[#(#values),*]) }
+    },
+    Data::Union(_) => return Err(syn::Error::new_spanned(ident, "ToJsonSchema cannot be derived for unions")),
+  };
+  let description: TokenStream2 = description_call(&description);
+  Ok(quote! {
+    impl #impl_generics ::openai_rust::openai::chat::ToJsonSchema for #ident #type_generics #where_clause {
+      fn json_schema() -> ::openai_rust::openai::chat::model::JsonSchema {
+        #schema #description
+      }
+    }
+  })
+}
+
+fn openai_function_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+  let ident = &input.ident;
+  if !matches!(&input.data, Data::Struct(data) if matches!(data.fields, Fields::Named(_))) {
+    return Err(syn::Error::new_spanned(ident, "OpenAiFunction can only be derived for structs with named fields"));
+  }
+  let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+  let container: ContainerAttributes = ContainerAttributes::parse(&input.attrs)?;
+  let name: String = container.name.clone().unwrap_or_else(|| function_name(&ident.to_string()));
+  let description: TokenStream2 = description_call(&doc_comment(&input.attrs));
+  let strict: TokenStream2 = if container.strict {
+    quote! { .strict(true) }
+  } else {
+    quote! {}
+  };
+  let to_json_schema: TokenStream2 = to_json_schema_impl(input)?;
+  Ok(quote! {
+    #to_json_schema
+
+    impl #impl_generics ::openai_rust::openai::chat::OpenAiFunction for #ident #type_generics #where_clause {
+      const NAME: &'static str = #name;
+
+      fn function_definition() -> ::openai_rust::openai::chat::model::FunctionDefinition {
+        // The description of the function is not repeated in its parameters
+        let mut parameters: ::openai_rust::openai::chat::model::JsonSchema = <Self as ::openai_rust::openai::chat::ToJsonSchema>::json_schema();
+        parameters.description = None;
+        ::openai_rust::openai::chat::model::FunctionDefinition::new(Self::NAME)
+          #description
+          .parameters(parameters)
+          #strict
+      }
+    }
+  })
+}
+
+fn object_schema(fields: &FieldsNamed, container: &ContainerAttributes) -> syn::Result<TokenStream2> {
+  let mut properties: Vec<TokenStream2> = Vec::new();
+  for field in &fields.named {
+    let attributes: FieldAttributes = FieldAttributes::parse(&field.attrs)?;
+    if attributes.skip {
+      continue;
+    }
+    let field_name: String = field.ident.as_ref().map(|ident| ident.to_string()).unwrap_or_default();
+    let field_name: String = field_name.strip_prefix("r#").map(String::from).unwrap_or(field_name);
+    let name: String = attributes.rename.clone().unwrap_or_else(|| rename_field(&field_name, container.rename_all.as_deref()));
+    let field_type: &Type = &field.ty;
+    let description: TokenStream2 = description_call(&doc_comment(&field.attrs));
+    let bounds: TokenStream2 = bounds_calls(&attributes);
+    let schema: TokenStream2 = quote! {
+      <#field_type as ::openai_rust::openai::chat::ToJsonSchema>::json_schema() #description #bounds
+    };
+    // A property is optional when serde can deserialize the arguments without it,
+    // but strict mode requires every property, the optional values being nullable
+    if !container.strict && (attributes.default || container.default || is_option(field_type)) {
+      properties.push(quote! { .property(#name, #schema) });
+    } else {
+      properties.push(quote! { .required_property(#name, #schema) });
+    }
+  }
+  Ok(quote! {
+    ::openai_rust::openai::chat::model::JsonSchema::object()
+      #(#properties)*
+      .additional_properties(false)
+  })
+}
+
+fn description_call(description: &str) -> TokenStream2 {
+  if description.is_empty() {
+    quote! {}
+  } else {
+    quote! { .description(#description) }
+  }
+}
+
+fn bounds_calls(attributes: &FieldAttributes) -> TokenStream2 {
+  let minimum: Option<TokenStream2> = attributes.minimum.map(|minimum| quote! { .minimum(#minimum) });
+  let maximum: Option<TokenStream2> = attributes.maximum.map(|maximum| quote! { .maximum(#maximum) });
+  quote! { #minimum #maximum }
+}
+
+fn is_option(field_type: &Type) -> bool {
+  match field_type {
+    Type::Path(type_path) => type_path.qself.is_none() && type_path.path.segments.last().is_some_and(|segment| segment.ident == "Option"),
+    _ => false,
+  }
+}
+
+/// Join the lines of the doc comments, keeping the paragraphs separated by an
+/// empty line.
+fn doc_comment(attrs: &[Attribute]) -> String {
+  let lines: Vec<String> = attrs.iter()
+    .filter(|attr| attr.path().is_ident("doc"))
+    .filter_map(|attr| match &attr.meta {
+      Meta::NameValue(name_value) => match &name_value.value {
+        Expr::Lit(ExprLit {lit: Lit::Str(doc), ..}) => Some(doc.value().trim().to_string()),
+        _ => None,
+      },
+      _ => None,
+    })
+    .collect();
+  let mut paragraphs: Vec<String> = Vec::new();
+  let mut paragraph: Vec<String> = Vec::new();
+  for line in lines {
+    if line.is_empty() {
+      if !paragraph.is_empty() {
+        paragraphs.push(paragraph.join(" "));
+        paragraph.clear();
+      }
+    } else {
+      paragraph.push(line);
+    }
+  }
+  if !paragraph.is_empty() {
+    paragraphs.push(paragraph.join(" "));
+  }
+  paragraphs.join("\n\n")
+}
+
+/// Rename a field the way `#[serde(rename_all = "...")]` does, assuming it is
+/// in snake case like serde.
+fn rename_field(name: &str, rename_all: Option<&str>) -> String {
+  match rename_all {
+    Some("UPPERCASE") | Some("SCREAMING_SNAKE_CASE") => name.to_ascii_uppercase(),
+    Some("PascalCase") => {
+      let mut pascal: String = String::new();
+      let mut capitalize: bool = true;
+      for character in name.chars() {
+        if character == '_' {
+          capitalize = true;
+        } else if capitalize {
+          pascal.push(character.to_ascii_uppercase());
+          capitalize = false;
+        } else {
+          pascal.push(character);
+        }
+      }
+      pascal
+    },
+    Some("camelCase") => lowercase_first(&rename_field(name, Some("PascalCase"))),
+    Some("kebab-case") => name.replace('_', "-"),
+    Some("SCREAMING-KEBAB-CASE") => name.to_ascii_uppercase().replace('_', "-"),
+    _ => name.to_string(),
+  }
+}
+
+/// Rename a variant the way `#[serde(rename_all = "...")]` does, assuming it is
+/// in pascal case like serde. Every uppercase character starts a new word, e.g.
+/// `HTTPRequest` is `h_t_t_p_request` in snake case.
+fn rename_variant(name: &str, rename_all: Option<&str>) -> String {
+  match rename_all {
+    Some("lowercase") => name.to_ascii_lowercase(),
+    Some("UPPERCASE") => name.to_ascii_uppercase(),
+    Some("camelCase") => lowercase_first(name),
+    Some("snake_case") => {
+      let mut snake: String = String::new();
+      for (index, character) in name.char_indices() {
+        if index > 0 && character.is_uppercase() {
+          snake.push('_');
+        }
+        snake.push(character.to_ascii_lowercase());
+      }
+      snake
+    },
+    Some("SCREAMING_SNAKE_CASE") => rename_variant(name, Some("snake_case")).to_ascii_uppercase(),
+    Some("kebab-case") => rename_variant(name, Some("snake_case")).replace('_', "-"),
+    Some("SCREAMING-KEBAB-CASE") => rename_variant(name, Some("SCREAMING_SNAKE_CASE")).replace('_', "-"),
+    _ => name.to_string(),
+  }
+}
+
+/// Return the name of the function of a struct in snake case. Unlike serde, a
+/// run of uppercase characters is a single word, e.g. `SendHTTPRequest` is
+/// `send_http_request`.
+fn function_name(name: &str) -> String {
+  let characters: Vec<char> = name.chars().collect();
+  let mut snake: String = String::new();
+  for (index, character) in characters.iter().enumerate() {
+    if index > 0 && character.is_uppercase() {
+      let previous: char = characters[index - 1];
+      let next_is_lowercase: bool = characters.get(index + 1).is_some_and(|next| next.is_lowercase());
+      if !previous.is_uppercase() || next_is_lowercase {
+        snake.push('_');
+      }
+    }
+    snake.push(character.to_ascii_lowercase());
+  }
+  snake
+}
+
+fn lowercase_first(name: &str) -> String {
+  let mut characters = name.chars();
+  match characters.next() {
+    Some(first) => first.to_ascii_lowercase().to_string() + characters.as_str(),
+    None => String::new(),
+  }
+}
+
+/// Attributes of the struct or enum: `#[serde(rename_all = "...", default)]` and
+/// `#[openai(name = "...", strict)]`.
+#[derive(Default)]
+struct ContainerAttributes {
+  name: Option<String>,
+  strict: bool,
+  rename_all: Option<String>,
+  default: bool,
+}
+
+impl ContainerAttributes {
+  fn parse(attrs: &[Attribute]) -> syn::Result<ContainerAttributes> {
+    let mut attributes: ContainerAttributes = ContainerAttributes::default();
+    for attr in attrs {
+      if attr.path().is_ident("serde") {
+        attr.parse_nested_meta(|meta| {
+          if meta.path.is_ident("rename_all") {
+            attributes.rename_all = Some(meta.value()?.parse::<LitStr>()?.value());
+          } else if meta.path.is_ident("default") {
+            attributes.default = true;
+            skip_meta_value(&meta)?;
+          } else {
+            skip_meta_value(&meta)?;
+          }
+          Ok(())
+        })?;
+      } else if attr.path().is_ident("openai") {
+        attr.parse_nested_meta(|meta| {
+          if meta.path.is_ident("name") {
+            attributes.name = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
+          } else if meta.path.is_ident("strict") {
+            attributes.strict = true;
+            Ok(())
+          } else {
+            Err(meta.error("unsupported openai attribute, expected `name` or `strict`"))
+          }
+        })?;
+      }
+    }
+    Ok(attributes)
+  }
+}
+
+/// Attributes of a field or a variant: `#[serde(rename = "...", default, skip)]`
+/// and `#[openai(minimum = ..., maximum = ...)]`.
+#[derive(Default)]
+struct FieldAttributes {
+  rename: Option<String>,
+  default: bool,
+  skip: bool,
+  minimum: Option<f64>,
+  maximum: Option<f64>,
+}
+
+impl FieldAttributes {
+  fn parse(attrs: &[Attribute]) -> syn::Result<FieldAttributes> {
+    let mut attributes: FieldAttributes = FieldAttributes::default();
+    for attr in attrs {
+      if attr.path().is_ident("serde") {
+        attr.parse_nested_meta(|meta| {
+          if meta.path.is_ident("rename") && meta.input.peek(Token![=]) {
+            attributes.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+          } else if meta.path.is_ident("default") {
+            attributes.default = true;
+            skip_meta_value(&meta)?;
+          } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_deserializing") {
+            attributes.skip = true;
+          } else {
+            skip_meta_value(&meta)?;
+          }
+          Ok(())
+        })?;
+      } else if attr.path().is_ident("openai") {
+        attr.parse_nested_meta(|meta| {
+          if meta.path.is_ident("minimum") {
+            attributes.minimum = Some(parse_number(&meta)?);
+            Ok(())
+          } else if meta.path.is_ident("maximum") {
+            attributes.maximum = Some(parse_number(&meta)?);
+            Ok(())
+          } else {
+            Err(meta.error("unsupported openai attribute, expected `minimum` or `maximum`"))
+          }
+        })?;
+      }
+    }
+    Ok(attributes)
+  }
+}
+
+fn parse_number(meta: &ParseNestedMeta) -> syn::Result<f64> {
+  let value = meta.value()?;
+  let negative: bool = value.parse::<Option<Token![-]>>()?.is_some();
+  let number: f64 = match value.parse::<Lit>()? {
+    Lit::Int(int) => int.base10_parse::<f64>()?,
+    Lit::Float(float) => float.base10_parse::<f64>()?,
+    lit => return Err(syn::Error::new_spanned(lit, "expected a number")),
+  };
+  Ok(if negative { -number } else { number })
+}
+
+/// Consume the value of a serde attribute that does not change the schema, e.g.
+/// `with = "..."` or `rename(serialize = "...")`.
+fn skip_meta_value(meta: &ParseNestedMeta) -> syn::Result<()> {
+  if meta.input.peek(Token![=]) {
+    meta.value()?.parse::<Expr>()?;
+  } else if !meta.input.is_empty() && !meta.input.peek(Token![,]) {
+    meta.input.parse::<proc_macro2::TokenTree>()?;
+  }
+  Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_rename_the_fields_like_serde() {
+    assert_eq!(rename_field("max_results", Some("camelCase")), "maxResults");
+    assert_eq!(rename_field("max_results", Some("PascalCase")), "MaxResults");
+    assert_eq!(rename_field("max_results", Some("lowercase")), "max_results");
+    assert_eq!(rename_field("max_results", Some("SCREAMING-KEBAB-CASE")), "MAX-RESULTS");
+    assert_eq!(rename_field("http_2_request", Some("camelCase")), "http2Request");
+    assert_eq!(rename_field("max_results", None), "max_results");
+  }
+
+  #[test]
+  fn it_should_rename_the_variants_like_serde() {
+    assert_eq!(rename_variant("GetWeather", Some("snake_case")), "get_weather");
+    assert_eq!(rename_variant("HTTPRequest", Some("snake_case")), "h_t_t_p_request");
+    assert_eq!(rename_variant("HTTPRequest", Some("camelCase")), "hTTPRequest");
+    assert_eq!(rename_variant("DeepSea", Some("lowercase")), "deepsea");
+    assert_eq!(rename_variant("DeepSea", Some("SCREAMING-KEBAB-CASE")), "DEEP-SEA");
+    assert_eq!(rename_variant("DeepSea", None), "DeepSea");
+  }
+
+  #[test]
+  fn it_should_name_the_functions_in_snake_case() {
+    assert_eq!(function_name("GetWeather"), "get_weather");
+    assert_eq!(function_name("SendHTTPRequest"), "send_http_request");
+    assert_eq!(function_name("HTTPRequest"), "http_request");
+    assert_eq!(function_name("ParseURL"), "parse_url");
+    assert_eq!(function_name("Http2Request"), "http2_request");
+  }
+
+  #[test]
+  fn it_should_join_the_lines_of_the_doc_comments() {
+    let input: DeriveInput = syn::parse_quote! {
+      /// Get the current weather
+      /// of a city.
+      ///
+      /// The temperature is in celsius.
+      struct GetWeather;
+    };
+    assert_eq!(doc_comment(&input.attrs), "Get the current weather of a city.\n\nThe temperature is in celsius.");
+  }
+}
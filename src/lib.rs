@@ -1,2 +1,5 @@
+// The derive macros refer to the items of the crate with absolute paths
+extern crate self as openai_rust;
+
 pub mod openai;
 // pub use openai::*;
\ No newline at end of file
@@ -15,7 +15,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     .max_tokens(5000)
     .stream(false)
     .messages(chat_messages)
-    .create(openai_client, "gpt-4-8k", Some("2023-08-01-preview")).await?;
+    .create(&openai_client, "gpt-4-8k", Some("2023-08-01-preview")).await?;
   println!("{:?}", chat_completion_response);
   Ok(())
 } 
\ No newline at end of file
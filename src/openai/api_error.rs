@@ -0,0 +1,229 @@
+use serde::Deserialize;
+
+/// Kind of the error returned by the API, derived from the error code, the
+/// error type and the HTTP status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+  Authentication,
+  ContentFilter,
+  ContextLengthExceeded,
+  InsufficientQuota,
+  InvalidRequest,
+  NotFound,
+  PermissionDenied,
+  RateLimitExceeded,
+  RequestTimeout,
+  ServerError,
+  Unknown,
+}
+
+/// Error returned by OpenAI or Azure OpenAI API. The fields are parsed from the
+/// error envelope `{"error": {"message", "type", "param", "code", "innererror"}}`.
+/// When the body is not an error envelope, the message is the raw body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+  pub status: u16,
+  pub kind: ErrorKind,
+  pub message: String,
+  pub error_type: Option<String>,
+  pub code: Option<String>,
+  pub param: Option<String>,
+  pub inner_error: Option<InnerError>,
+  pub attempts: u32,
+}
+
+/// Inner error sent by Azure OpenAI, e.g. when the prompt is filtered.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct InnerError {
+  pub code: Option<String>,
+  pub content_filter_result: Option<ContentFilterResults>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ContentFilterResults {
+  pub hate: Option<ContentFilterSeverityResult>,
+  pub self_harm: Option<ContentFilterSeverityResult>,
+  pub sexual: Option<ContentFilterSeverityResult>,
+  pub violence: Option<ContentFilterSeverityResult>,
+  pub jailbreak: Option<ContentFilterDetectedResult>,
+  pub profanity: Option<ContentFilterDetectedResult>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ContentFilterSeverityResult {
+  pub filtered: bool,
+  pub severity: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ContentFilterDetectedResult {
+  pub filtered: bool,
+  pub detected: bool,
+}
+
+#[derive(Deserialize)]
+struct ErrorEnvelope {
+  error: ErrorBody,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+  message: Option<String>,
+  #[serde(rename = "type")]
+  error_type: Option<String>,
+  param: Option<String>,
+  // Azure OpenAI may send the code as a number
+  code: Option<serde_json::Value>,
+  #[serde(rename = "innererror")]
+  inner_error: Option<InnerError>,
+}
+
+impl ApiError {
+  /// Parse the error from the status and the body of the response.
+  pub fn from_response(status: u16, body: &str, attempts: u32) -> ApiError {
+    let mut api_error = ApiError {
+      status,
+      kind: ErrorKind::Unknown,
+      message: body.to_string(),
+      error_type: None,
+      code: None,
+      param: None,
+      inner_error: None,
+      attempts,
+    };
+    if let Ok(envelope) = serde_json::from_str::<ErrorEnvelope>(body) {
+      let error: ErrorBody = envelope.error;
+      api_error.message = error.message.unwrap_or_default();
+      api_error.error_type = error.error_type;
+      api_error.param = error.param;
+      api_error.code = match error.code {
+        Some(serde_json::Value::String(code)) => Some(code),
+        Some(serde_json::Value::Number(code)) => Some(code.to_string()),
+        _ => None,
+      };
+      api_error.inner_error = error.inner_error;
+    }
+    api_error.kind = api_error.derive_kind();
+    api_error
+  }
+
+  fn derive_kind(&self) -> ErrorKind {
+    let matches = |value: &str| self.code.as_deref() == Some(value) || self.error_type.as_deref() == Some(value);
+    let inner_code: Option<&str> = self.inner_error.as_ref().and_then(|inner_error| inner_error.code.as_deref());
+    if matches("context_length_exceeded") {
+      ErrorKind::ContextLengthExceeded
+    } else if matches("insufficient_quota") {
+      ErrorKind::InsufficientQuota
+    } else if matches("content_filter") || inner_code == Some("ResponsibleAIPolicyViolation") {
+      ErrorKind::ContentFilter
+    } else if matches("rate_limit_exceeded") {
+      ErrorKind::RateLimitExceeded
+    } else {
+      match self.status {
+        400 | 422 => ErrorKind::InvalidRequest,
+        401 => ErrorKind::Authentication,
+        403 => ErrorKind::PermissionDenied,
+        404 => ErrorKind::NotFound,
+        408 => ErrorKind::RequestTimeout,
+        429 => ErrorKind::RateLimitExceeded,
+        500..=599 => ErrorKind::ServerError,
+        _ => ErrorKind::Unknown,
+      }
+    }
+  }
+
+  /// Return true if the same request may succeed when it is sent again.
+  pub fn is_retriable(&self) -> bool {
+    matches!(self.kind, ErrorKind::RateLimitExceeded | ErrorKind::RequestTimeout | ErrorKind::ServerError)
+  }
+
+  pub fn is_context_length_exceeded(&self) -> bool {
+    self.kind == ErrorKind::ContextLengthExceeded
+  }
+
+  pub fn is_rate_limit_exceeded(&self) -> bool {
+    self.kind == ErrorKind::RateLimitExceeded
+  }
+
+  pub fn is_insufficient_quota(&self) -> bool {
+    self.kind == ErrorKind::InsufficientQuota
+  }
+
+  pub fn is_content_filtered(&self) -> bool {
+    self.kind == ErrorKind::ContentFilter
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_parse_openai_context_length_exceeded_error() {
+    let body: &str = r#"{"error": {"message": "This model's maximum context length is 8192 tokens.", "type": "invalid_request_error", "param": "messages", "code": "context_length_exceeded"}}"#;
+
+    let api_error: ApiError = ApiError::from_response(400, body, 1);
+    assert_eq!(api_error.kind, ErrorKind::ContextLengthExceeded);
+    assert_eq!(api_error.message, "This model's maximum context length is 8192 tokens.");
+    assert_eq!(api_error.error_type, Some(String::from("invalid_request_error")));
+    assert_eq!(api_error.param, Some(String::from("messages")));
+    assert_eq!(api_error.code, Some(String::from("context_length_exceeded")));
+    assert!(api_error.is_context_length_exceeded());
+    assert!(!api_error.is_retriable());
+  }
+
+  #[test]
+  fn it_should_parse_openai_rate_limit_exceeded_error() {
+    let body: &str = r#"{"error": {"message": "Rate limit reached", "type": "requests", "param": null, "code": "rate_limit_exceeded"}}"#;
+
+    let api_error: ApiError = ApiError::from_response(429, body, 3);
+    assert!(api_error.is_rate_limit_exceeded());
+    assert!(api_error.is_retriable());
+    assert_eq!(api_error.attempts, 3);
+  }
+
+  #[test]
+  fn it_should_parse_openai_insufficient_quota_error_as_not_retriable() {
+    let body: &str = r#"{"error": {"message": "You exceeded your current quota", "type": "insufficient_quota", "param": null, "code": "insufficient_quota"}}"#;
+
+    let api_error: ApiError = ApiError::from_response(429, body, 1);
+    assert!(api_error.is_insufficient_quota());
+    assert!(!api_error.is_retriable());
+  }
+
+  #[test]
+  fn it_should_parse_azure_content_filter_error() {
+    let body: &str = r#"{"error": {"message": "The response was filtered", "type": null, "param": "prompt", "code": "content_filter", "status": 400, "innererror": {"code": "ResponsibleAIPolicyViolation", "content_filter_result": {"hate": {"filtered": false, "severity": "safe"}, "jailbreak": {"filtered": true, "detected": true}, "violence": {"filtered": true, "severity": "medium"}}}}}"#;
+
+    let api_error: ApiError = ApiError::from_response(400, body, 1);
+    assert!(api_error.is_content_filtered());
+    let inner_error: InnerError = api_error.inner_error.unwrap();
+    assert_eq!(inner_error.code, Some(String::from("ResponsibleAIPolicyViolation")));
+    let content_filter_result: ContentFilterResults = inner_error.content_filter_result.unwrap();
+    assert_eq!(content_filter_result.jailbreak, Some(ContentFilterDetectedResult {filtered: true, detected: true}));
+    assert_eq!(content_filter_result.violence, Some(ContentFilterSeverityResult {filtered: true, severity: String::from("medium")}));
+    assert_eq!(content_filter_result.sexual, None);
+  }
+
+  #[test]
+  fn it_should_parse_azure_error_with_numeric_code() {
+    let body: &str = r#"{"error": {"code": 429, "message": "Requests to the ChatCompletions_Create Operation have exceeded call rate limit."}}"#;
+
+    let api_error: ApiError = ApiError::from_response(429, body, 1);
+    assert_eq!(api_error.code, Some(String::from("429")));
+    assert!(api_error.is_rate_limit_exceeded());
+  }
+
+  #[test]
+  fn it_should_keep_the_raw_body_when_it_is_not_an_error_envelope() {
+    let api_error: ApiError = ApiError::from_response(502, "Bad gateway", 1);
+    assert_eq!(api_error.kind, ErrorKind::ServerError);
+    assert_eq!(api_error.message, "Bad gateway");
+    assert_eq!(api_error.code, None);
+    assert!(api_error.is_retriable());
+  }
+}
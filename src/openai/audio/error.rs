@@ -0,0 +1,50 @@
+use std::error::Error;
+use std::fmt::{Display, Debug};
+
+#[derive(Clone, PartialEq)]
+pub enum AudioError {
+  EmptyInput,
+  InputTooLong(usize),
+  MissingFile,
+  SpeedValueOutOfRange(f32),
+  TemperatureValueOutOfRange(f32),
+  TimestampGranularitiesRequireVerboseJson,
+}
+
+impl AudioError {
+  fn label(&self) -> &'static str {
+    match self {
+      AudioError::EmptyInput => "EmptyInput",
+      AudioError::InputTooLong(_) => "InputTooLong",
+      AudioError::MissingFile => "MissingFile",
+      AudioError::SpeedValueOutOfRange(_) => "SpeedValueOutOfRange",
+      AudioError::TemperatureValueOutOfRange(_) => "TemperatureValueOutOfRange",
+      AudioError::TimestampGranularitiesRequireVerboseJson => "TimestampGranularitiesRequireVerboseJson",
+    }
+  }
+
+  fn error_message(&self) -> String {
+    match self {
+      AudioError::EmptyInput => String::from("Input cannot be empty."),
+      AudioError::InputTooLong(length) => format!("Input must have at most 4096 characters [Number of characters: {}]", length),
+      AudioError::MissingFile => String::from("Audio file is required."),
+      AudioError::SpeedValueOutOfRange(speed) => format!("Speed value must be between 0.25 and 4.0 [Given value: {}]", speed),
+      AudioError::TemperatureValueOutOfRange(temperature) => format!("Temperature value must be between 0.0 and 1.0 [Given value: {}]", temperature),
+      AudioError::TimestampGranularitiesRequireVerboseJson => String::from("Timestamp granularities require the verbose_json response format."),
+    }
+  }
+}
+
+impl Debug for AudioError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "[{}] {}", self.label(), self.error_message())
+  }
+}
+
+impl Display for AudioError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.error_message())
+  }
+}
+
+impl Error for AudioError {}
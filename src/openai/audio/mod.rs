@@ -0,0 +1,10 @@
+pub mod error;
+pub mod model;
+
+mod speech;
+mod transcription;
+mod translation;
+
+pub use speech::Speech;
+pub use transcription::Transcription;
+pub use translation::Translation;
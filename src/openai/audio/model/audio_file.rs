@@ -0,0 +1,16 @@
+/// Audio file to transcribe or to translate, e.g. mp3, mp4, mpeg, mpga, m4a, wav
+/// or webm. The format is inferred by the API from the extension of the file name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudioFile {
+  pub file_name: String,
+  pub bytes: Vec<u8>,
+}
+
+impl AudioFile {
+  pub fn new(file_name: &str, bytes: Vec<u8>) -> AudioFile {
+    AudioFile {
+      file_name: file_name.to_string(),
+      bytes,
+    }
+  }
+}
@@ -0,0 +1,28 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioResponseFormat {
+  Json,
+  Text,
+  Srt,
+  VerboseJson,
+  Vtt,
+}
+
+impl AudioResponseFormat {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      AudioResponseFormat::Json => "json",
+      AudioResponseFormat::Text => "text",
+      AudioResponseFormat::Srt => "srt",
+      AudioResponseFormat::VerboseJson => "verbose_json",
+      AudioResponseFormat::Vtt => "vtt",
+    }
+  }
+
+  /// Return true if the response is a JSON object, false if it is plain text.
+  pub fn is_json(&self) -> bool {
+    matches!(self, AudioResponseFormat::Json | AudioResponseFormat::VerboseJson)
+  }
+}
@@ -0,0 +1,75 @@
+use serde::{Serialize, Deserialize};
+
+/// Cue of a subtitle returned with the srt and vtt response formats, with its
+/// start and end in seconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cue {
+  pub start: f32,
+  pub end: f32,
+  pub text: String,
+}
+
+/// Parse the cues of a SubRip (srt) or WebVTT (vtt) subtitle. The blocks without
+/// timing, like the WEBVTT header or the NOTE and STYLE blocks, and the cues with
+/// malformed timestamps are skipped.
+pub(crate) fn parse_cues(subtitle: &str) -> Vec<Cue> {
+  let subtitle: String = subtitle.replace("\r\n", "\n");
+  subtitle.split("\n\n")
+    .filter_map(parse_cue)
+    .collect()
+}
+
+fn parse_cue(block: &str) -> Option<Cue> {
+  let mut lines = block.lines().skip_while(|line| !line.contains("-->"));
+  let (start, end) = lines.next()?.split_once("-->")?;
+  // Cue settings of WebVTT may follow the end timestamp
+  let end: &str = end.split_whitespace().next()?;
+  Some(Cue {
+    start: parse_timestamp(start.trim())?,
+    end: parse_timestamp(end)?,
+    text: lines.collect::<Vec<&str>>().join("\n"),
+  })
+}
+
+/// Parse a timestamp `hh:mm:ss,ttt` of SubRip or `[hh:]mm:ss.ttt` of WebVTT into seconds.
+fn parse_timestamp(timestamp: &str) -> Option<f32> {
+  let timestamp: String = timestamp.replace(',', ".");
+  let mut parts = timestamp.rsplit(':');
+  let seconds: f32 = parts.next()?.parse().ok()?;
+  let minutes: u32 = parts.next()?.parse().ok()?;
+  let hours: u32 = match parts.next() {
+    Some(hours) => hours.parse().ok()?,
+    None => 0,
+  };
+  if parts.next().is_some() {
+    return None;
+  }
+  Some((hours * 3600 + minutes * 60) as f32 + seconds)
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_parse_the_cues_of_a_srt_subtitle() {
+    let srt: &str = "1\r\n00:00:00,000 --> 00:00:02,500\r\nHello team.\r\n\r\n2\r\n01:02:03,250 --> 01:02:05,000\r\nLet's start\r\nthe meeting.\r\n";
+    assert_eq!(parse_cues(srt), vec![
+      Cue {start: 0.0, end: 2.5, text: String::from("Hello team.")},
+      Cue {start: 3723.25, end: 3725.0, text: String::from("Let's start\nthe meeting.")},
+    ]);
+  }
+
+  #[test]
+  fn it_should_parse_the_cues_of_a_vtt_subtitle() {
+    let vtt: &str = "WEBVTT\n\nNOTE generated by whisper\n\n00:00.000 --> 00:02.500\nHello team.\n\nintro\n00:00:02.500 --> 00:00:04.000 align:start\nWelcome.\n\n00:05.000 --> later\nMalformed.\n";
+    assert_eq!(parse_cues(vtt), vec![
+      Cue {start: 0.0, end: 2.5, text: String::from("Hello team.")},
+      Cue {start: 2.5, end: 4.0, text: String::from("Welcome.")},
+    ]);
+  }
+}
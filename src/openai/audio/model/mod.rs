@@ -0,0 +1,20 @@
+mod audio_file;
+mod audio_response_format;
+mod cue;
+mod segment;
+mod speech_format;
+mod timestamp_granularity;
+mod transcription_response;
+mod voice;
+mod word;
+
+pub use audio_file::AudioFile;
+pub use audio_response_format::AudioResponseFormat;
+pub use cue::Cue;
+pub(crate) use cue::parse_cues;
+pub use segment::Segment;
+pub use speech_format::SpeechFormat;
+pub use timestamp_granularity::TimestampGranularity;
+pub use transcription_response::TranscriptionResponse;
+pub use voice::Voice;
+pub use word::Word;
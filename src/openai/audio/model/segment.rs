@@ -0,0 +1,16 @@
+use serde::{Serialize, Deserialize};
+
+/// Segment of a transcription returned with the verbose_json response format.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Segment {
+  pub id: u32,
+  pub seek: u32,
+  pub start: f32,
+  pub end: f32,
+  pub text: String,
+  pub tokens: Vec<u32>,
+  pub temperature: f32,
+  pub avg_logprob: f32,
+  pub compression_ratio: f32,
+  pub no_speech_prob: f32,
+}
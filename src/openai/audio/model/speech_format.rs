@@ -0,0 +1,12 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpeechFormat {
+  Mp3,
+  Opus,
+  Aac,
+  Flac,
+  Wav,
+  Pcm,
+}
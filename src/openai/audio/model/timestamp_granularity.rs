@@ -0,0 +1,17 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimestampGranularity {
+  Word,
+  Segment,
+}
+
+impl TimestampGranularity {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      TimestampGranularity::Word => "word",
+      TimestampGranularity::Segment => "segment",
+    }
+  }
+}
@@ -0,0 +1,36 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::audio::model::{AudioResponseFormat, Cue, Segment, Word, parse_cues};
+
+/// Transcription or translation of an audio file. With the json response format
+/// only the text is set, the other fields are set with the verbose_json format.
+/// With the text, srt and vtt formats, the text is the raw body of the response
+/// and the cues are parsed from the srt and vtt subtitles.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionResponse {
+  pub text: String,
+  pub task: Option<String>,
+  pub language: Option<String>,
+  pub duration: Option<f32>,
+  pub segments: Option<Vec<Segment>>,
+  pub words: Option<Vec<Word>>,
+  pub cues: Option<Vec<Cue>>,
+}
+
+impl TranscriptionResponse {
+  pub(crate) fn from_text(text: String, response_format: AudioResponseFormat) -> TranscriptionResponse {
+    let cues: Option<Vec<Cue>> = match response_format {
+      AudioResponseFormat::Srt | AudioResponseFormat::Vtt => Some(parse_cues(&text)),
+      _ => None,
+    };
+    TranscriptionResponse {
+      text,
+      task: None,
+      language: None,
+      duration: None,
+      segments: None,
+      words: None,
+      cues,
+    }
+  }
+}
@@ -0,0 +1,15 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Voice {
+  Alloy,
+  Ash,
+  Coral,
+  Echo,
+  Fable,
+  Onyx,
+  Nova,
+  Sage,
+  Shimmer,
+}
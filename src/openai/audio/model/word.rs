@@ -0,0 +1,10 @@
+use serde::{Serialize, Deserialize};
+
+/// Word of a transcription with its timestamps, returned when the word timestamp
+/// granularity is requested.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Word {
+  pub word: String,
+  pub start: f32,
+  pub end: f32,
+}
@@ -0,0 +1,170 @@
+use serde::{Serialize, Deserialize};
+use tokio::io::AsyncWrite;
+
+use crate::openai::error::Error;
+use crate::openai::requestor::{Requestor, write_body};
+use crate::openai::{Client, Operation};
+use crate::openai::audio::error::AudioError;
+use crate::openai::audio::model::{SpeechFormat, Voice};
+
+/// Text-to-speech request whose response is the raw audio in the requested format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Speech {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  model: Option<String>,
+  input: String,
+  voice: Voice,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  response_format: Option<SpeechFormat>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  speed: Option<f32>,
+}
+
+impl Default for Speech {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Speech {
+  pub fn new() -> Speech {
+    Speech {
+      model: None,
+      input: String::new(),
+      voice: Voice::Alloy,
+      response_format: None,
+      speed: None,
+    }
+  }
+
+  /// Set the text to generate audio for, with at most 4096 characters.
+  pub fn input(mut self, input: String) -> Result<Speech, AudioError> {
+    let length: usize = input.chars().count();
+    if input.is_empty() {
+      Err(AudioError::EmptyInput)
+    } else if length > 4096 {
+      Err(AudioError::InputTooLong(length))
+    } else {
+      self.input = input;
+      Ok(self)
+    }
+  }
+
+  pub fn voice(mut self, voice: Voice) -> Speech {
+    self.voice = voice;
+    self
+  }
+
+  pub fn response_format(mut self, response_format: SpeechFormat) -> Speech {
+    self.response_format = Some(response_format);
+    self
+  }
+
+  pub fn speed(mut self, speed: f32) -> Result<Speech, AudioError> {
+    if !(0.25..=4.0).contains(&speed) {
+      Err(AudioError::SpeedValueOutOfRange(speed))
+    } else {
+      self.speed = Some(speed);
+      Ok(self)
+    }
+  }
+
+  /// Generate the audio and return it once fully received.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the input is empty or if the request fails.
+  ///
+  pub async fn create(&self, client: &Client, model_id: &str, api_version: Option<&str>) -> Result<Vec<u8>, Error> {
+    let response = self.send(client, model_id, api_version).await?;
+    Ok(response.bytes().await?.to_vec())
+  }
+
+  /// Generate the audio and write it to the given writer as it is received, e.g. a
+  /// file or a player, without holding the whole audio in memory. Return the number
+  /// of bytes written.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the input is empty, if the request fails
+  /// or if the audio cannot be written.
+  ///
+  pub async fn stream_to<W: AsyncWrite + Unpin>(&self, client: &Client, model_id: &str, api_version: Option<&str>, writer: &mut W) -> Result<u64, Error> {
+    let response = self.send(client, model_id, api_version).await?;
+    write_body(response, writer).await
+  }
+
+  async fn send(&self, client: &Client, model_id: &str, api_version: Option<&str>) -> Result<reqwest::Response, Error> {
+    if self.input.is_empty() {
+      return Err(AudioError::EmptyInput.into());
+    }
+    let mut request_body: serde_json::Value = serde_json::to_value(self)?;
+    client.add_model_to_body(&mut request_body, model_id);
+    client.post(Operation::AudioSpeech, &serde_json::to_string(&request_body)?, Some(model_id), api_version).await
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, body_json, query_param};
+  use crate::openai::ApiType;
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_generate_speech_with_openai() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/audio/speech"))
+      .and(body_json(serde_json::json!({"model": "tts-1", "input": "Hello team.", "voice": "nova", "response_format": "opus", "speed": 1.5})))
+      .respond_with(ResponseTemplate::new(200).set_body_bytes(b"opus-bytes".to_vec()))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let audio: Vec<u8> = Speech::new()
+      .input(String::from("Hello team.")).unwrap()
+      .voice(Voice::Nova)
+      .response_format(SpeechFormat::Opus)
+      .speed(1.5).unwrap()
+      .create(&client, "tts-1", None).await.unwrap();
+    assert_eq!(audio, b"opus-bytes".to_vec());
+  }
+
+  #[tokio::test]
+  async fn it_should_stream_speech_to_a_writer_with_azure_deployment() {
+    let audio: Vec<u8> = (0..100_000).map(|i: u32| (i % 256) as u8).collect();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/openai/deployments/tts/audio/speech"))
+      .and(query_param("api-version", "2024-10-21"))
+      .and(body_json(serde_json::json!({"input": "Hello team.", "voice": "alloy"})))
+      .respond_with(ResponseTemplate::new(200).set_body_bytes(audio.clone()))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let mut writer: Vec<u8> = Vec::new();
+    let written: u64 = Speech::new()
+      .input(String::from("Hello team.")).unwrap()
+      .stream_to(&client, "tts", None, &mut writer).await.unwrap();
+    assert_eq!(written, 100_000);
+    assert_eq!(writer, audio);
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_speech_is_invalid() {
+    let client: Client = Client::new_openai_client(create_auth_with_given_api_key("12345abcd"));
+    let result = Speech::new().create(&client, "tts-1", None).await;
+    assert!(matches!(result, Err(Error::AudioValidationError(AudioError::EmptyInput))));
+    assert_eq!(Speech::new().input("a".repeat(4097)).unwrap_err(), AudioError::InputTooLong(4097));
+    assert_eq!(Speech::new().speed(5.0).unwrap_err(), AudioError::SpeedValueOutOfRange(5.0));
+  }
+}
@@ -0,0 +1,211 @@
+use crate::openai::error::Error;
+use crate::openai::requestor::{Requestor, decode_json};
+use crate::openai::{Client, MultipartForm, Operation};
+use crate::openai::audio::error::AudioError;
+use crate::openai::audio::model::{AudioFile, AudioResponseFormat, TimestampGranularity, TranscriptionResponse};
+
+/// Transcription of an audio file, in the language of the audio, sent as
+/// `multipart/form-data`.
+#[derive(Debug, Clone, Default)]
+pub struct Transcription {
+  file: Option<AudioFile>,
+  language: Option<String>,
+  prompt: Option<String>,
+  response_format: Option<AudioResponseFormat>,
+  temperature: Option<f32>,
+  timestamp_granularities: Vec<TimestampGranularity>,
+}
+
+impl Transcription {
+  pub fn new() -> Transcription {
+    Transcription::default()
+  }
+
+  /// Set the audio file to transcribe.
+  pub fn file(mut self, file: AudioFile) -> Transcription {
+    self.file = Some(file);
+    self
+  }
+
+  /// Set the language of the audio in ISO-639-1 format, e.g. `en`, to improve
+  /// accuracy and latency.
+  pub fn language(mut self, language: String) -> Transcription {
+    if language.is_empty() {
+      self.language = None;
+    } else {
+      self.language = Some(language);
+    }
+    self
+  }
+
+  /// Set the text guiding the style of the model or continuing a previous audio
+  /// segment. The prompt should match the language of the audio.
+  pub fn prompt(mut self, prompt: String) -> Transcription {
+    if prompt.is_empty() {
+      self.prompt = None;
+    } else {
+      self.prompt = Some(prompt);
+    }
+    self
+  }
+
+  pub fn response_format(mut self, response_format: AudioResponseFormat) -> Transcription {
+    self.response_format = Some(response_format);
+    self
+  }
+
+  pub fn temperature(mut self, temperature: f32) -> Result<Transcription, AudioError> {
+    if !(0.0..=1.0).contains(&temperature) {
+      Err(AudioError::TemperatureValueOutOfRange(temperature))
+    } else {
+      self.temperature = Some(temperature);
+      Ok(self)
+    }
+  }
+
+  /// Set the timestamp granularities of the transcription. They are only
+  /// supported with the verbose_json response format.
+  pub fn timestamp_granularities(mut self, timestamp_granularities: Vec<TimestampGranularity>) -> Transcription {
+    self.timestamp_granularities = timestamp_granularities;
+    self
+  }
+
+  /// Transcribe the audio file.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the file is not set, if timestamp
+  /// granularities are set without the verbose_json response format or if the
+  /// request fails.
+  ///
+  pub async fn create(&self, client: &Client, model_id: &str, api_version: Option<&str>) -> Result<TranscriptionResponse, Error> {
+    let form: MultipartForm = self.form(client, model_id)?;
+    let response = client.post_multipart(Operation::AudioTranscriptions, &form, Some(model_id), api_version).await?;
+    decode_transcription(response, self.response_format).await
+  }
+
+  fn form(&self, client: &Client, model_id: &str) -> Result<MultipartForm, Error> {
+    let file: &AudioFile = self.file.as_ref().ok_or(AudioError::MissingFile)?;
+    if !self.timestamp_granularities.is_empty() && self.response_format != Some(AudioResponseFormat::VerboseJson) {
+      return Err(AudioError::TimestampGranularitiesRequireVerboseJson.into());
+    }
+    let mut form: MultipartForm = MultipartForm::new()
+      .file("file", &file.file_name, file.bytes.clone(), None);
+    form = client.add_model_to_form(form, model_id);
+    if let Some(language) = &self.language {
+      form = form.text("language", language);
+    }
+    if let Some(prompt) = &self.prompt {
+      form = form.text("prompt", prompt);
+    }
+    if let Some(response_format) = self.response_format {
+      form = form.text("response_format", response_format.as_str());
+    }
+    if let Some(temperature) = self.temperature {
+      form = form.text("temperature", &temperature.to_string());
+    }
+    for timestamp_granularity in &self.timestamp_granularities {
+      form = form.text("timestamp_granularities[]", timestamp_granularity.as_str());
+    }
+    Ok(form)
+  }
+}
+
+/// Decode the transcription or the translation according to the requested response
+/// format. The API returns a JSON object by default, and the raw text for the text,
+/// srt and vtt formats whose cues are parsed.
+pub(crate) async fn decode_transcription(response: reqwest::Response, response_format: Option<AudioResponseFormat>) -> Result<TranscriptionResponse, Error> {
+  match response_format {
+    Some(response_format) if !response_format.is_json() => Ok(TranscriptionResponse::from_text(response.text().await?, response_format)),
+    _ => decode_json::<TranscriptionResponse>(response).await,
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, query_param};
+  use crate::openai::ApiType;
+  use crate::openai::audio::model::Cue;
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_transcribe_an_audio_file_with_segments_and_words() {
+    let body_response: &str = r#"{"task": "transcribe", "language": "english", "duration": 2.5, "text": "Hello team.", "segments": [{"id": 0, "seek": 0, "start": 0.0, "end": 2.5, "text": " Hello team.", "tokens": [50364, 2425], "temperature": 0.0, "avg_logprob": -0.28, "compression_ratio": 0.8, "no_speech_prob": 0.01}], "words": [{"word": "Hello", "start": 0.0, "end": 0.8}, {"word": "team", "start": 0.9, "end": 1.5}]}"#;
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/audio/transcriptions"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(body_response))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let transcription: TranscriptionResponse = Transcription::new()
+      .file(AudioFile::new("meeting.mp3", b"audio-bytes".to_vec()))
+      .language(String::from("en"))
+      .prompt(String::from("Weekly sync"))
+      .response_format(AudioResponseFormat::VerboseJson)
+      .temperature(0.2).unwrap()
+      .timestamp_granularities(vec![TimestampGranularity::Word, TimestampGranularity::Segment])
+      .create(&client, "whisper-1", None).await.unwrap();
+    assert_eq!(transcription.text, "Hello team.");
+    assert_eq!(transcription.duration, Some(2.5));
+    assert_eq!(transcription.segments.as_ref().unwrap()[0].tokens, vec![50364, 2425]);
+    assert_eq!(transcription.words.as_ref().unwrap()[1].word, "team");
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: String = String::from_utf8_lossy(&requests[0].body).to_string();
+    assert!(body.contains("name=\"file\"; filename=\"meeting.mp3\""));
+    assert!(body.contains("name=\"model\"\r\n\r\nwhisper-1"));
+    assert!(body.contains("name=\"language\"\r\n\r\nen"));
+    assert!(body.contains("name=\"prompt\"\r\n\r\nWeekly sync"));
+    assert!(body.contains("name=\"response_format\"\r\n\r\nverbose_json"));
+    assert!(body.contains("name=\"temperature\"\r\n\r\n0.2"));
+    assert!(body.contains("name=\"timestamp_granularities[]\"\r\n\r\nword"));
+    assert!(body.contains("name=\"timestamp_granularities[]\"\r\n\r\nsegment"));
+  }
+
+  #[tokio::test]
+  async fn it_should_transcribe_an_audio_file_to_srt_with_azure_deployment() {
+    let srt: &str = "1\n00:00:00,000 --> 00:00:02,500\nHello team.\n";
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/openai/deployments/whisper/audio/transcriptions"))
+      .and(query_param("api-version", "2024-06-01"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(srt))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let transcription: TranscriptionResponse = Transcription::new()
+      .file(AudioFile::new("meeting.mp3", b"audio-bytes".to_vec()))
+      .response_format(AudioResponseFormat::Srt)
+      .create(&client, "whisper", Some("2024-06-01")).await.unwrap();
+    assert_eq!(transcription.text, srt);
+    assert_eq!(transcription.segments, None);
+    assert_eq!(transcription.cues, Some(vec![Cue {start: 0.0, end: 2.5, text: String::from("Hello team.")}]));
+    let requests = mock_server.received_requests().await.unwrap();
+    assert!(!String::from_utf8_lossy(&requests[0].body).contains("name=\"model\""));
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_transcription_is_invalid() {
+    let client: Client = Client::new_openai_client(create_auth_with_given_api_key("12345abcd"));
+    let result = Transcription::new().create(&client, "whisper-1", None).await;
+    assert!(matches!(result, Err(Error::AudioValidationError(AudioError::MissingFile))));
+
+    let result = Transcription::new()
+      .file(AudioFile::new("meeting.mp3", b"audio-bytes".to_vec()))
+      .timestamp_granularities(vec![TimestampGranularity::Word])
+      .create(&client, "whisper-1", None).await;
+    assert!(matches!(result, Err(Error::AudioValidationError(AudioError::TimestampGranularitiesRequireVerboseJson))));
+    assert_eq!(Transcription::new().temperature(1.5).unwrap_err(), AudioError::TemperatureValueOutOfRange(1.5));
+  }
+}
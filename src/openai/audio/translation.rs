@@ -0,0 +1,137 @@
+use crate::openai::error::Error;
+use crate::openai::requestor::Requestor;
+use crate::openai::{Client, MultipartForm, Operation};
+use crate::openai::audio::error::AudioError;
+use crate::openai::audio::model::{AudioFile, AudioResponseFormat, TranscriptionResponse};
+use crate::openai::audio::transcription::decode_transcription;
+
+/// Translation of an audio file into English, sent as `multipart/form-data`.
+#[derive(Debug, Clone, Default)]
+pub struct Translation {
+  file: Option<AudioFile>,
+  prompt: Option<String>,
+  response_format: Option<AudioResponseFormat>,
+  temperature: Option<f32>,
+}
+
+impl Translation {
+  pub fn new() -> Translation {
+    Translation::default()
+  }
+
+  /// Set the audio file to translate.
+  pub fn file(mut self, file: AudioFile) -> Translation {
+    self.file = Some(file);
+    self
+  }
+
+  /// Set the text guiding the style of the model or continuing a previous audio
+  /// segment. The prompt should be in English.
+  pub fn prompt(mut self, prompt: String) -> Translation {
+    if prompt.is_empty() {
+      self.prompt = None;
+    } else {
+      self.prompt = Some(prompt);
+    }
+    self
+  }
+
+  pub fn response_format(mut self, response_format: AudioResponseFormat) -> Translation {
+    self.response_format = Some(response_format);
+    self
+  }
+
+  pub fn temperature(mut self, temperature: f32) -> Result<Translation, AudioError> {
+    if !(0.0..=1.0).contains(&temperature) {
+      Err(AudioError::TemperatureValueOutOfRange(temperature))
+    } else {
+      self.temperature = Some(temperature);
+      Ok(self)
+    }
+  }
+
+  /// Translate the audio file into English.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the file is not set or if the request fails.
+  ///
+  pub async fn create(&self, client: &Client, model_id: &str, api_version: Option<&str>) -> Result<TranscriptionResponse, Error> {
+    let form: MultipartForm = self.form(client, model_id)?;
+    let response = client.post_multipart(Operation::AudioTranslations, &form, Some(model_id), api_version).await?;
+    decode_transcription(response, self.response_format).await
+  }
+
+  fn form(&self, client: &Client, model_id: &str) -> Result<MultipartForm, Error> {
+    let file: &AudioFile = self.file.as_ref().ok_or(AudioError::MissingFile)?;
+    let mut form: MultipartForm = MultipartForm::new()
+      .file("file", &file.file_name, file.bytes.clone(), None);
+    form = client.add_model_to_form(form, model_id);
+    if let Some(prompt) = &self.prompt {
+      form = form.text("prompt", prompt);
+    }
+    if let Some(response_format) = self.response_format {
+      form = form.text("response_format", response_format.as_str());
+    }
+    if let Some(temperature) = self.temperature {
+      form = form.text("temperature", &temperature.to_string());
+    }
+    Ok(form)
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, query_param};
+  use crate::openai::ApiType;
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_translate_an_audio_file_with_azure_deployment() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/openai/deployments/whisper/audio/translations"))
+      .and(query_param("api-version", "2024-10-21"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"text": "Hello team."}"#))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let translation: TranscriptionResponse = Translation::new()
+      .file(AudioFile::new("reunion.m4a", b"audio-bytes".to_vec()))
+      .create(&client, "whisper", None).await.unwrap();
+    assert_eq!(translation.text, "Hello team.");
+    assert_eq!(translation.language, None);
+  }
+
+  #[tokio::test]
+  async fn it_should_translate_an_audio_file_to_text_with_openai() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/audio/translations"))
+      .respond_with(ResponseTemplate::new(200).set_body_string("Hello team.\n"))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let translation: TranscriptionResponse = Translation::new()
+      .file(AudioFile::new("reunion.m4a", b"audio-bytes".to_vec()))
+      .response_format(AudioResponseFormat::Text)
+      .create(&client, "whisper-1", None).await.unwrap();
+    assert_eq!(translation.text, "Hello team.\n");
+    assert_eq!(translation.cues, None);
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: String = String::from_utf8_lossy(&requests[0].body).to_string();
+    assert!(body.contains("name=\"model\"\r\n\r\nwhisper-1"));
+    assert!(body.contains("name=\"response_format\"\r\n\r\ntext"));
+  }
+}
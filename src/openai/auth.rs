@@ -1,12 +1,73 @@
+use std::sync::Arc;
+
+use crate::openai::error::{Error, env_var};
+use crate::openai::credential::{ChainedCredential, ClientSecretCredential, CredentialHeader, CredentialProvider, EnvironmentKeyCredential, KeyHeader, ManagedIdentityCredential};
+
+/// Credential used to authenticate the requests. It is cheap to clone as the
+/// credential provider, and the token it caches, is shared between the clones.
+#[derive(Clone)]
 pub struct Auth {
   pub api_key: String,
+  credential_provider: Option<Arc<dyn CredentialProvider>>,
 }
 
-// TODO: Implement Azure AD auth
-
 impl Auth {
   pub fn new(api_key: String) -> Auth {
-    Auth { api_key }
+    Auth {
+      api_key,
+      credential_provider: None,
+    }
+  }
+
+  /// Create auth using the given credential provider. The header returned by the
+  /// provider is sent with every request instead of the API key.
+  pub fn from_credential_provider(credential_provider: Box<dyn CredentialProvider>) -> Auth {
+    Auth {
+      api_key: String::new(),
+      credential_provider: Some(Arc::from(credential_provider)),
+    }
+  }
+
+  /// Create auth for Azure AD (Entra ID) using the credential chain
+  /// [`crate::openai::credential::ChainedCredential::default_azure_credential()`].
+  pub fn default_azure_credential() -> Auth {
+    Auth::from_credential_provider(Box::new(ChainedCredential::default_azure_credential()))
+  }
+
+  /// Create auth for Azure AD (Entra ID) using the client credentials flow with
+  /// the given tenant ID, client ID and client secret. The token is requested to
+  /// the default authority host, use [`Self::from_azure_ad_credential()`] to
+  /// configure it.
+  pub fn new_azure_ad(tenant_id: String, client_id: String, client_secret: String) -> Auth {
+    Auth::from_azure_ad_credential(ClientSecretCredential::new(tenant_id, client_id, client_secret))
+  }
+
+  pub fn from_azure_ad_credential(credential: ClientSecretCredential) -> Auth {
+    Auth::from_credential_provider(Box::new(credential))
+  }
+
+  /// Create auth for Azure AD (Entra ID) using the managed identity of the Azure
+  /// resource running the code.
+  pub fn from_managed_identity_credential(credential: ManagedIdentityCredential) -> Auth {
+    Auth::from_credential_provider(Box::new(credential))
+  }
+
+  /// Create auth for Azure AD (Entra ID) with the managed identity credential
+  /// created using [`crate::openai::credential::ManagedIdentityCredential::from_env()`].
+  pub fn managed_identity_from_env() -> Self {
+    Self::from_managed_identity_credential(ManagedIdentityCredential::from_env())
+  }
+
+  /// Create auth for Azure AD (Entra ID) with the credential created using
+  /// [`crate::openai::credential::ClientSecretCredential::from_env()`].
+  /// 
+  /// # Errors
+  /// 
+  /// This function will return an error if AZURE_TENANT_ID, AZURE_CLIENT_ID or
+  /// AZURE_CLIENT_SECRET environment variable is not set.
+  /// 
+  pub fn azure_ad_from_env() -> Result<Self, Error> {
+    Ok(Self::from_azure_ad_credential(ClientSecretCredential::from_env()?))
   }
 
   /// Create auth with api_key set to the value
@@ -17,10 +78,36 @@ impl Auth {
   /// This function will return an error if OPENAI_API_KEY environment
   /// variable is not set.
   /// 
-  pub fn from_env() -> Result<Self, std::env::VarError> {
-    let api_key = std::env::var("OPENAI_API_KEY")?;
+  pub fn from_env() -> Result<Self, Error> {
+    let api_key = env_var("OPENAI_API_KEY")?;
     Ok(Self::new(api_key))
   }
+
+  /// Create auth with the credential [`crate::openai::credential::EnvironmentKeyCredential`]
+  /// reading the environment variable OPENAI_API_KEY on every request, so that a
+  /// rotated key is used without creating a new client. The api_key is set to the
+  /// current value of the variable.
+  /// 
+  /// # Errors
+  /// 
+  /// This function will return an error if OPENAI_API_KEY environment
+  /// variable is not set.
+  /// 
+  pub fn api_key_from_env(key_header: KeyHeader) -> Result<Self, Error> {
+    let api_key = env_var("OPENAI_API_KEY")?;
+    Ok(Auth {
+      api_key,
+      credential_provider: Some(Arc::new(EnvironmentKeyCredential::new("OPENAI_API_KEY", key_header))),
+    })
+  }
+
+  /// Get the header of the credential provider if one is set.
+  pub(crate) async fn get_credential_header(&self) -> Result<Option<CredentialHeader>, Error> {
+    match &self.credential_provider {
+      Some(credential_provider) => Ok(Some(credential_provider.get_credential().await?)),
+      None => Ok(None),
+    }
+  }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -34,13 +121,17 @@ pub fn create_auth_with_given_api_key(api_key: &str) -> Auth {
 
 
 #[cfg(test)]
-pub fn create_auth_with_environment_variable(api_key: &str) -> Result<Auth, std::env::VarError> {
-  std::env::set_var("OPENAI_API_KEY", api_key.to_string());
+pub fn create_auth_with_environment_variable(api_key: &str) -> Result<Auth, Error> {
+  std::env::set_var("OPENAI_API_KEY", api_key);
   Auth::from_env()
 }
 
 #[cfg(test)]
 mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, header, query_param};
+  use crate::openai::credential::{KeyHeader, StaticKeyCredential};
+  use crate::openai::credential::client_secret_credential::{create_client_secret_credential, mount_token_endpoint};
   use super::*;
 
   #[test]
@@ -55,10 +146,59 @@ mod tests {
   fn it_should_create_auth_with_environment_variable() {
     let api_key: String = String::from("12345abcd");
     
-    let auth: Result<Auth, std::env::VarError> = create_auth_with_environment_variable(&api_key);
+    let auth: Result<Auth, Error> = create_auth_with_environment_variable(&api_key);
     match auth {
       Ok(auth) => assert_eq!(auth.api_key, api_key),
-      Err(_) => assert!(false, "Auth should be created with environment variable")
+      Err(_) => panic!("Auth should be created with environment variable")
     }
   }
-}
\ No newline at end of file
+
+  #[tokio::test]
+  async fn it_should_create_auth_with_credential_provider() {
+    let credential_provider = StaticKeyCredential::new(String::from("12345abcd"), KeyHeader::ApiKey);
+
+    let auth: Auth = Auth::from_credential_provider(Box::new(credential_provider));
+    assert!(auth.api_key.is_empty());
+    assert_eq!(auth.get_credential_header().await.unwrap(), Some(CredentialHeader::api_key("12345abcd")));
+  }
+
+  #[tokio::test]
+  async fn it_should_not_return_credential_header_without_credential_provider() {
+    let auth: Auth = create_auth_with_given_api_key("12345abcd");
+    assert_eq!(auth.get_credential_header().await.unwrap(), None);
+  }
+
+  #[tokio::test]
+  async fn it_should_create_auth_with_azure_ad_client_secret() {
+    let mock_server = MockServer::start().await;
+    // The token endpoint only matches the tenant ID, client ID and client secret of the credential
+    mount_token_endpoint(&mock_server, "azure-ad-token", 3600, 1).await;
+
+    let auth: Auth = Auth::from_azure_ad_credential(create_client_secret_credential(&mock_server.uri()));
+    assert!(auth.api_key.is_empty());
+    assert_eq!(auth.get_credential_header().await.unwrap().unwrap().value, "Bearer azure-ad-token");
+  }
+
+  #[tokio::test]
+  async fn it_should_create_auth_with_managed_identity() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/metadata/identity/oauth2/token"))
+      .and(header("Metadata", "true"))
+      .and(query_param("client_id", "client-id"))
+      .and(query_param("resource", "https://cognitiveservices.azure.com"))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_json(serde_json::json!({"access_token": "managed-identity-token", "expires_in": 3599}))
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let auth: Auth = Auth::from_managed_identity_credential(ManagedIdentityCredential::new()
+      .endpoint(&format!("{}/metadata/identity/oauth2/token", mock_server.uri()))
+      .client_id(String::from("client-id"))
+    );
+    assert!(auth.api_key.is_empty());
+    assert_eq!(auth.get_credential_header().await.unwrap().unwrap().value, "Bearer managed-identity-token");
+  }
+}
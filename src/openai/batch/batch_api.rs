@@ -0,0 +1,299 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::openai::error::Error;
+use crate::openai::requestor::{Requestor, decode_json};
+use crate::openai::{Client, Operation};
+use crate::openai::batch::BatchInput;
+use crate::openai::batch::model::{Batch, BatchEndpoint, BatchList, BatchListParams, BatchRequestError, BatchResults};
+use crate::openai::chat::model::ChatCompletionResponse;
+use crate::openai::files;
+use crate::openai::files::model::{FileObject, FilePurpose};
+
+/// Line of the output or of the error file of a batch.
+#[derive(Deserialize)]
+struct BatchOutputLine {
+  custom_id: String,
+  response: Option<BatchOutputResponse>,
+  error: Option<BatchOutputError>,
+}
+
+#[derive(Deserialize)]
+struct BatchOutputResponse {
+  status_code: u16,
+  body: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct BatchOutputError {
+  code: Option<String>,
+  message: String,
+}
+
+/// Create a batch from an uploaded input file with the `batch` purpose. The
+/// requests are processed within 24 hours.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails.
+///
+pub async fn create(client: &Client, input_file_id: &str, endpoint: BatchEndpoint, metadata: Option<HashMap<String, String>>, api_version: Option<&str>) -> Result<Batch, Error> {
+  let mut request_body = serde_json::json!({
+    "input_file_id": input_file_id,
+    "endpoint": endpoint.url(client),
+    "completion_window": "24h",
+  });
+  if let Some(metadata) = metadata {
+    request_body["metadata"] = serde_json::to_value(metadata)?;
+  }
+  let response = client.post(Operation::Batches, &serde_json::to_string(&request_body)?, None, api_version).await?;
+  decode_json::<Batch>(response).await
+}
+
+/// List a page of the batches of the organization or of the Azure OpenAI resource.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails.
+///
+pub async fn list(client: &Client, params: &BatchListParams, api_version: Option<&str>) -> Result<BatchList, Error> {
+  let response = client.get(Operation::Batches, &params.to_query(), None, api_version).await?;
+  decode_json::<BatchList>(response).await
+}
+
+/// Retrieve the batch with the given ID.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails.
+///
+pub async fn retrieve(client: &Client, batch_id: &str, api_version: Option<&str>) -> Result<Batch, Error> {
+  let response = client.get(Operation::Batch(batch_id), &[], None, api_version).await?;
+  decode_json::<Batch>(response).await
+}
+
+/// Cancel the batch with the given ID. The batch is `cancelling` until the requests
+/// in progress are finished, then `cancelled` with the partial results.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails.
+///
+pub async fn cancel(client: &Client, batch_id: &str, api_version: Option<&str>) -> Result<Batch, Error> {
+  let response = client.post(Operation::BatchCancel(batch_id), "{}", None, api_version).await?;
+  decode_json::<Batch>(response).await
+}
+
+/// Poll the batch with the given ID at the given interval until its status is
+/// terminal, and return it.
+///
+/// # Errors
+///
+/// This function will return an error if one of the requests fails.
+///
+pub async fn wait(client: &Client, batch_id: &str, poll_interval: Duration, api_version: Option<&str>) -> Result<Batch, Error> {
+  loop {
+    let batch: Batch = retrieve(client, batch_id, api_version).await?;
+    if batch.status.is_terminal() {
+      return Ok(batch);
+    }
+    tokio::time::sleep(poll_interval).await;
+  }
+}
+
+/// Download the output and the error files of the finished batch and map each line
+/// to the chat completion response or to the error of the request with the same
+/// custom ID.
+///
+/// # Errors
+///
+/// This function will return an error if a file cannot be downloaded or if a line
+/// cannot be parsed.
+///
+pub async fn collect_results(client: &Client, batch: Batch, api_version: Option<&str>) -> Result<BatchResults, Error> {
+  let mut responses: HashMap<String, Result<ChatCompletionResponse, BatchRequestError>> = HashMap::new();
+  for file_id in [&batch.output_file_id, &batch.error_file_id].into_iter().flatten() {
+    let content: Vec<u8> = files::content(client, file_id, api_version).await?;
+    for line in String::from_utf8_lossy(&content).lines().filter(|line| !line.trim().is_empty()) {
+      let (custom_id, result) = parse_output_line(line)?;
+      responses.insert(custom_id, result);
+    }
+  }
+  Ok(BatchResults {batch, responses})
+}
+
+/// Run the requests as a batch: upload the input file, create the batch, poll it at
+/// the given interval until it is finished and collect its results.
+///
+/// # Errors
+///
+/// This function will return an error if the input is invalid or if one of the
+/// requests fails. The batch itself may have failed or expired, its status is in
+/// the results.
+///
+pub async fn run(client: &Client, input: &BatchInput, model_id: &str, poll_interval: Duration, api_version: Option<&str>) -> Result<BatchResults, Error> {
+  let jsonl: String = input.to_jsonl(client, model_id)?;
+  let mut file: FileObject = files::upload(client, "batch.jsonl", jsonl.into_bytes(), FilePurpose::Batch, api_version).await?;
+  // Azure OpenAI imports the file asynchronously, the batch cannot be created before it is processed
+  while file.status.is_some_and(|status| !status.is_terminal()) {
+    tokio::time::sleep(poll_interval).await;
+    file = files::retrieve(client, &file.id, api_version).await?;
+  }
+  let batch: Batch = create(client, &file.id, BatchEndpoint::ChatCompletions, None, api_version).await?;
+  let batch: Batch = wait(client, &batch.id, poll_interval, api_version).await?;
+  collect_results(client, batch, api_version).await
+}
+
+fn parse_output_line(line: &str) -> Result<(String, Result<ChatCompletionResponse, BatchRequestError>), Error> {
+  let output_line: BatchOutputLine = serde_json::from_str(line)?;
+  let status_code: Option<u16> = output_line.response.as_ref().map(|response| response.status_code);
+  let result = match (output_line.error, output_line.response) {
+    (Some(error), _) => Err(BatchRequestError {status_code, code: error.code, message: error.message}),
+    (None, Some(response)) if (200..300).contains(&response.status_code) => Ok(serde_json::from_value::<ChatCompletionResponse>(response.body)?),
+    (None, Some(response)) => {
+      let error: &serde_json::Value = &response.body["error"];
+      Err(BatchRequestError {
+        status_code,
+        code: error["code"].as_str().map(str::to_string),
+        message: error["message"].as_str().unwrap_or_default().to_string(),
+      })
+    },
+    (None, None) => Err(BatchRequestError {status_code, code: None, message: String::from("The request has no response.")}),
+  };
+  Ok((output_line.custom_id, result))
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, body_json, query_param};
+  use crate::openai::ApiType;
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use crate::openai::batch::model::BatchStatus;
+  use crate::openai::chat::ChatCompletion;
+  use crate::openai::chat::model::ChatMessageBuilder;
+  use super::*;
+
+  fn batch_body(status: &str, output_file_id: Option<&str>, error_file_id: Option<&str>) -> String {
+    serde_json::json!({
+      "id": "batch_abc123", "object": "batch", "endpoint": "/v1/chat/completions", "errors": null,
+      "input_file_id": "file-input", "completion_window": "24h", "status": status,
+      "output_file_id": output_file_id, "error_file_id": error_file_id, "created_at": 1711471533,
+      "request_counts": {"total": 3, "completed": 2, "failed": 1}, "metadata": {"job": "nightly"},
+    }).to_string()
+  }
+
+  const OUTPUT_FILE: &str = concat!(
+    r#"{"id": "batch_req_1", "custom_id": "note-1", "response": {"status_code": 200, "request_id": "req_1", "body": {"id": "chatcmpl-1", "object": "chat.completion", "created": 1711475054, "model": "gpt-4o-mini", "choices": [{"index": 0, "message": {"role": "assistant", "content": "Summary 1"}, "finish_reason": "stop"}], "usage": {"prompt_tokens": 10, "completion_tokens": 2, "total_tokens": 12}}}, "error": null}"#, "\n",
+    r#"{"id": "batch_req_2", "custom_id": "note-2", "response": {"status_code": 200, "request_id": "req_2", "body": {"id": "chatcmpl-2", "object": "chat.completion", "created": 1711475054, "model": "gpt-4o-mini", "choices": [{"index": 0, "message": {"role": "assistant", "content": "Summary 2"}, "finish_reason": "stop"}], "usage": {"prompt_tokens": 10, "completion_tokens": 2, "total_tokens": 12}}}, "error": null}"#, "\n",
+  );
+
+  const ERROR_FILE: &str = concat!(
+    r#"{"id": "batch_req_3", "custom_id": "note-3", "response": {"status_code": 400, "request_id": "req_3", "body": {"error": {"message": "Invalid value for 'temperature'", "type": "invalid_request_error", "param": "temperature", "code": "invalid_value"}}}, "error": null}"#, "\n",
+  );
+
+  #[tokio::test]
+  async fn it_should_run_a_batch_of_chat_completions() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/files"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"id": "file-input", "object": "file", "bytes": 1024, "created_at": 1711471533, "filename": "batch.jsonl", "purpose": "batch", "status": "processed"}"#))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("POST"))
+      .and(path("/batches"))
+      .and(body_json(serde_json::json!({"input_file_id": "file-input", "endpoint": "/v1/chat/completions", "completion_window": "24h"})))
+      .respond_with(ResponseTemplate::new(200).set_body_string(batch_body("validating", None, None)))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("GET"))
+      .and(path("/batches/batch_abc123"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(batch_body("in_progress", None, None)))
+      .up_to_n_times(1)
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("GET"))
+      .and(path("/batches/batch_abc123"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(batch_body("completed", Some("file-output"), Some("file-error"))))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("GET"))
+      .and(path("/files/file-output/content"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(OUTPUT_FILE))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("GET"))
+      .and(path("/files/file-error/content"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(ERROR_FILE))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let mut input: BatchInput = BatchInput::new();
+    for index in 1..=3 {
+      let chat_completion: ChatCompletion = ChatCompletion::new()
+        .messages(ChatMessageBuilder::new().user(format!("Summarize note {}", index)).build());
+      input = input.request(&format!("note-{}", index), chat_completion).unwrap();
+    }
+    let batch_results: BatchResults = run(&client, &input, "gpt-4o-mini", Duration::from_millis(10), None).await.unwrap();
+    assert_eq!(batch_results.batch.status, BatchStatus::Completed);
+    assert_eq!(batch_results.responses.len(), 3);
+    assert_eq!(batch_results.responses["note-2"].as_ref().unwrap().choices[0].message.content, "Summary 2");
+    let error: &BatchRequestError = batch_results.responses["note-3"].as_ref().unwrap_err();
+    assert_eq!(error.status_code, Some(400));
+    assert_eq!(error.code, Some(String::from("invalid_value")));
+    assert_eq!(error.message, "Invalid value for 'temperature'");
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let upload_body: String = String::from_utf8_lossy(&requests[0].body).to_string();
+    assert!(upload_body.contains("name=\"purpose\"\r\n\r\nbatch"));
+    assert!(upload_body.contains(r#""custom_id":"note-3""#));
+  }
+
+  #[tokio::test]
+  async fn it_should_list_and_cancel_batches_with_azure() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/openai/batches"))
+      .and(query_param("api-version", "2024-10-21"))
+      .and(query_param("limit", "10"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(format!(r#"{{"object": "list", "data": [{}], "first_id": "batch_abc123", "last_id": "batch_abc123", "has_more": false}}"#, batch_body("in_progress", None, None))))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("POST"))
+      .and(path("/openai/batches/batch_abc123/cancel"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(batch_body("cancelling", None, None)))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let batch_list: BatchList = list(&client, &BatchListParams::new().limit(10), None).await.unwrap();
+    assert_eq!(batch_list.data[0].request_counts.as_ref().unwrap().failed, 1);
+    assert_eq!(batch_list.data[0].metadata.as_ref().unwrap()["job"], "nightly");
+    let batch: Batch = cancel(&client, "batch_abc123", None).await.unwrap();
+    assert!(!batch.status.is_terminal());
+  }
+
+  #[test]
+  fn it_should_parse_an_expired_request() {
+    let (custom_id, result) = parse_output_line(r#"{"id": "batch_req_4", "custom_id": "note-4", "response": null, "error": {"code": "batch_expired", "message": "This request could not be executed before the completion window expired."}}"#).unwrap();
+    assert_eq!(custom_id, "note-4");
+    let error: BatchRequestError = result.unwrap_err();
+    assert_eq!(error.status_code, None);
+    assert_eq!(error.code, Some(String::from("batch_expired")));
+  }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use crate::openai::Client;
+use crate::openai::error::Error;
+use crate::openai::batch::error::BatchError;
+use crate::openai::batch::model::BatchEndpoint;
+use crate::openai::chat::ChatCompletion;
+
+/// Maximum number of requests in the input file of a batch.
+const MAX_REQUESTS: usize = 50000;
+
+/// Chat completion requests of a batch, each identified by a custom ID used to find
+/// its result in the output of the batch.
+#[derive(Debug, Default)]
+pub struct BatchInput {
+  requests: Vec<(String, ChatCompletion)>,
+  custom_ids: HashSet<String>,
+}
+
+impl BatchInput {
+  pub fn new() -> BatchInput {
+    BatchInput::default()
+  }
+
+  /// Add a chat completion request with the given custom ID, unique in the batch.
+  pub fn request(mut self, custom_id: &str, chat_completion: ChatCompletion) -> Result<BatchInput, BatchError> {
+    if custom_id.is_empty() {
+      Err(BatchError::EmptyCustomId)
+    } else if !self.custom_ids.insert(custom_id.to_string()) {
+      Err(BatchError::DuplicateCustomId(custom_id.to_string()))
+    } else {
+      self.requests.push((custom_id.to_string(), chat_completion));
+      Ok(self)
+    }
+  }
+
+  pub fn len(&self) -> usize {
+    self.requests.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.requests.is_empty()
+  }
+
+  /// Serialize the requests into the JSONL format of a batch input file, one request
+  /// per line, for the given model or Azure OpenAI global batch deployment.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if there is no request, if there are more
+  /// than 50000 requests or if one of the chat completions is invalid.
+  ///
+  pub fn to_jsonl(&self, client: &Client, model_id: &str) -> Result<String, Error> {
+    if self.requests.is_empty() {
+      return Err(BatchError::EmptyInput.into());
+    }
+    if self.requests.len() > MAX_REQUESTS {
+      return Err(BatchError::TooManyRequests(self.requests.len()).into());
+    }
+    let url: &str = BatchEndpoint::ChatCompletions.url(client);
+    let mut jsonl: String = String::new();
+    for (custom_id, chat_completion) in &self.requests {
+      let line = serde_json::json!({
+        "custom_id": custom_id,
+        "method": "POST",
+        "url": url,
+        "body": chat_completion.batch_body(model_id)?,
+      });
+      jsonl.push_str(&serde_json::to_string(&line)?);
+      jsonl.push('\n');
+    }
+    Ok(jsonl)
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use crate::openai::ApiType;
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use crate::openai::chat::error::ChatCompletionError;
+  use crate::openai::chat::model::ChatMessageBuilder;
+  use super::*;
+
+  fn create_chat_completion(content: &str) -> ChatCompletion {
+    ChatCompletion::new()
+      .messages(ChatMessageBuilder::new().user(content.to_string()).build())
+  }
+
+  #[test]
+  fn it_should_serialize_the_requests_into_jsonl() {
+    let input: BatchInput = BatchInput::new()
+      .request("note-1", create_chat_completion("Summarize note 1")).unwrap()
+      .request("note-2", create_chat_completion("Summarize note 2")).unwrap();
+    let openai_client: Client = Client::new_openai_client(create_auth_with_given_api_key("12345abcd"));
+    let lines: Vec<serde_json::Value> = input.to_jsonl(&openai_client, "gpt-4o-mini").unwrap()
+      .lines()
+      .map(|line| serde_json::from_str(line).unwrap())
+      .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], serde_json::json!({"custom_id": "note-1", "method": "POST", "url": "/v1/chat/completions", "body": {"model": "gpt-4o-mini", "messages": [{"role": "user", "content": "Summarize note 1"}]}}));
+
+    let azure_client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), "https://resource.openai.azure.com/", ApiType::Azure);
+    let jsonl: String = input.to_jsonl(&azure_client, "gpt-4o-batch").unwrap();
+    assert!(jsonl.starts_with(r#"{"body":{"messages":[{"content":"Summarize note 1","role":"user"}],"model":"gpt-4o-batch"},"custom_id":"note-1","method":"POST","url":"/chat/completions"}"#));
+  }
+
+  #[test]
+  fn it_should_return_an_error_when_the_input_is_invalid() {
+    let client: Client = Client::new_openai_client(create_auth_with_given_api_key("12345abcd"));
+    assert!(matches!(BatchInput::new().to_jsonl(&client, "gpt-4o-mini"), Err(Error::BatchValidationError(BatchError::EmptyInput))));
+    assert_eq!(BatchInput::new().request("", create_chat_completion("Hello")).unwrap_err(), BatchError::EmptyCustomId);
+    let error: BatchError = BatchInput::new()
+      .request("note-1", create_chat_completion("Hello")).unwrap()
+      .request("note-1", create_chat_completion("Hello")).unwrap_err();
+    assert_eq!(error, BatchError::DuplicateCustomId(String::from("note-1")));
+    let input: BatchInput = BatchInput::new().request("note-1", ChatCompletion::new()).unwrap();
+    assert!(matches!(input.to_jsonl(&client, "gpt-4o-mini"), Err(Error::ChatCompletionValidationError(ChatCompletionError::EmptyMessages))));
+  }
+}
@@ -0,0 +1,44 @@
+use std::error::Error;
+use std::fmt::{Display, Debug};
+
+#[derive(Clone, PartialEq)]
+pub enum BatchError {
+  DuplicateCustomId(String),
+  EmptyCustomId,
+  EmptyInput,
+  TooManyRequests(usize),
+}
+
+impl BatchError {
+  fn label(&self) -> &'static str {
+    match self {
+      BatchError::DuplicateCustomId(_) => "DuplicateCustomId",
+      BatchError::EmptyCustomId => "EmptyCustomId",
+      BatchError::EmptyInput => "EmptyInput",
+      BatchError::TooManyRequests(_) => "TooManyRequests",
+    }
+  }
+
+  fn error_message(&self) -> String {
+    match self {
+      BatchError::DuplicateCustomId(custom_id) => format!("Custom ID must be unique in a batch [Custom ID: {}]", custom_id),
+      BatchError::EmptyCustomId => String::from("Custom ID cannot be empty."),
+      BatchError::EmptyInput => String::from("Batch must have at least one request."),
+      BatchError::TooManyRequests(requests) => format!("Batch must have at most 50000 requests [Number of requests: {}]", requests),
+    }
+  }
+}
+
+impl Debug for BatchError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "[{}] {}", self.label(), self.error_message())
+  }
+}
+
+impl Display for BatchError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.error_message())
+  }
+}
+
+impl Error for BatchError {}
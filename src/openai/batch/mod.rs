@@ -0,0 +1,8 @@
+pub mod error;
+pub mod model;
+
+mod batch_api;
+mod batch_input;
+
+pub use batch_api::{cancel, collect_results, create, list, retrieve, run, wait};
+pub use batch_input::BatchInput;
@@ -0,0 +1,24 @@
+use crate::openai::{ApiType, Client};
+
+/// Endpoint to which all the requests of a batch are sent.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum BatchEndpoint {
+  ChatCompletions,
+  Completions,
+  Embeddings,
+}
+
+impl BatchEndpoint {
+  /// Return the URL of the endpoint, relative to the API version for OpenAI and to
+  /// the resource for Azure OpenAI.
+  pub(crate) fn url(&self, client: &Client) -> &'static str {
+    match (self, client.api_type == ApiType::OpenAI) {
+      (BatchEndpoint::ChatCompletions, true) => "/v1/chat/completions",
+      (BatchEndpoint::ChatCompletions, false) => "/chat/completions",
+      (BatchEndpoint::Completions, true) => "/v1/completions",
+      (BatchEndpoint::Completions, false) => "/completions",
+      (BatchEndpoint::Embeddings, true) => "/v1/embeddings",
+      (BatchEndpoint::Embeddings, false) => "/embeddings",
+    }
+  }
+}
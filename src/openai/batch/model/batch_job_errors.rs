@@ -0,0 +1,16 @@
+use serde::{Serialize, Deserialize};
+
+/// Errors found while validating the input file of a batch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchJobErrors {
+  pub object: Option<String>,
+  pub data: Vec<BatchJobError>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchJobError {
+  pub code: Option<String>,
+  pub message: Option<String>,
+  pub param: Option<String>,
+  pub line: Option<u64>,
+}
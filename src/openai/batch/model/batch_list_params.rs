@@ -0,0 +1,39 @@
+/// Query parameters to paginate the list of batches.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BatchListParams {
+  after: Option<String>,
+  limit: Option<u32>,
+}
+
+impl BatchListParams {
+  pub fn new() -> BatchListParams {
+    BatchListParams::default()
+  }
+
+  /// Set the ID of the last batch of the previous page.
+  pub fn after(mut self, after: String) -> BatchListParams {
+    if after.is_empty() {
+      self.after = None;
+    } else {
+      self.after = Some(after);
+    }
+    self
+  }
+
+  /// Set the number of batches per page, 20 by default.
+  pub fn limit(mut self, limit: u32) -> BatchListParams {
+    self.limit = Some(limit);
+    self
+  }
+
+  pub(crate) fn to_query(&self) -> Vec<(&'static str, String)> {
+    let mut query: Vec<(&'static str, String)> = Vec::new();
+    if let Some(after) = &self.after {
+      query.push(("after", after.clone()));
+    }
+    if let Some(limit) = self.limit {
+      query.push(("limit", limit.to_string()));
+    }
+    query
+  }
+}
@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+
+use serde::{Serialize, Deserialize};
+
+use crate::openai::batch::model::{BatchJobErrors, BatchRequestCounts, BatchStatus};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Batch {
+  pub id: String,
+  pub object: String,
+  pub endpoint: String,
+  pub errors: Option<BatchJobErrors>,
+  pub input_file_id: String,
+  pub completion_window: String,
+  pub status: BatchStatus,
+  pub output_file_id: Option<String>,
+  pub error_file_id: Option<String>,
+  pub created_at: u64,
+  pub in_progress_at: Option<u64>,
+  pub expires_at: Option<u64>,
+  pub finalizing_at: Option<u64>,
+  pub completed_at: Option<u64>,
+  pub failed_at: Option<u64>,
+  pub expired_at: Option<u64>,
+  pub cancelling_at: Option<u64>,
+  pub cancelled_at: Option<u64>,
+  pub request_counts: Option<BatchRequestCounts>,
+  pub metadata: Option<HashMap<String, String>>,
+}
@@ -0,0 +1,8 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchRequestCounts {
+  pub total: u64,
+  pub completed: u64,
+  pub failed: u64,
+}
@@ -0,0 +1,10 @@
+use serde::{Serialize, Deserialize};
+
+/// Error of a request of a batch, either returned by the API with the given status
+/// code or raised before the request was sent, e.g. when the batch expired.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchRequestError {
+  pub status_code: Option<u16>,
+  pub code: Option<String>,
+  pub message: String,
+}
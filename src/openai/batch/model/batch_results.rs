@@ -0,0 +1,12 @@
+use std::collections::HashMap;
+
+use crate::openai::batch::model::{Batch, BatchRequestError};
+use crate::openai::chat::model::ChatCompletionResponse;
+
+/// Results of a finished batch, keyed by the custom ID of each request. A request
+/// without a result was not processed, e.g. because the batch was cancelled.
+#[derive(Debug)]
+pub struct BatchResults {
+  pub batch: Batch,
+  pub responses: HashMap<String, Result<ChatCompletionResponse, BatchRequestError>>,
+}
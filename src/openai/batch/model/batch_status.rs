@@ -0,0 +1,21 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchStatus {
+  Validating,
+  Failed,
+  InProgress,
+  Finalizing,
+  Completed,
+  Expired,
+  Cancelling,
+  Cancelled,
+}
+
+impl BatchStatus {
+  /// Return true if the status will not change anymore.
+  pub fn is_terminal(&self) -> bool {
+    matches!(self, BatchStatus::Failed | BatchStatus::Completed | BatchStatus::Expired | BatchStatus::Cancelled)
+  }
+}
@@ -0,0 +1,19 @@
+mod batch_endpoint;
+mod batch_job_errors;
+mod batch_list;
+mod batch_list_params;
+mod batch_object;
+mod batch_request_counts;
+mod batch_request_error;
+mod batch_results;
+mod batch_status;
+
+pub use batch_endpoint::BatchEndpoint;
+pub use batch_job_errors::{BatchJobError, BatchJobErrors};
+pub use batch_list::BatchList;
+pub use batch_list_params::BatchListParams;
+pub use batch_object::Batch;
+pub use batch_request_counts::BatchRequestCounts;
+pub use batch_request_error::BatchRequestError;
+pub use batch_results::BatchResults;
+pub use batch_status::BatchStatus;
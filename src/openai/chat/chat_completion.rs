@@ -1,13 +1,21 @@
 use std::collections::HashMap;
+use std::pin::Pin;
 
+use futures::{Stream, StreamExt};
 use serde::{Serialize, Deserialize};
 
-use crate::openai::requestor::Requestor;
-use crate::openai::{Client, ApiType};
+use crate::openai::error::Error;
+use crate::openai::event_stream;
+use crate::openai::requestor::{Requestor, decode_json};
+use crate::openai::{Client, Operation};
 use crate::openai::chat::error::ChatCompletionError;
-use crate::openai::chat::model::{FunctionDefinition, ChatMessage, ChatCompletionResponse};
+use crate::openai::chat::ToolRegistry;
+use crate::openai::chat::model::{FunctionCall, FunctionDefinition, ChatMessage, ChatCompletionChunk, ChatCompletionResponse, Role, Tool, ToolCall, ToolChoice, ToolRun};
+use crate::openai::moderations::Moderation;
+use crate::openai::moderations::model::ModerationResponse;
 
-const API_PATH: &str = "chat/completions";
+/// Stream of the chunks of a chat completion created with [`ChatCompletion::create_stream()`].
+pub type ChatCompletionStream = Pin<Box<dyn Stream<Item = Result<ChatCompletionChunk, Error>> + Send>>;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ChatCompletion {
@@ -38,6 +46,20 @@ pub struct ChatCompletion {
   function_call: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   functions: Option<Vec<FunctionDefinition>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  tools: Option<Vec<Tool>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  tool_choice: Option<ToolChoice>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  parallel_tool_calls: Option<bool>,
+  #[serde(skip)]
+  moderation_model: Option<String>,
+}
+
+impl Default for ChatCompletion {
+  fn default() -> Self {
+    Self::new()
+  }
 }
 
 impl ChatCompletion {
@@ -57,11 +79,15 @@ impl ChatCompletion {
       user: None,
       function_call: None,
       functions: None,
+      tools: None,
+      tool_choice: None,
+      parallel_tool_calls: None,
+      moderation_model: None,
     }
   }
 
   pub fn message(mut self, message: ChatMessage) -> Result<ChatCompletion, ChatCompletionError> {
-    if message.content.is_empty() {
+    if !message.has_content() {
       Err(ChatCompletionError::EmptyMessageContent)
     } else {
       self.messages.push(message);
@@ -75,7 +101,7 @@ impl ChatCompletion {
   }
 
   pub fn temperature(mut self, temperature: f32) -> Result<ChatCompletion, ChatCompletionError> {
-    if temperature < 0.0 || temperature > 2.0 {
+    if !(0.0..=2.0).contains(&temperature) {
       Err(ChatCompletionError::TemperatureValueOutOfRange(temperature))
     } else {
       self.temperature = Some(temperature);
@@ -84,7 +110,7 @@ impl ChatCompletion {
   }
 
   pub fn top_p(mut self, top_p: f32) -> Result<ChatCompletion, ChatCompletionError> {
-    if top_p < 0.0 || top_p > 1.0 {
+    if !(0.0..=1.0).contains(&top_p) {
       Err(ChatCompletionError::TopPValueOutOfRange(top_p))
     } else {
       self.top_p = Some(top_p);
@@ -120,7 +146,7 @@ impl ChatCompletion {
   }
 
   pub fn presence_penalty(mut self, presence_penalty: f32) -> Result<ChatCompletion, ChatCompletionError> {
-    if presence_penalty < -2.0 || presence_penalty > 2.0 {
+    if !(-2.0..=2.0).contains(&presence_penalty) {
       Err(ChatCompletionError::PresencePenaltyValueOutOfRange(presence_penalty))
     } else {
       self.presence_penalty = Some(presence_penalty);
@@ -129,7 +155,7 @@ impl ChatCompletion {
   }
 
   pub fn frequency_penalty(mut self, frequency_penalty: f32) -> Result<ChatCompletion, ChatCompletionError> {
-    if frequency_penalty < -2.0 || frequency_penalty > 2.0 {
+    if !(-2.0..=2.0).contains(&frequency_penalty) {
       Err(ChatCompletionError::FrequencyPenaltyValueOutOfRange(frequency_penalty))
     } else {
       self.frequency_penalty = Some(frequency_penalty);
@@ -173,19 +199,687 @@ impl ChatCompletion {
     self
   }
 
-  pub async fn create(&mut self, client: Client, model_id: &str, api_version: Option<&str>) -> Result<ChatCompletionResponse, Box<dyn std::error::Error>> {
+  /// Set the tools the model may call. They replace the deprecated functions,
+  /// which are still sent when set.
+  pub fn tools(mut self, tools: Vec<Tool>) -> ChatCompletion {
+    if tools.is_empty() {
+      self.tools = None;
+    } else {
+      self.tools = Some(tools);
+    }
+    self
+  }
+
+  pub fn tool_choice(mut self, tool_choice: ToolChoice) -> ChatCompletion {
+    self.tool_choice = Some(tool_choice);
+    self
+  }
+
+  /// Allow or prevent the model from calling several tools in a single response.
+  pub fn parallel_tool_calls(mut self, parallel_tool_calls: bool) -> ChatCompletion {
+    self.parallel_tool_calls = Some(parallel_tool_calls);
+    self
+  }
+
+  /// Run the user messages through the given moderation model, e.g.
+  /// `omni-moderation-latest`, before sending them. When one of them is flagged,
+  /// the chat completion is not requested and a [`Error::ContentFlagged`] error is
+  /// returned. The guard is only available with an OpenAI client: Azure OpenAI has
+  /// no moderations endpoint, its content filters already run on each request.
+  pub fn moderation(mut self, moderation_model: String) -> ChatCompletion {
+    if moderation_model.is_empty() {
+      self.moderation_model = None;
+    } else {
+      self.moderation_model = Some(moderation_model);
+    }
+    self
+  }
+
+  /// Create a chat completion. The request is not modified, so it can be sent
+  /// many times, concurrently, with the same or with different clients.
+  /// 
+  /// # Errors
+  /// 
+  /// This function will return an error if the messages are empty, if stream is
+  /// enabled, use [`Self::create_stream()`] instead, if a user message is flagged
+  /// by the moderation, or if the request fails.
+  /// 
+  pub async fn create(&self, client: &Client, model_id: &str, api_version: Option<&str>) -> Result<ChatCompletionResponse, Error> {
+    if self.stream == Some(true) {
+      return Err(ChatCompletionError::StreamEnabled.into());
+    }
+    let request_body = self.request_body(client, model_id, false)?;
+    self.moderate(client, api_version).await?;
+    // We can call with model id both OpenAI and Azure OpenAI the requestor will handle the logic
+    let response = client.post(Operation::ChatCompletions, &request_body, Some(model_id), api_version).await?;
+    decode_json::<ChatCompletionResponse>(response).await
+  }
+
+  /// Create a chat completion with stream enabled and return the stream of the
+  /// chunks sent by the API as server-sent events. The stream ends when the
+  /// `[DONE]` message is received.
+  /// 
+  /// # Errors
+  /// 
+  /// This function will return an error if the messages are empty, if a user message
+  /// is flagged by the moderation, or if the request fails. Each item of the stream
+  /// is an error if the chunk cannot be read or parsed.
+  /// 
+  pub async fn create_stream(&self, client: &Client, model_id: &str, api_version: Option<&str>) -> Result<ChatCompletionStream, Error> {
+    let request_body = self.request_body(client, model_id, true)?;
+    self.moderate(client, api_version).await?;
+    let response = client.post(Operation::ChatCompletions, &request_body, Some(model_id), api_version).await?;
+    let chunks = event_stream::data_events(response)
+      .map(|data| -> Result<ChatCompletionChunk, Error> {
+        let chunk = serde_json::from_str::<ChatCompletionChunk>(&data?)?;
+        Ok(chunk)
+      });
+    Ok(Box::pin(chunks))
+  }
+
+  /// Create chat completions until the model answers without calling a function.
+  /// The functions called by the model in the first choice are run by the
+  /// handlers of the registry, in parallel when it calls several tools at once,
+  /// and their results are appended to the messages of the next chat completion.
+  /// The functions of the registry are sent as the tools unless the tools or the
+  /// functions of the request are set.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the messages are empty, if stream is
+  /// enabled, if a user message is flagged by the moderation, if the model calls
+  /// a function without handler, if the model is still calling functions after
+  /// the maximum number of iterations of the registry, or if a request fails.
+  ///
+  pub async fn run_with_tools(&self, client: &Client, tool_registry: &ToolRegistry, model_id: &str, api_version: Option<&str>) -> Result<ToolRun, Error> {
+    if self.stream == Some(true) {
+      return Err(ChatCompletionError::StreamEnabled.into());
+    }
+    let mut request_body: serde_json::Value = self.request_value(client, model_id, false)?;
+    let tools: Vec<Tool> = tool_registry.tools();
+    if self.tools.is_none() && self.functions.is_none() && !tools.is_empty() {
+      request_body["tools"] = serde_json::to_value(tools)?;
+    }
+    self.moderate(client, api_version).await?;
+    let mut messages: Vec<ChatMessage> = self.messages.clone();
+    for iteration in 1..=tool_registry.get_max_iterations() {
+      request_body["messages"] = serde_json::to_value(&messages)?;
+      let response = client.post(Operation::ChatCompletions, &serde_json::to_string(&request_body)?, Some(model_id), api_version).await?;
+      let chat_completion_response: ChatCompletionResponse = decode_json::<ChatCompletionResponse>(response).await?;
+      let message: ChatMessage = match chat_completion_response.choices.first() {
+        Some(choice) => choice.message.clone(),
+        None => return Ok(ToolRun {response: chat_completion_response, messages, iterations: iteration}),
+      };
+      let tool_calls: Vec<ToolCall> = message.tool_calls.clone().unwrap_or_default();
+      let function_call: Option<FunctionCall> = message.function_call.clone();
+      messages.push(message);
+      if !tool_calls.is_empty() {
+        let contents: Vec<String> = futures::future::try_join_all(tool_calls.iter()
+          .map(|tool_call| tool_registry.call(&tool_call.function.name, &tool_call.function.arguments))
+        ).await?;
+        for (tool_call, content) in tool_calls.into_iter().zip(contents) {
+          let mut tool_message: ChatMessage = ChatMessage::new(Role::Tool, content);
+          tool_message.tool_call_id = Some(tool_call.id);
+          messages.push(tool_message);
+        }
+      } else if let Some(function_call) = function_call {
+        let content: String = tool_registry.call(&function_call.name, &function_call.arguments).await?;
+        let mut function_message: ChatMessage = ChatMessage::new(Role::Function, content);
+        function_message.name = Some(function_call.name);
+        messages.push(function_message);
+      } else {
+        return Ok(ToolRun {response: chat_completion_response, messages, iterations: iteration});
+      }
+      // A forced tool choice would make the model call the tools again after each result
+      for choice_field in ["tool_choice", "function_call"] {
+        if request_body.get(choice_field).is_some() {
+          request_body[choice_field] = serde_json::Value::from("auto");
+        }
+      }
+    }
+    Err(ChatCompletionError::MaxIterationsReached(tool_registry.get_max_iterations()).into())
+  }
+
+  /// Run the user messages through the moderation model when it is set and return
+  /// an error with the first flagged result.
+  async fn moderate(&self, client: &Client, api_version: Option<&str>) -> Result<(), Error> {
+    let moderation_model: &str = match &self.moderation_model {
+      Some(moderation_model) => moderation_model,
+      None => return Ok(()),
+    };
+    Moderation::check_api_type(client)?;
+    let inputs: Vec<String> = self.messages.iter()
+      // Empty contents are not accepted by the moderation API and cannot be flagged
+      .filter(|message| message.role == Role::User && !message.content.is_empty())
+      .map(|message| message.content.clone())
+      .collect();
+    if inputs.is_empty() {
+      return Ok(());
+    }
+    let moderation_response: ModerationResponse = Moderation::new().inputs(inputs).create(client, moderation_model, api_version).await?;
+    match moderation_response.results.into_iter().find(|result| result.flagged) {
+      Some(result) => Err(Error::ContentFlagged(Box::new(result))),
+      None => Ok(()),
+    }
+  }
+
+  fn request_body(&self, client: &Client, model_id: &str, stream: bool) -> Result<String, Error> {
+    let request_body: serde_json::Value = self.request_value(client, model_id, stream)?;
+    // Serialize the body to a string to be sent to the API
+    Ok(serde_json::to_string(&request_body)?)
+  }
+
+  fn request_value(&self, client: &Client, model_id: &str, stream: bool) -> Result<serde_json::Value, Error> {
     if self.messages.is_empty() {
       return Err(ChatCompletionError::EmptyMessages.into());
     }
-    // Model id is required only for Open AI as it needs to be in the body. Not required for Azure OpenAI
-    if client.api_type == ApiType::OpenAI {
-      self.model = Some(model_id.to_string());
+    let mut request_body: serde_json::Value = serde_json::to_value(self)?;
+    client.add_model_to_body(&mut request_body, model_id);
+    if stream {
+      request_body["stream"] = serde_json::Value::from(true);
     }
-    // Serialize the body to a string to be sent to the API
-    let request_body = serde_json::to_string(self)?;
-    // We can call with model id both OpenAI and Azure OpenAI the requestor will handle the logic
-    let response = client.post(API_PATH, &request_body, Some(model_id), api_version).await?;
-    let chat_completion_response = response.json::<ChatCompletionResponse>().await?;
-    Ok(chat_completion_response)
+    Ok(request_body)
   }
-}
\ No newline at end of file
+
+  /// Return the body of the request in a batch input file. The model id is always
+  /// in the body as the requests of a batch are not sent to a deployment path.
+  pub(crate) fn batch_body(&self, model_id: &str) -> Result<serde_json::Value, Error> {
+    if self.messages.is_empty() {
+      return Err(ChatCompletionError::EmptyMessages.into());
+    }
+    if self.stream == Some(true) {
+      return Err(ChatCompletionError::StreamEnabled.into());
+    }
+    let mut request_body: serde_json::Value = serde_json::to_value(self)?;
+    request_body["model"] = serde_json::Value::from(model_id);
+    Ok(request_body)
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, body_partial_json};
+  use crate::openai::{ApiType, AzureApiSurface};
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use crate::openai::chat::model::{ChatMessageBuilder, FunctionCall, JsonSchema, ToolCall, ToolType};
+  use crate::openai::moderations::error::ModerationError;
+  use crate::openai::moderations::test_helpers::moderation_response_body;
+  use super::*;
+
+  const STREAM_BODY: &str = concat!(
+    "data: {\"id\":\"\",\"object\":\"\",\"created\":0,\"model\":\"\",\"prompt_filter_results\":[],\"choices\":[]}\n\n",
+    "data: {\"id\":\"chatcmpl-1\",\"object\":\"chat.completion.chunk\",\"created\":1694268190,\"model\":\"gpt-4\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"\"},\"finish_reason\":null}]}\n\n",
+    "data: {\"id\":\"chatcmpl-1\",\"object\":\"chat.completion.chunk\",\"created\":1694268190,\"model\":\"gpt-4\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"Hello\"},\"finish_reason\":null}]}\n\n",
+    "data: {\"id\":\"chatcmpl-1\",\"object\":\"chat.completion.chunk\",\"created\":1694268190,\"model\":\"gpt-4\",\"choices\":[{\"index\":0,\"delta\":{\"function_call\":{\"name\":\"get_weather\",\"arguments\":\"{\\\"city\"}},\"finish_reason\":null}]}\n\n",
+    "data: {\"id\":\"chatcmpl-1\",\"object\":\"chat.completion.chunk\",\"created\":1694268190,\"model\":\"gpt-4\",\"choices\":[{\"index\":0,\"delta\":{},\"finish_reason\":\"stop\"}]}\n\n",
+    "data: [DONE]\n\n",
+  );
+
+  fn create_chat_completion() -> ChatCompletion {
+    ChatCompletion::new()
+      .messages(ChatMessageBuilder::new().user(String::from("Hello")).build())
+  }
+
+  async fn collect_chunks(stream: ChatCompletionStream) -> Vec<ChatCompletionChunk> {
+    stream.map(|chunk| chunk.unwrap()).collect::<Vec<ChatCompletionChunk>>().await
+  }
+
+  #[tokio::test]
+  async fn it_should_stream_chat_completion_chunks_from_openai_api() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .and(body_partial_json(serde_json::json!({"model": "gpt-4", "stream": true})))
+      .respond_with(ResponseTemplate::new(200)
+        .insert_header("content-type", "text/event-stream")
+        .set_body_string(STREAM_BODY)
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let stream: ChatCompletionStream = create_chat_completion().create_stream(&client, "gpt-4", None).await.unwrap();
+    let chunks: Vec<ChatCompletionChunk> = collect_chunks(stream).await;
+    assert_eq!(chunks.len(), 5);
+    assert!(chunks[0].choices.is_empty());
+    assert_eq!(chunks[1].choices[0].delta.role, Some(Role::Assistant));
+    assert_eq!(chunks[2].choices[0].delta.content, Some(String::from("Hello")));
+    let function_call = chunks[3].choices[0].delta.function_call.clone().unwrap();
+    assert_eq!(function_call.name, Some(String::from("get_weather")));
+    assert_eq!(function_call.arguments, Some(String::from("{\"city")));
+    assert_eq!(chunks[4].choices[0].finish_reason, Some(String::from("stop")));
+  }
+
+  #[tokio::test]
+  async fn it_should_stream_chat_completion_chunks_from_azure_openai_api() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/openai/deployments/model-deployment-id/chat/completions"))
+      .and(body_partial_json(serde_json::json!({"stream": true})))
+      .respond_with(ResponseTemplate::new(200)
+        .insert_header("content-type", "text/event-stream")
+        .set_body_string(STREAM_BODY)
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let stream: ChatCompletionStream = create_chat_completion().create_stream(&client, "model-deployment-id", None).await.unwrap();
+    let chunks: Vec<ChatCompletionChunk> = collect_chunks(stream).await;
+    assert_eq!(chunks.len(), 5);
+    assert_eq!(chunks[4].choices[0].finish_reason, Some(String::from("stop")));
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_create_is_called_with_stream_enabled() {
+    let client: Client = Client::new_openai_client(create_auth_with_given_api_key("12345abcd"));
+    let result = create_chat_completion().stream(true).create(&client, "gpt-4", None).await;
+    match result {
+      Ok(_) => panic!("It should return an error"),
+      Err(error) => assert!(matches!(error, Error::ChatCompletionValidationError(ChatCompletionError::StreamEnabled))),
+    }
+  }
+
+  #[tokio::test]
+  async fn it_should_create_chat_completion_in_a_spawned_task() {
+    let body_response: &str = r#"{"id": "chatcmpl-1", "object": "chat.completion", "created": 1694268190, "model": "gpt-4", "choices": [{"index": 0, "message": {"role": "assistant", "content": "Hello"}, "finish_reason": "stop"}], "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}}"#;
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_string(body_response)
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let task = tokio::spawn(async move {
+      create_chat_completion().create(&client, "gpt-4", None).await
+    });
+    let chat_completion_response: ChatCompletionResponse = task.await.unwrap().unwrap();
+    assert_eq!(chat_completion_response.choices[0].message.content, "Hello");
+  }
+
+  #[tokio::test]
+  async fn it_should_share_the_client_and_the_request_between_concurrent_tasks() {
+    let body_response: &str = r#"{"id": "chatcmpl-1", "object": "chat.completion", "created": 1694268190, "model": "gpt-4", "choices": [{"index": 0, "message": {"role": "assistant", "content": "Hello"}, "finish_reason": "stop"}], "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}}"#;
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .and(body_partial_json(serde_json::json!({"model": "gpt-4"})))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_string(body_response)
+      )
+      .expect(4)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let chat_completion: Arc<ChatCompletion> = Arc::new(create_chat_completion());
+    let tasks: Vec<_> = (0..4).map(|_| {
+      let client: Client = client.clone();
+      let chat_completion: Arc<ChatCompletion> = chat_completion.clone();
+      tokio::spawn(async move {
+        chat_completion.create(&client, "gpt-4", None).await
+      })
+    }).collect();
+    for task in tasks {
+      let chat_completion_response: ChatCompletionResponse = task.await.unwrap().unwrap();
+      assert_eq!(chat_completion_response.choices[0].message.content, "Hello");
+    }
+    assert_eq!(chat_completion.model, None);
+    assert_eq!(chat_completion.stream, None);
+  }
+
+  #[tokio::test]
+  async fn it_should_send_the_model_in_the_body_with_the_azure_v1_api() {
+    let body_response: &str = r#"{"id": "chatcmpl-1", "object": "chat.completion", "created": 1694268190, "model": "gpt-4", "choices": [{"index": 0, "message": {"role": "assistant", "content": "Hello"}, "finish_reason": "stop"}], "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}}"#;
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/openai/v1/chat/completions"))
+      .and(body_partial_json(serde_json::json!({"model": "model-deployment-id"})))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_string(body_response)
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure)
+      .azure_api_surface(AzureApiSurface::V1);
+    let chat_completion_response: ChatCompletionResponse = create_chat_completion().create(&client, "model-deployment-id", None).await.unwrap();
+    assert_eq!(chat_completion_response.choices[0].message.content, "Hello");
+  }
+
+  #[tokio::test]
+  async fn it_should_not_send_the_messages_flagged_by_the_moderation() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/moderations"))
+      .and(body_partial_json(serde_json::json!({"model": "omni-moderation-latest", "input": ["Hello", "I will hurt you"]})))
+      .respond_with(ResponseTemplate::new(200).set_body_string(moderation_response_body(&[false, true])))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .respond_with(ResponseTemplate::new(200))
+      .expect(0)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let messages: Vec<ChatMessage> = ChatMessageBuilder::new()
+      .system(String::from("You are a helpful assistant."))
+      .user(String::from("Hello"))
+      .user(String::from("I will hurt you"))
+      .build();
+    let error: Error = ChatCompletion::new()
+      .messages(messages)
+      .moderation(String::from("omni-moderation-latest"))
+      .create(&client, "gpt-4", None).await.unwrap_err();
+    match error {
+      Error::ContentFlagged(result) => assert_eq!(result.categories.flagged(), vec!["violence", "violence/graphic"]),
+      error => panic!("Unexpected error: {:?}", error),
+    }
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_moderation_is_enabled_with_azure() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .respond_with(ResponseTemplate::new(200))
+      .expect(0)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let error: Error = create_chat_completion()
+      .moderation(String::from("omni-moderation-latest"))
+      .create(&client, "gpt-4", None).await.unwrap_err();
+    assert!(matches!(error, Error::ModerationValidationError(ModerationError::UnsupportedApiType(ApiType::Azure))));
+  }
+
+  #[tokio::test]
+  async fn it_should_send_the_messages_accepted_by_the_moderation() {
+    let body_response: &str = r#"{"id": "chatcmpl-1", "object": "chat.completion", "created": 1694268190, "model": "gpt-4", "choices": [{"index": 0, "message": {"role": "assistant", "content": "Hello"}, "finish_reason": "stop"}], "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}}"#;
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/moderations"))
+      .and(body_partial_json(serde_json::json!({"input": ["Hello"]})))
+      .respond_with(ResponseTemplate::new(200).set_body_string(moderation_response_body(&[false])))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(body_response))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let messages: Vec<ChatMessage> = ChatMessageBuilder::new()
+      .user(String::from(""))
+      .user(String::from("Hello"))
+      .build();
+    let chat_completion_response: ChatCompletionResponse = ChatCompletion::new()
+      .messages(messages)
+      .moderation(String::from("omni-moderation-latest"))
+      .create(&client, "gpt-4", None).await.unwrap();
+    assert_eq!(chat_completion_response.choices[0].message.content, "Hello");
+  }
+
+  #[tokio::test]
+  async fn it_should_send_tools_and_receive_parallel_tool_calls() {
+    let body_response: &str = r#"{"id": "chatcmpl-1", "object": "chat.completion", "created": 1694268190, "model": "gpt-4o", "choices": [{"index": 0, "message": {"role": "assistant", "content": null, "tool_calls": [{"id": "call_1", "type": "function", "function": {"name": "get_weather", "arguments": "{\"city\": \"Paris\"}"}}, {"id": "call_2", "type": "function", "function": {"name": "get_weather", "arguments": "{\"city\": \"Lyon\"}"}}]}, "finish_reason": "tool_calls"}], "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}}"#;
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .and(body_partial_json(serde_json::json!({
+        "tools": [{"type": "function", "function": {"name": "get_weather", "description": "Get the weather of a city", "parameters": {"type": "object", "properties": {"city": {"type": "string"}}, "required": ["city"], "additionalProperties": false}, "strict": true}}],
+        "tool_choice": "required",
+        "parallel_tool_calls": true,
+      })))
+      .respond_with(ResponseTemplate::new(200).set_body_string(body_response))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let get_weather: FunctionDefinition = FunctionDefinition::new("get_weather")
+      .description("Get the weather of a city")
+      .parameters(JsonSchema::object()
+        .required_property("city", JsonSchema::string())
+        .additional_properties(false)
+      )
+      .strict(true);
+    let chat_completion_response: ChatCompletionResponse = create_chat_completion()
+      .tools(vec![Tool::function(get_weather)])
+      .tool_choice(ToolChoice::Required)
+      .parallel_tool_calls(true)
+      .create(&client, "gpt-4o", None).await.unwrap();
+    let message: &ChatMessage = &chat_completion_response.choices[0].message;
+    assert_eq!(chat_completion_response.choices[0].finish_reason, "tool_calls");
+    assert_eq!(message.content, "");
+    let tool_calls: &Vec<ToolCall> = message.tool_calls.as_ref().unwrap();
+    assert_eq!(tool_calls.len(), 2);
+    assert_eq!(tool_calls[1].id, "call_2");
+    assert_eq!(tool_calls[1].function.name, "get_weather");
+    assert_eq!(tool_calls[1].function.parse_arguments::<serde_json::Value>().unwrap(), serde_json::json!({"city": "Lyon"}));
+  }
+
+  #[tokio::test]
+  async fn it_should_send_the_tool_messages_and_a_named_tool_choice() {
+    let body_response: &str = r#"{"id": "chatcmpl-2", "object": "chat.completion", "created": 1694268190, "model": "gpt-4o", "choices": [{"index": 0, "message": {"role": "assistant", "content": "It is sunny in Paris."}, "finish_reason": "stop"}], "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}}"#;
+    let tool_call: ToolCall = ToolCall {
+      id: String::from("call_1"),
+      tool_type: ToolType::Function,
+      function: FunctionCall::new("get_weather", "{\"city\": \"Paris\"}"),
+    };
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .and(body_partial_json(serde_json::json!({
+        "messages": [
+          {"role": "user", "content": "What is the weather in Paris?"},
+          {"role": "assistant", "content": "", "tool_calls": [{"id": "call_1", "type": "function", "function": {"name": "get_weather", "arguments": "{\"city\": \"Paris\"}"}}]},
+          {"role": "tool", "content": "Sunny", "tool_call_id": "call_1"},
+        ],
+        "tool_choice": {"type": "function", "function": {"name": "get_weather"}},
+      })))
+      .respond_with(ResponseTemplate::new(200).set_body_string(body_response))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let messages: Vec<ChatMessage> = ChatMessageBuilder::new()
+      .user(String::from("What is the weather in Paris?"))
+      .assistant_tool_calls(vec![tool_call])
+      .tool(String::from("Sunny"), String::from("call_1"))
+      .build();
+    let chat_completion_response: ChatCompletionResponse = ChatCompletion::new()
+      .messages(messages)
+      .tool_choice(ToolChoice::Function(String::from("get_weather")))
+      .create(&client, "gpt-4o", None).await.unwrap();
+    assert_eq!(chat_completion_response.choices[0].message.content, "It is sunny in Paris.");
+  }
+
+  #[test]
+  fn it_should_read_the_tool_call_fragments_of_a_chunk() {
+    let chunk: ChatCompletionChunk = serde_json::from_str(r#"{"id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1694268190, "model": "gpt-4o", "choices": [{"index": 0, "delta": {"tool_calls": [{"index": 1, "id": "call_2", "type": "function", "function": {"name": "get_weather", "arguments": ""}}]}, "finish_reason": null}]}"#).unwrap();
+    let tool_call_delta = &chunk.choices[0].delta.tool_calls.as_ref().unwrap()[0];
+    assert_eq!(tool_call_delta.index, 1);
+    assert_eq!(tool_call_delta.id, Some(String::from("call_2")));
+    assert_eq!(tool_call_delta.function.as_ref().unwrap().name, Some(String::from("get_weather")));
+    assert!(create_chat_completion().message(ChatMessageBuilder::new().assistant_tool_calls(Vec::new()).build().remove(0)).is_err());
+  }
+
+  fn tool_calls_response_body(tool_calls: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({"id": "chatcmpl-1", "object": "chat.completion", "created": 1694268190, "model": "gpt-4o", "choices": [{"index": 0, "message": {"role": "assistant", "content": null, "tool_calls": tool_calls}, "finish_reason": "tool_calls"}], "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}})
+  }
+
+  #[tokio::test]
+  async fn it_should_run_the_tools_in_parallel_until_the_final_answer() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .and(body_partial_json(serde_json::json!({"tools": [{"type": "function", "function": {"name": "get_weather"}}]})))
+      .respond_with(ResponseTemplate::new(200).set_body_json(tool_calls_response_body(serde_json::json!([
+        {"id": "call_1", "type": "function", "function": {"name": "get_weather", "arguments": "{\"city\": \"Paris\"}"}},
+        {"id": "call_2", "type": "function", "function": {"name": "get_weather", "arguments": "{\"city\": \"Lyon\"}"}},
+      ]))))
+      .up_to_n_times(1)
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .and(body_partial_json(serde_json::json!({
+        "messages": [
+          {"role": "user", "content": "Hello"},
+          {"role": "assistant", "content": "", "tool_calls": [{"id": "call_1"}, {"id": "call_2"}]},
+          {"role": "tool", "content": "Sunny in Paris", "tool_call_id": "call_1"},
+          {"role": "tool", "content": "Sunny in Lyon", "tool_call_id": "call_2"},
+        ],
+      })))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_string(r#"{"id": "chatcmpl-2", "object": "chat.completion", "created": 1694268190, "model": "gpt-4o", "choices": [{"index": 0, "message": {"role": "assistant", "content": "It is sunny in Paris and Lyon."}, "finish_reason": "stop"}], "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}}"#)
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    // Both handlers wait for each other, so the run only ends if they are called in parallel
+    let barrier: Arc<tokio::sync::Barrier> = Arc::new(tokio::sync::Barrier::new(2));
+    let tool_registry: ToolRegistry = ToolRegistry::new()
+      .register(FunctionDefinition::new("get_weather"), move |arguments: String| {
+        let barrier: Arc<tokio::sync::Barrier> = barrier.clone();
+        async move {
+          barrier.wait().await;
+          let arguments: serde_json::Value = serde_json::from_str(&arguments).map_err(|error| error.to_string())?;
+          Ok(format!("Sunny in {}", arguments["city"].as_str().unwrap_or_default()))
+        }
+      });
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let tool_run: ToolRun = tokio::time::timeout(std::time::Duration::from_secs(5), create_chat_completion().run_with_tools(&client, &tool_registry, "gpt-4o", None))
+      .await.unwrap().unwrap();
+    assert_eq!(tool_run.iterations, 2);
+    assert_eq!(tool_run.response.choices[0].message.content, "It is sunny in Paris and Lyon.");
+    assert_eq!(tool_run.messages.len(), 5);
+    assert_eq!(tool_run.messages[4].role, Role::Assistant);
+  }
+
+  #[tokio::test]
+  async fn it_should_let_the_model_choose_after_running_the_tools_of_a_forced_tool_choice() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .and(body_partial_json(serde_json::json!({"tool_choice": "required"})))
+      .respond_with(ResponseTemplate::new(200).set_body_json(tool_calls_response_body(serde_json::json!([
+        {"id": "call_1", "type": "function", "function": {"name": "get_weather", "arguments": "{\"city\": \"Paris\"}"}},
+      ]))))
+      .up_to_n_times(1)
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .and(body_partial_json(serde_json::json!({"tool_choice": "auto"})))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_string(r#"{"id": "chatcmpl-2", "object": "chat.completion", "created": 1694268190, "model": "gpt-4o", "choices": [{"index": 0, "message": {"role": "assistant", "content": "It is sunny in Paris."}, "finish_reason": "stop"}], "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}}"#)
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let tool_registry: ToolRegistry = ToolRegistry::new()
+      .register(FunctionDefinition::new("get_weather"), |_arguments: String| async move {
+        Ok(String::from("Sunny"))
+      });
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let tool_run: ToolRun = create_chat_completion()
+      .tool_choice(ToolChoice::Required)
+      .run_with_tools(&client, &tool_registry, "gpt-4o", None).await.unwrap();
+    assert_eq!(tool_run.iterations, 2);
+    assert_eq!(tool_run.response.choices[0].message.content, "It is sunny in Paris.");
+  }
+
+  #[tokio::test]
+  async fn it_should_answer_a_function_call_with_a_function_message() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_string(r#"{"id": "chatcmpl-1", "object": "chat.completion", "created": 1694268190, "model": "gpt-4", "choices": [{"index": 0, "message": {"role": "assistant", "content": null, "function_call": {"name": "get_time", "arguments": ""}}, "finish_reason": "function_call"}], "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}}"#)
+      )
+      .up_to_n_times(1)
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .and(body_partial_json(serde_json::json!({
+        "messages": [
+          {"role": "user", "content": "Hello"},
+          {"role": "assistant", "content": "", "function_call": {"name": "get_time", "arguments": ""}},
+          {"role": "function", "content": "Error: Clock unavailable", "name": "get_time"},
+        ],
+        "functions": [{"name": "get_time"}],
+      })))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_string(r#"{"id": "chatcmpl-2", "object": "chat.completion", "created": 1694268190, "model": "gpt-4", "choices": [{"index": 0, "message": {"role": "assistant", "content": "I cannot tell the time."}, "finish_reason": "stop"}], "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}}"#)
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let tool_registry: ToolRegistry = ToolRegistry::new()
+      .register(FunctionDefinition::new("get_time"), |_arguments: String| async move {
+        Err(String::from("Clock unavailable"))
+      });
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let tool_run: ToolRun = create_chat_completion()
+      .functions(vec![FunctionDefinition::new("get_time")])
+      .run_with_tools(&client, &tool_registry, "gpt-4", None).await.unwrap();
+    assert_eq!(tool_run.iterations, 2);
+    assert_eq!(tool_run.response.choices[0].message.content, "I cannot tell the time.");
+    let requests = mock_server.received_requests().await.unwrap();
+    assert!(requests[1].body_json::<serde_json::Value>().unwrap().get("tools").is_none());
+  }
+
+  #[tokio::test]
+  async fn it_should_stop_calling_the_tools_after_the_max_iterations() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .respond_with(ResponseTemplate::new(200).set_body_json(tool_calls_response_body(serde_json::json!([
+        {"id": "call_1", "type": "function", "function": {"name": "get_time", "arguments": "{}"}},
+      ]))))
+      .expect(3)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let tool_registry: ToolRegistry = ToolRegistry::new()
+      .register(FunctionDefinition::new("get_time"), |_arguments: String| async move {
+        Ok(String::from("12:00"))
+      })
+      .max_iterations(2).unwrap();
+    let result = create_chat_completion().run_with_tools(&client, &tool_registry, "gpt-4o", None).await;
+    assert!(matches!(result, Err(Error::ChatCompletionValidationError(ChatCompletionError::MaxIterationsReached(2)))));
+
+    let result = create_chat_completion().run_with_tools(&client, &ToolRegistry::new(), "gpt-4o", None).await;
+    assert!(matches!(result, Err(Error::ChatCompletionValidationError(ChatCompletionError::UnknownFunction(name))) if name == "get_time"));
+  }
+}
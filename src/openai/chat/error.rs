@@ -6,10 +6,15 @@ pub enum ChatCompletionError {
   EmptyMessageContent,
   EmptyMessages,
   FrequencyPenaltyValueOutOfRange(f32),
+  InvalidFunctionArguments {name: String, arguments: String, reason: String},
+  MaxIterationsOutOfRange(u32),
+  MaxIterationsReached(u32),
   PresencePenaltyValueOutOfRange(f32),
   StopSequencesOutOfRange(usize),
+  StreamEnabled,
   TemperatureValueOutOfRange(f32),
   TopPValueOutOfRange(f32),
+  UnknownFunction(String),
   
 }
 
@@ -19,10 +24,15 @@ impl ChatCompletionError {
       ChatCompletionError::EmptyMessageContent => "EmptyMessageContent",
       ChatCompletionError::EmptyMessages => "EmptyMessages",
       ChatCompletionError::FrequencyPenaltyValueOutOfRange(_) => "FrequencyPenaltyValueOutOfRange",
+      ChatCompletionError::InvalidFunctionArguments {..} => "InvalidFunctionArguments",
+      ChatCompletionError::MaxIterationsOutOfRange(_) => "MaxIterationsOutOfRange",
+      ChatCompletionError::MaxIterationsReached(_) => "MaxIterationsReached",
       ChatCompletionError::PresencePenaltyValueOutOfRange(_) => "PresencePenaltyValueOutOfRange",
       ChatCompletionError::StopSequencesOutOfRange(_) => "StopSequencesOutOfRange",
+      ChatCompletionError::StreamEnabled => "StreamEnabled",
       ChatCompletionError::TemperatureValueOutOfRange(_) => "TemperatureValueOutOfRange",
       ChatCompletionError::TopPValueOutOfRange(_) => "TopPValueOutOfRange",
+      ChatCompletionError::UnknownFunction(_) => "UnknownFunction",
     }
   }
 
@@ -31,10 +41,15 @@ impl ChatCompletionError {
       ChatCompletionError::EmptyMessageContent => String::from("Message content cannot be empty"),
       ChatCompletionError::EmptyMessages => String::from("Messages cannot be empty."),
       ChatCompletionError::FrequencyPenaltyValueOutOfRange(frequency_penalty) => format!("Frequency penalty value must be between -2.0 and 2.0 [Given value: {}]", frequency_penalty),
+      ChatCompletionError::InvalidFunctionArguments {name, arguments, reason} => format!("Arguments of function {} are not valid: {} [Arguments: {}]", name, reason, arguments),
+      ChatCompletionError::MaxIterationsOutOfRange(max_iterations) => format!("Max iterations must be greater than 0 [Given value: {}]", max_iterations),
+      ChatCompletionError::MaxIterationsReached(max_iterations) => format!("The model was still calling tools after the maximum number of iterations [Max iterations: {}]", max_iterations),
       ChatCompletionError::PresencePenaltyValueOutOfRange(presence_penalty) => format!("Presence penalty value must be between -2.0 and 2.0 [Given value: {}]", presence_penalty),
       ChatCompletionError::StopSequencesOutOfRange(sequences_count) => format!("Stop value must have between 0 and 4 sequences [Number of sequences: {}]", sequences_count),
+      ChatCompletionError::StreamEnabled => String::from("Stream is enabled, use create_stream to receive the chat completion chunks."),
       ChatCompletionError::TemperatureValueOutOfRange(temperature) => format!("Temperature value must be between 0.0 and 2.0 [Given value: {}]", temperature),
       ChatCompletionError::TopPValueOutOfRange(top_p) => format!("Top P value must be between 0.0 and 1.0 [Given value: {}]", top_p),
+      ChatCompletionError::UnknownFunction(name) => format!("No handler is registered for the function called by the model [Function: {}]", name),
     }
   }
 }
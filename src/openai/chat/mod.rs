@@ -2,5 +2,10 @@ pub mod error;
 pub mod model;
 
 mod chat_completion;
+mod openai_function;
+mod tool_registry;
 
-pub use chat_completion::ChatCompletion;
+pub use chat_completion::{ChatCompletion, ChatCompletionStream};
+pub use openai_function::{OpenAiFunction, ToJsonSchema};
+pub use openai_rust_derive::{OpenAiFunction, ToJsonSchema};
+pub use tool_registry::ToolRegistry;
@@ -0,0 +1,20 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::chat::model::ChunkChoice;
+
+/// Chunk of a streamed chat completion. Azure OpenAI sends a first chunk
+/// without choices and with empty identifiers that only carries the prompt
+/// filter results, so these fields default to empty values.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatCompletionChunk {
+  #[serde(default)]
+  pub id: String,
+  #[serde(default)]
+  pub object: String,
+  #[serde(default)]
+  pub created: u64,
+  #[serde(default)]
+  pub model: String,
+  #[serde(default)]
+  pub choices: Vec<ChunkChoice>,
+}
@@ -1,21 +1,58 @@
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::openai::chat::model::{FunctionCall, Role};
+use crate::openai::chat::model::{FunctionCall, Role, ToolCall};
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct ChatMessage {
   pub role: Role,
+  /// Content of the message, empty when an assistant message only has tool calls.
+  #[serde(default, deserialize_with = "deserialize_content")]
   pub content: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   pub function_call: Option<FunctionCall>,
   #[serde(skip_serializing_if = "Option::is_none")]
   pub name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tool_calls: Option<Vec<ToolCall>>,
+  /// ID of the tool call answered by a tool message.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tool_call_id: Option<String>,
+}
+
+/// The content of an assistant message with tool calls is null.
+fn deserialize_content<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+  Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+impl ChatMessage {
+  pub(crate) fn new(role: Role, content: String) -> ChatMessage {
+    ChatMessage {
+      role,
+      content,
+      function_call: None,
+      name: None,
+      tool_calls: None,
+      tool_call_id: None,
+    }
+  }
+
+  /// Return true if the message has the content, the function call or the tool
+  /// calls expected for its role.
+  pub(crate) fn has_content(&self) -> bool {
+    !self.content.is_empty() || self.function_call.is_some() || self.tool_calls.as_ref().is_some_and(|tool_calls| !tool_calls.is_empty())
+  }
 }
 
 pub struct ChatMessageBuilder {
   messages: Vec<ChatMessage>,
 }
 
+impl Default for ChatMessageBuilder {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
 impl ChatMessageBuilder {
   pub fn new() -> ChatMessageBuilder {
     ChatMessageBuilder {
@@ -24,46 +61,46 @@ impl ChatMessageBuilder {
   }
 
   pub fn system(mut self, content: String) -> ChatMessageBuilder {
-    self.messages.push(ChatMessage {
-      role: Role::System,
-      content,
-      function_call: None,
-      name: None,
-    });
+    self.messages.push(ChatMessage::new(Role::System, content));
     self
   }
 
   pub fn assistant(mut self, content: String) -> ChatMessageBuilder {
-    self.messages.push(ChatMessage {
-      role: Role::Assistant,
-      content,
-      function_call: None,
-      name: None,
-    });
+    self.messages.push(ChatMessage::new(Role::Assistant, content));
+    self
+  }
+
+  /// Add an assistant message with the tool calls returned by the model, before
+  /// the tool messages with their results.
+  pub fn assistant_tool_calls(mut self, tool_calls: Vec<ToolCall>) -> ChatMessageBuilder {
+    let mut message: ChatMessage = ChatMessage::new(Role::Assistant, String::new());
+    message.tool_calls = Some(tool_calls);
+    self.messages.push(message);
     self
   }
 
   pub fn user(mut self, content: String) -> ChatMessageBuilder {
-    self.messages.push(ChatMessage {
-      role: Role::User,
-      content,
-      function_call: None,
-      name: None,
-    });
+    self.messages.push(ChatMessage::new(Role::User, content));
     self
   }
 
   pub fn function(mut self, content: String, function_call: FunctionCall, name: String) -> ChatMessageBuilder {
-    self.messages.push(ChatMessage {
-      role: Role::Function,
-      content,
-      function_call: Some(function_call),
-      name: Some(name),
-    });
+    let mut message: ChatMessage = ChatMessage::new(Role::Function, content);
+    message.function_call = Some(function_call);
+    message.name = Some(name);
+    self.messages.push(message);
+    self
+  }
+
+  /// Add a tool message with the result of the tool call with the given ID.
+  pub fn tool(mut self, content: String, tool_call_id: String) -> ChatMessageBuilder {
+    let mut message: ChatMessage = ChatMessage::new(Role::Tool, content);
+    message.tool_call_id = Some(tool_call_id);
+    self.messages.push(message);
     self
   }
 
   pub fn build(self) -> Vec<ChatMessage> {
     self.messages
   }
-}
\ No newline at end of file
+}
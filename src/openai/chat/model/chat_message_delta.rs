@@ -0,0 +1,18 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::chat::model::{FunctionCallDelta, Role, ToolCallDelta};
+
+/// Fragment of a chat message received in a streamed chat completion. The
+/// role is only sent in the first chunk, the content, the function call and
+/// the tool calls are sent in fragments that need to be concatenated.
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ChatMessageDelta {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub role: Option<Role>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub content: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub function_call: Option<FunctionCallDelta>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tool_calls: Option<Vec<ToolCallDelta>>,
+}
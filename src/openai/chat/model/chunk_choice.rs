@@ -0,0 +1,10 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::chat::model::ChatMessageDelta;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChunkChoice {
+  pub index: u16,
+  pub delta: ChatMessageDelta,
+  pub finish_reason: Option<String>,
+}
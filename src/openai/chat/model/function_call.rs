@@ -1,9 +1,88 @@
-use std::collections::HashMap;
-
 use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+
+use crate::openai::chat::error::ChatCompletionError;
 
+/// Call of a function by the model. The arguments are the JSON-encoded string
+/// generated by the model, kept as is since it is not always valid JSON.
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct FunctionCall {
   pub name: String,
-  pub arguments: HashMap<String, String>,
-}
\ No newline at end of file
+  pub arguments: String,
+}
+
+impl FunctionCall {
+  pub fn new(name: &str, arguments: &str) -> FunctionCall {
+    FunctionCall {
+      name: name.to_string(),
+      arguments: arguments.to_string(),
+    }
+  }
+
+  /// Deserialize the arguments into the given type. Empty arguments are read as
+  /// an empty object.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error containing the arguments if they are not
+  /// valid JSON or do not match the type.
+  ///
+  pub fn parse_arguments<T: DeserializeOwned>(&self) -> Result<T, ChatCompletionError> {
+    parse_arguments(&self.name, &self.arguments)
+  }
+}
+
+pub(crate) fn parse_arguments<T: DeserializeOwned>(name: &str, arguments: &str) -> Result<T, ChatCompletionError> {
+  let json: &str = if arguments.trim().is_empty() {
+    "{}"
+  } else {
+    arguments
+  };
+  serde_json::from_str(json).map_err(|error| ChatCompletionError::InvalidFunctionArguments {
+    name: name.to_string(),
+    arguments: arguments.to_string(),
+    reason: error.to_string(),
+  })
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use super::*;
+
+  #[derive(Debug, PartialEq, Deserialize)]
+  struct Route {
+    stops: Vec<String>,
+    days: u8,
+    options: HashMap<String, bool>,
+  }
+
+  #[test]
+  fn it_should_deserialize_the_arguments_encoded_as_a_string() {
+    let function_call: FunctionCall = serde_json::from_str(r#"{"name": "plan_route", "arguments": "{\"stops\": [\"Paris\", \"Lyon\"], \"days\": 3, \"options\": {\"tolls\": false}}"}"#).unwrap();
+    let route: Route = function_call.parse_arguments().unwrap();
+    assert_eq!(route, Route {
+      stops: vec![String::from("Paris"), String::from("Lyon")],
+      days: 3,
+      options: HashMap::from([(String::from("tolls"), false)]),
+    });
+    assert_eq!(serde_json::to_value(&function_call).unwrap()["arguments"], serde_json::json!(function_call.arguments));
+  }
+
+  #[test]
+  fn it_should_read_empty_arguments_as_an_empty_object() {
+    let arguments: HashMap<String, String> = FunctionCall::new("ping", "").parse_arguments().unwrap();
+    assert!(arguments.is_empty());
+  }
+
+  #[test]
+  fn it_should_return_an_error_containing_the_malformed_arguments() {
+    let result: Result<Route, ChatCompletionError> = FunctionCall::new("plan_route", "{\"stops\": [\"Paris\"").parse_arguments();
+    assert!(matches!(result, Err(ChatCompletionError::InvalidFunctionArguments {name, arguments, ..}) if name == "plan_route" && arguments == "{\"stops\": [\"Paris\""));
+  }
+}
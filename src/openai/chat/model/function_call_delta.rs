@@ -0,0 +1,12 @@
+use serde::{Serialize, Deserialize};
+
+/// Fragment of a function call received in a streamed chat completion. The
+/// name is sent in the first fragment and the arguments are sent as pieces of
+/// a JSON-encoded string.
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+pub struct FunctionCallDelta {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub arguments: Option<String>,
+}
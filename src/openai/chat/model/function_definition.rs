@@ -1,9 +1,83 @@
 use serde::{Serialize, Deserialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+use crate::openai::chat::model::JsonSchema;
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct FunctionDefinition {
   pub name: String,
-  pub desription: String,
-  // TODO Update to a JSON object
-  pub parameters: String,
-}
\ No newline at end of file
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub parameters: Option<JsonSchema>,
+  /// Make the model follow the schema of the parameters exactly. In strict mode,
+  /// every property must be required and additional properties forbidden, see
+  /// [`JsonSchema::strict()`].
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub strict: Option<bool>,
+}
+
+impl FunctionDefinition {
+  pub fn new(name: &str) -> FunctionDefinition {
+    FunctionDefinition {
+      name: name.to_string(),
+      description: None,
+      parameters: None,
+      strict: None,
+    }
+  }
+
+  pub fn description(mut self, description: &str) -> FunctionDefinition {
+    if description.is_empty() {
+      self.description = None;
+    } else {
+      self.description = Some(description.to_string());
+    }
+    self
+  }
+
+  /// Set the schema of the parameters. It is made strict if the definition is.
+  pub fn parameters(mut self, parameters: JsonSchema) -> FunctionDefinition {
+    if self.strict == Some(true) {
+      self.parameters = Some(parameters.strict());
+    } else {
+      self.parameters = Some(parameters);
+    }
+    self
+  }
+
+  /// Enable or disable strict mode. When it is enabled, the optional properties
+  /// of the parameters become required and nullable.
+  pub fn strict(mut self, strict: bool) -> FunctionDefinition {
+    self.strict = Some(strict);
+    if strict {
+      self.parameters = self.parameters.map(JsonSchema::strict);
+    }
+    self
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_make_the_parameters_strict_whatever_the_order_of_the_setters() {
+    let parameters: JsonSchema = JsonSchema::object()
+      .required_property("city", JsonSchema::string())
+      .property("days", JsonSchema::integer());
+    let strict_parameters: JsonSchema = JsonSchema::object()
+      .required_property("city", JsonSchema::string())
+      .required_property("days", JsonSchema::integer().nullable())
+      .additional_properties(false);
+    let function: FunctionDefinition = FunctionDefinition::new("get_weather").parameters(parameters.clone()).strict(true);
+    assert_eq!(function.parameters, Some(strict_parameters.clone()));
+    let function: FunctionDefinition = FunctionDefinition::new("get_weather").strict(true).parameters(parameters.clone());
+    assert_eq!(function.parameters, Some(strict_parameters));
+    let function: FunctionDefinition = FunctionDefinition::new("get_weather").strict(false).parameters(parameters.clone());
+    assert_eq!(function.parameters, Some(parameters));
+  }
+}
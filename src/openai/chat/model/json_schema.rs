@@ -0,0 +1,239 @@
+use std::collections::BTreeMap;
+
+use serde::{Serialize, Deserialize};
+
+use crate::openai::chat::model::{JsonSchemaType, JsonSchemaTypes};
+
+/// JSON Schema describing the parameters of a function, built from the type of
+/// each value, e.g. an object with a required string property:
+///
+/// ```
+/// use openai_rust::openai::chat::model::JsonSchema;
+///
+/// let parameters: JsonSchema = JsonSchema::object()
+///   .required_property("city", JsonSchema::string().description("Name of the city"))
+///   .property("unit", JsonSchema::enumeration(&["celsius", "fahrenheit"]));
+/// ```
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct JsonSchema {
+  #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+  pub schema_type: Option<JsonSchemaTypes>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+  pub enum_values: Option<Vec<serde_json::Value>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub properties: Option<BTreeMap<String, JsonSchema>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub required: Option<Vec<String>>,
+  #[serde(rename = "additionalProperties", skip_serializing_if = "Option::is_none")]
+  pub additional_properties: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub items: Option<Box<JsonSchema>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub minimum: Option<f64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub maximum: Option<f64>,
+}
+
+impl JsonSchema {
+  fn of_type(schema_type: JsonSchemaType) -> JsonSchema {
+    JsonSchema {
+      schema_type: Some(JsonSchemaTypes::Single(schema_type)),
+      ..JsonSchema::default()
+    }
+  }
+
+  /// Create the schema of an object without properties.
+  pub fn object() -> JsonSchema {
+    JsonSchema {
+      properties: Some(BTreeMap::new()),
+      ..JsonSchema::of_type(JsonSchemaType::Object)
+    }
+  }
+
+  /// Create the schema of an array whose items match the given schema.
+  pub fn array(items: JsonSchema) -> JsonSchema {
+    JsonSchema {
+      items: Some(Box::new(items)),
+      ..JsonSchema::of_type(JsonSchemaType::Array)
+    }
+  }
+
+  pub fn string() -> JsonSchema {
+    JsonSchema::of_type(JsonSchemaType::String)
+  }
+
+  pub fn number() -> JsonSchema {
+    JsonSchema::of_type(JsonSchemaType::Number)
+  }
+
+  pub fn integer() -> JsonSchema {
+    JsonSchema::of_type(JsonSchemaType::Integer)
+  }
+
+  pub fn boolean() -> JsonSchema {
+    JsonSchema::of_type(JsonSchemaType::Boolean)
+  }
+
+  /// Create the schema of a string restricted to the given values.
+  pub fn enumeration(values: &[&str]) -> JsonSchema {
+    JsonSchema {
+      enum_values: Some(values.iter().map(|value| serde_json::Value::from(*value)).collect()),
+      ..JsonSchema::of_type(JsonSchemaType::String)
+    }
+  }
+
+  pub fn description(mut self, description: &str) -> JsonSchema {
+    if description.is_empty() {
+      self.description = None;
+    } else {
+      self.description = Some(description.to_string());
+    }
+    self
+  }
+
+  /// Add an optional property to the object.
+  pub fn property(mut self, name: &str, schema: JsonSchema) -> JsonSchema {
+    self.properties.get_or_insert_with(BTreeMap::new).insert(name.to_string(), schema);
+    self
+  }
+
+  /// Add a property to the object and to its required properties.
+  pub fn required_property(self, name: &str, schema: JsonSchema) -> JsonSchema {
+    let mut schema: JsonSchema = self.property(name, schema);
+    let required: &mut Vec<String> = schema.required.get_or_insert_with(Vec::new);
+    if !required.iter().any(|required_name| required_name == name) {
+      required.push(name.to_string());
+    }
+    schema
+  }
+
+  /// Set the required properties of the object.
+  pub fn required(mut self, required: Vec<String>) -> JsonSchema {
+    if required.is_empty() {
+      self.required = None;
+    } else {
+      self.required = Some(required);
+    }
+    self
+  }
+
+  /// Allow or forbid the properties that are not listed. They must be forbidden
+  /// in strict mode.
+  pub fn additional_properties(mut self, additional_properties: bool) -> JsonSchema {
+    self.additional_properties = Some(additional_properties);
+    self
+  }
+
+  pub fn minimum(mut self, minimum: f64) -> JsonSchema {
+    self.minimum = Some(minimum);
+    self
+  }
+
+  pub fn maximum(mut self, maximum: f64) -> JsonSchema {
+    self.maximum = Some(maximum);
+    self
+  }
+
+  /// Accept null in addition to the type of the schema, e.g. `"type": ["string", "null"]`.
+  /// Null is also added to the enumerated values. A schema without type already
+  /// accepts null.
+  pub fn nullable(mut self) -> JsonSchema {
+    self.schema_type = self.schema_type.map(|schema_type| schema_type.with(JsonSchemaType::Null));
+    if let Some(enum_values) = &mut self.enum_values {
+      if !enum_values.contains(&serde_json::Value::Null) {
+        enum_values.push(serde_json::Value::Null);
+      }
+    }
+    self
+  }
+
+  /// Apply the rules of strict mode to the schema and to its nested schemas: every
+  /// property of an object is required, the optional ones becoming nullable, and
+  /// additional properties are forbidden.
+  pub fn strict(mut self) -> JsonSchema {
+    self.items = self.items.map(|items| Box::new(items.strict()));
+    if let Some(properties) = self.properties.take() {
+      let mut required: Vec<String> = self.required.take().unwrap_or_default();
+      self.properties = Some(properties.into_iter()
+        .map(|(name, schema)| {
+          if required.contains(&name) {
+            (name, schema.strict())
+          } else {
+            required.push(name.clone());
+            (name, schema.strict().nullable())
+          }
+        })
+        .collect());
+      self = self.required(required).additional_properties(false);
+    }
+    self
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_serialize_a_nested_schema() {
+    let schema: JsonSchema = JsonSchema::object()
+      .required_property("city", JsonSchema::string().description("Name of the city"))
+      .required_property("days", JsonSchema::integer().minimum(1.0).maximum(7.0))
+      .property("unit", JsonSchema::enumeration(&["celsius", "fahrenheit"]))
+      .property("stops", JsonSchema::array(JsonSchema::object()
+        .required_property("lat", JsonSchema::number())
+        .required_property("lon", JsonSchema::number())
+        .additional_properties(false)
+      ))
+      .additional_properties(false);
+    assert_eq!(serde_json::to_value(&schema).unwrap(), serde_json::json!({
+      "type": "object",
+      "properties": {
+        "city": {"type": "string", "description": "Name of the city"},
+        "days": {"type": "integer", "minimum": 1.0, "maximum": 7.0},
+        "unit": {"type": "string", "enum": ["celsius", "fahrenheit"]},
+        "stops": {"type": "array", "items": {"type": "object", "properties": {"lat": {"type": "number"}, "lon": {"type": "number"}}, "required": ["lat", "lon"], "additionalProperties": false}},
+      },
+      "required": ["city", "days"],
+      "additionalProperties": false,
+    }));
+    assert_eq!(serde_json::from_value::<JsonSchema>(serde_json::to_value(&schema).unwrap()).unwrap(), schema);
+  }
+
+  #[test]
+  fn it_should_serialize_a_nullable_schema_as_a_union_of_types() {
+    let schema: JsonSchema = JsonSchema::enumeration(&["celsius", "fahrenheit"]).nullable().nullable();
+    let value: serde_json::Value = serde_json::to_value(&schema).unwrap();
+    assert_eq!(value, serde_json::json!({"type": ["string", "null"], "enum": ["celsius", "fahrenheit", null]}));
+    assert_eq!(serde_json::from_value::<JsonSchema>(value).unwrap(), schema);
+    assert_eq!(JsonSchema::default().nullable(), JsonSchema::default());
+  }
+
+  #[test]
+  fn it_should_require_every_property_of_a_strict_schema() {
+    let schema: JsonSchema = JsonSchema::object()
+      .required_property("city", JsonSchema::string())
+      .property("unit", JsonSchema::string())
+      .property("stops", JsonSchema::array(JsonSchema::object()
+        .required_property("lat", JsonSchema::number())
+        .property("lon", JsonSchema::number())
+      ))
+      .strict();
+    assert_eq!(serde_json::to_value(&schema).unwrap(), serde_json::json!({
+      "type": "object",
+      "properties": {
+        "city": {"type": "string"},
+        "unit": {"type": ["string", "null"]},
+        "stops": {"type": ["array", "null"], "items": {"type": "object", "properties": {"lat": {"type": "number"}, "lon": {"type": ["number", "null"]}}, "required": ["lat", "lon"], "additionalProperties": false}},
+      },
+      "required": ["city", "stops", "unit"],
+      "additionalProperties": false,
+    }));
+  }
+}
@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonSchemaType {
+  Object,
+  Array,
+  String,
+  Number,
+  Integer,
+  Boolean,
+  Null,
+}
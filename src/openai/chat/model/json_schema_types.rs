@@ -0,0 +1,34 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::chat::model::JsonSchemaType;
+
+/// Type of a JSON Schema: a single type or a union of types, e.g.
+/// `["string", "null"]` for a nullable string.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum JsonSchemaTypes {
+  Single(JsonSchemaType),
+  Union(Vec<JsonSchemaType>),
+}
+
+impl JsonSchemaTypes {
+  /// Return the union of the types with the given type.
+  pub fn with(self, schema_type: JsonSchemaType) -> JsonSchemaTypes {
+    match self {
+      JsonSchemaTypes::Single(single_type) if single_type == schema_type => self,
+      JsonSchemaTypes::Single(single_type) => JsonSchemaTypes::Union(vec![single_type, schema_type]),
+      JsonSchemaTypes::Union(mut schema_types) => {
+        if !schema_types.contains(&schema_type) {
+          schema_types.push(schema_type);
+        }
+        JsonSchemaTypes::Union(schema_types)
+      },
+    }
+  }
+}
+
+impl From<JsonSchemaType> for JsonSchemaTypes {
+  fn from(schema_type: JsonSchemaType) -> Self {
+    JsonSchemaTypes::Single(schema_type)
+  }
+}
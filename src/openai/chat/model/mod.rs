@@ -1,17 +1,43 @@
 
+mod chat_completion_chunk;
 mod chat_completion_response;
 mod chat_message;
+mod chat_message_delta;
 mod choice;
-mod function_call;
+mod chunk_choice;
+pub(crate) mod function_call;
+mod function_call_delta;
 mod function_definition;
+mod json_schema;
+mod json_schema_type;
+mod json_schema_types;
 mod role;
+mod tool;
+mod tool_call;
+mod tool_call_delta;
+mod tool_choice;
+mod tool_run;
+mod tool_type;
 mod usage;
 
+pub use chat_completion_chunk::ChatCompletionChunk;
 pub use chat_completion_response::ChatCompletionResponse;
 pub use chat_message::ChatMessage;
 pub use chat_message::ChatMessageBuilder;
+pub use chat_message_delta::ChatMessageDelta;
 pub use choice::Choice;
+pub use chunk_choice::ChunkChoice;
 pub use function_call::FunctionCall;
+pub use function_call_delta::FunctionCallDelta;
 pub use function_definition::FunctionDefinition;
+pub use json_schema::JsonSchema;
+pub use json_schema_type::JsonSchemaType;
+pub use json_schema_types::JsonSchemaTypes;
 pub use role::Role;
+pub use tool::Tool;
+pub use tool_call::ToolCall;
+pub use tool_call_delta::ToolCallDelta;
+pub use tool_choice::ToolChoice;
+pub use tool_run::ToolRun;
+pub use tool_type::ToolType;
 pub use usage::Usage;
\ No newline at end of file
@@ -7,4 +7,5 @@ pub enum Role {
   Assistant,
   User,
   Function,
-}
\ No newline at end of file
+  Tool,
+}
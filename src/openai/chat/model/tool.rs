@@ -0,0 +1,20 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::chat::model::{FunctionDefinition, ToolType};
+
+/// Tool the model may call, replacing the deprecated `functions`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Tool {
+  #[serde(rename = "type")]
+  pub tool_type: ToolType,
+  pub function: FunctionDefinition,
+}
+
+impl Tool {
+  pub fn function(function: FunctionDefinition) -> Tool {
+    Tool {
+      tool_type: ToolType::Function,
+      function,
+    }
+  }
+}
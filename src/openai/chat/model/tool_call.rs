@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::chat::model::{FunctionCall, ToolType};
+
+/// Call of a tool by the model. The result of the call is sent back in a tool
+/// message with the same ID.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ToolCall {
+  pub id: String,
+  #[serde(rename = "type")]
+  pub tool_type: ToolType,
+  pub function: FunctionCall,
+}
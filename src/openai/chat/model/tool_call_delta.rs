@@ -0,0 +1,17 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::chat::model::{FunctionCallDelta, ToolType};
+
+/// Fragment of a tool call received in a streamed chat completion. The fragments
+/// of a tool call have the same index, the ID, the type and the function name are
+/// only sent in the first fragment.
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ToolCallDelta {
+  pub index: u16,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub id: Option<String>,
+  #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+  pub tool_type: Option<ToolType>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub function: Option<FunctionCallDelta>,
+}
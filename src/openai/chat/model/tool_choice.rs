@@ -0,0 +1,57 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+/// Control of the tool called by the model: `auto` lets the model choose between
+/// a message and tool calls, `none` forces a message, `required` forces at least
+/// one tool call and `Function` forces the call of the named function.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ToolChoice {
+  Auto,
+  None,
+  Required,
+  Function(String),
+}
+
+#[derive(Serialize, Deserialize)]
+struct NamedFunction {
+  name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawToolChoice {
+  Mode(String),
+  Function {
+    #[serde(rename = "type")]
+    tool_type: String,
+    function: NamedFunction,
+  },
+}
+
+impl Serialize for ToolChoice {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match self {
+      ToolChoice::Auto => serializer.serialize_str("auto"),
+      ToolChoice::None => serializer.serialize_str("none"),
+      ToolChoice::Required => serializer.serialize_str("required"),
+      ToolChoice::Function(name) => RawToolChoice::Function {
+        tool_type: String::from("function"),
+        function: NamedFunction {name: name.clone()},
+      }.serialize(serializer),
+    }
+  }
+}
+
+impl<'de> Deserialize<'de> for ToolChoice {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    match RawToolChoice::deserialize(deserializer)? {
+      RawToolChoice::Mode(mode) => match mode.as_str() {
+        "auto" => Ok(ToolChoice::Auto),
+        "none" => Ok(ToolChoice::None),
+        "required" => Ok(ToolChoice::Required),
+        _ => Err(D::Error::custom(format!("invalid tool choice: {}", mode))),
+      },
+      RawToolChoice::Function {function, ..} => Ok(ToolChoice::Function(function.name)),
+    }
+  }
+}
@@ -0,0 +1,13 @@
+use crate::openai::chat::model::{ChatCompletionResponse, ChatMessage};
+
+/// Result of [`crate::openai::chat::ChatCompletion::run_with_tools()`].
+#[derive(Debug)]
+pub struct ToolRun {
+  /// Last chat completion, with the final answer of the model.
+  pub response: ChatCompletionResponse,
+  /// Messages of the conversation, from the messages of the request to the final
+  /// answer, including the function calls and their results.
+  pub messages: Vec<ChatMessage>,
+  /// Number of chat completions created.
+  pub iterations: u32,
+}
@@ -0,0 +1,9 @@
+use serde::{Serialize, Deserialize};
+
+/// Type of a tool. Functions are the only tools supported by chat completions.
+#[derive(Debug, Default, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ToolType {
+  #[default]
+  Function,
+}
@@ -0,0 +1,220 @@
+use serde::de::DeserializeOwned;
+
+use crate::openai::chat::error::ChatCompletionError;
+use crate::openai::chat::model::{FunctionDefinition, JsonSchema};
+use crate::openai::chat::model::function_call::parse_arguments;
+
+/// Type whose values are described by a JSON Schema. Derive it with
+/// `#[derive(ToJsonSchema)]` on a struct with named fields, a newtype struct or
+/// an enum with unit variants. The doc comments become the descriptions and the
+/// `#[serde(rename, rename_all, default, skip)]` attributes are honoured. With
+/// `#[openai(strict)]`, every field is required, the optional ones being nullable.
+pub trait ToJsonSchema {
+  fn json_schema() -> JsonSchema;
+}
+
+/// Arguments of a function the model can call. Derive it with
+/// `#[derive(OpenAiFunction)]` on a struct with named fields, which also derives
+/// `ToJsonSchema`. The name of the function is the struct name in snake case,
+/// e.g. `send_http_request` for `SendHTTPRequest`, unless it is set with
+/// `#[openai(name = "...")]`, and its description is the doc comment of the struct. The definition is strict with `#[openai(strict)]`,
+/// which should also be set on the nested structs:
+///
+/// ```
+/// use serde::Deserialize;
+/// use openai_rust::openai::chat::OpenAiFunction;
+///
+/// /// Get the current weather of a city.
+/// #[derive(Deserialize, OpenAiFunction)]
+/// struct GetWeather {
+///   /// Name of the city
+///   city: String,
+///   #[openai(minimum = 1, maximum = 7)]
+///   days: Option<u8>,
+/// }
+///
+/// let function = GetWeather::function_definition();
+/// assert_eq!(function.name, "get_weather");
+/// let arguments: GetWeather = GetWeather::from_arguments(r#"{"city": "Paris"}"#).unwrap();
+/// assert_eq!(arguments.city, "Paris");
+/// ```
+pub trait OpenAiFunction: ToJsonSchema + DeserializeOwned {
+  const NAME: &'static str;
+
+  fn function_definition() -> FunctionDefinition;
+
+  /// Deserialize the arguments of a call to the function. Empty arguments are
+  /// read as an empty object.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error containing the arguments if they are not
+  /// valid JSON or do not match the struct.
+  ///
+  fn from_arguments(arguments: &str) -> Result<Self, ChatCompletionError> {
+    parse_arguments(Self::NAME, arguments)
+  }
+}
+
+macro_rules! impl_to_json_schema {
+  ($schema:expr => $($rust_type:ty),+) => {
+    $(
+      impl ToJsonSchema for $rust_type {
+        fn json_schema() -> JsonSchema {
+          $schema
+        }
+      }
+    )+
+  };
+}
+
+impl_to_json_schema!(JsonSchema::string() => String, str, char);
+impl_to_json_schema!(JsonSchema::boolean() => bool);
+impl_to_json_schema!(JsonSchema::integer() => i8, i16, i32, i64, i128, isize);
+impl_to_json_schema!(JsonSchema::integer().minimum(0.0) => u8, u16, u32, u64, u128, usize);
+impl_to_json_schema!(JsonSchema::number() => f32, f64);
+impl_to_json_schema!(JsonSchema::default() => serde_json::Value);
+
+impl<T: ToJsonSchema> ToJsonSchema for Vec<T> {
+  fn json_schema() -> JsonSchema {
+    JsonSchema::array(T::json_schema())
+  }
+}
+
+impl<T: ToJsonSchema> ToJsonSchema for [T] {
+  fn json_schema() -> JsonSchema {
+    JsonSchema::array(T::json_schema())
+  }
+}
+
+/// An optional value has the schema of the value, also accepting null. It is not
+/// required in the object containing it, unless the object is strict.
+impl<T: ToJsonSchema> ToJsonSchema for Option<T> {
+  fn json_schema() -> JsonSchema {
+    T::json_schema().nullable()
+  }
+}
+
+impl<T: ToJsonSchema + ?Sized> ToJsonSchema for Box<T> {
+  fn json_schema() -> JsonSchema {
+    T::json_schema()
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use serde::Deserialize;
+  use crate::openai::chat::{OpenAiFunction, ToJsonSchema};
+  use super::*;
+
+  /// Unit of the temperature.
+  #[derive(Debug, PartialEq, Deserialize, ToJsonSchema)]
+  #[serde(rename_all = "lowercase")]
+  enum Unit {
+    Celsius,
+    Fahrenheit,
+  }
+
+  #[derive(Debug, PartialEq, Deserialize, ToJsonSchema)]
+  struct Stop {
+    lat: f64,
+    lon: f64,
+  }
+
+  /// Get the weather forecast
+  /// of a city.
+  #[derive(Debug, PartialEq, Deserialize, OpenAiFunction)]
+  #[serde(rename_all = "camelCase")]
+  struct GetForecast {
+    /// Name of the city
+    city: String,
+    #[openai(minimum = 1, maximum = 7)]
+    number_of_days: u8,
+    unit: Option<Unit>,
+    #[serde(default)]
+    stops: Vec<Stop>,
+    #[serde(skip)]
+    cache_key: String,
+  }
+
+  #[derive(Deserialize, OpenAiFunction)]
+  #[openai(name = "ping")]
+  struct Ping {}
+
+  #[derive(Debug, PartialEq, Deserialize, OpenAiFunction)]
+  #[openai(strict)]
+  struct SendHTTPRequest {
+    url: String,
+    timeout: Option<u32>,
+    #[serde(default)]
+    headers: Vec<String>,
+  }
+
+  #[test]
+  fn it_should_derive_the_function_definition_of_a_struct() {
+    let function_definition: FunctionDefinition = GetForecast::function_definition();
+    assert_eq!(GetForecast::NAME, "get_forecast");
+    assert_eq!(serde_json::to_value(&function_definition).unwrap(), serde_json::json!({
+      "name": "get_forecast",
+      "description": "Get the weather forecast of a city.",
+      "parameters": {
+        "type": "object",
+        "properties": {
+          "city": {"type": "string", "description": "Name of the city"},
+          "numberOfDays": {"type": "integer", "minimum": 1.0, "maximum": 7.0},
+          "unit": {"type": ["string", "null"], "description": "Unit of the temperature.", "enum": ["celsius", "fahrenheit", null]},
+          "stops": {"type": "array", "items": {"type": "object", "properties": {"lat": {"type": "number"}, "lon": {"type": "number"}}, "required": ["lat", "lon"], "additionalProperties": false}},
+        },
+        "required": ["city", "numberOfDays"],
+        "additionalProperties": false,
+      },
+    }));
+  }
+
+  #[test]
+  fn it_should_require_every_property_of_a_strict_function() {
+    let function_definition: FunctionDefinition = SendHTTPRequest::function_definition();
+    assert_eq!(SendHTTPRequest::NAME, "send_http_request");
+    assert_eq!(serde_json::to_value(&function_definition).unwrap(), serde_json::json!({
+      "name": "send_http_request",
+      "parameters": {
+        "type": "object",
+        "properties": {
+          "url": {"type": "string"},
+          "timeout": {"type": ["integer", "null"], "minimum": 0.0},
+          "headers": {"type": "array", "items": {"type": "string"}},
+        },
+        "required": ["url", "timeout", "headers"],
+        "additionalProperties": false,
+      },
+      "strict": true,
+    }));
+    let arguments: SendHTTPRequest = SendHTTPRequest::from_arguments(r#"{"url": "https://example.com", "timeout": null, "headers": []}"#).unwrap();
+    assert_eq!(arguments.timeout, None);
+  }
+
+  #[test]
+  fn it_should_use_the_name_given_in_the_attribute() {
+    let function_definition: FunctionDefinition = Ping::function_definition();
+    assert_eq!(function_definition.name, "ping");
+    assert_eq!(function_definition.description, None);
+    assert_eq!(function_definition.parameters, Some(JsonSchema::object().additional_properties(false)));
+  }
+
+  #[test]
+  fn it_should_deserialize_the_arguments_of_a_call() {
+    let arguments: GetForecast = GetForecast::from_arguments(r#"{"city": "Paris", "numberOfDays": 3, "unit": "celsius"}"#).unwrap();
+    assert_eq!(arguments, GetForecast {
+      city: String::from("Paris"),
+      number_of_days: 3,
+      unit: Some(Unit::Celsius),
+      stops: Vec::new(),
+      cache_key: String::new(),
+    });
+    assert!(matches!(GetForecast::from_arguments(r#"{"city": "Paris"}"#), Err(ChatCompletionError::InvalidFunctionArguments {..})));
+  }
+}
@@ -0,0 +1,179 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::future::Future;
+
+use futures::FutureExt;
+use futures::future::BoxFuture;
+
+use crate::openai::chat::OpenAiFunction;
+use crate::openai::chat::error::ChatCompletionError;
+use crate::openai::chat::model::{FunctionDefinition, Tool};
+
+/// Default maximum number of chat completions created by
+/// [`crate::openai::chat::ChatCompletion::run_with_tools()`].
+const DEFAULT_MAX_ITERATIONS: u32 = 10;
+
+/// Async handler receiving the JSON-encoded arguments of a call. The result, or
+/// the error, is sent back to the model as the content of the tool message.
+type ToolHandler = Box<dyn Fn(String) -> BoxFuture<'static, Result<String, String>> + Send + Sync>;
+
+struct RegisteredTool {
+  definition: FunctionDefinition,
+  handler: ToolHandler,
+}
+
+/// Functions the model can call during
+/// [`crate::openai::chat::ChatCompletion::run_with_tools()`], with the handlers
+/// running them, by name.
+pub struct ToolRegistry {
+  tools: BTreeMap<String, RegisteredTool>,
+  max_iterations: u32,
+}
+
+impl Debug for ToolRegistry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ToolRegistry")
+      .field("functions", &self.tools.keys().collect::<Vec<&String>>())
+      .field("max_iterations", &self.max_iterations)
+      .finish()
+  }
+}
+
+impl Default for ToolRegistry {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl ToolRegistry {
+  pub fn new() -> ToolRegistry {
+    ToolRegistry {
+      tools: BTreeMap::new(),
+      max_iterations: DEFAULT_MAX_ITERATIONS,
+    }
+  }
+
+  /// Register the handler of a function receiving the JSON-encoded arguments of
+  /// the calls. A function registered with the same name is replaced.
+  pub fn register<F, Fut>(mut self, definition: FunctionDefinition, handler: F) -> ToolRegistry
+  where
+    F: Fn(String) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<String, String>> + Send + 'static,
+  {
+    let handler: ToolHandler = Box::new(move |arguments: String| handler(arguments).boxed());
+    self.tools.insert(definition.name.clone(), RegisteredTool {definition, handler});
+    self
+  }
+
+  /// Register the handler of a function receiving the deserialized arguments of
+  /// the calls. When the arguments are not valid, the handler is not called and
+  /// the error is sent back to the model so it can fix its call.
+  pub fn register_function<T, F, Fut>(self, handler: F) -> ToolRegistry
+  where
+    T: OpenAiFunction + Send + 'static,
+    F: Fn(T) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<String, String>> + Send + 'static,
+  {
+    self.register(T::function_definition(), move |arguments: String| {
+      let result: Result<BoxFuture<'static, Result<String, String>>, ChatCompletionError> = T::from_arguments(&arguments)
+        .map(|arguments| handler(arguments).boxed());
+      async move {
+        match result {
+          Ok(call) => call.await,
+          Err(error) => Err(error.to_string()),
+        }
+      }
+    })
+  }
+
+  /// Set the maximum number of chat completions created before giving up when
+  /// the model keeps calling functions. The default value is 10.
+  pub fn max_iterations(mut self, max_iterations: u32) -> Result<ToolRegistry, ChatCompletionError> {
+    if max_iterations == 0 {
+      Err(ChatCompletionError::MaxIterationsOutOfRange(max_iterations))
+    } else {
+      self.max_iterations = max_iterations;
+      Ok(self)
+    }
+  }
+
+  pub fn get_max_iterations(&self) -> u32 {
+    self.max_iterations
+  }
+
+  /// Return the registered functions as the tools of a chat completion.
+  pub fn tools(&self) -> Vec<Tool> {
+    self.tools.values()
+      .map(|tool| Tool::function(tool.definition.clone()))
+      .collect()
+  }
+
+  /// Call the handler of the function and return the content of the message
+  /// answering the call. An error of the handler is returned as the content.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if no handler is registered for the
+  /// function.
+  ///
+  pub(crate) async fn call(&self, name: &str, arguments: &str) -> Result<String, ChatCompletionError> {
+    let tool: &RegisteredTool = self.tools.get(name).ok_or_else(|| ChatCompletionError::UnknownFunction(name.to_string()))?;
+    match (tool.handler)(arguments.to_string()).await {
+      Ok(content) => Ok(content),
+      Err(error) => Ok(format!("Error: {}", error)),
+    }
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use serde::Deserialize;
+  use crate::openai::chat::OpenAiFunction;
+  use super::*;
+
+  /// Get the current weather of a city.
+  #[derive(Deserialize, OpenAiFunction)]
+  struct GetWeather {
+    city: String,
+  }
+
+  fn create_tool_registry() -> ToolRegistry {
+    ToolRegistry::new()
+      .register_function(|arguments: GetWeather| async move {
+        Ok(format!("Sunny in {}", arguments.city))
+      })
+      .register(FunctionDefinition::new("get_time"), |_arguments: String| async move {
+        Err(String::from("Clock unavailable"))
+      })
+  }
+
+  #[tokio::test]
+  async fn it_should_call_the_handler_of_the_function() {
+    let tool_registry: ToolRegistry = create_tool_registry();
+    assert_eq!(tool_registry.call("get_weather", r#"{"city": "Paris"}"#).await.unwrap(), "Sunny in Paris");
+    assert_eq!(tool_registry.call("get_time", "{}").await.unwrap(), "Error: Clock unavailable");
+    assert_eq!(tool_registry.call("get_stock", "{}").await.unwrap_err(), ChatCompletionError::UnknownFunction(String::from("get_stock")));
+  }
+
+  #[tokio::test]
+  async fn it_should_return_the_invalid_arguments_as_the_content() {
+    let content: String = create_tool_registry().call("get_weather", r#"{"town": "Paris"}"#).await.unwrap();
+    assert!(content.starts_with("Error: Arguments of function get_weather are not valid"));
+    assert!(content.ends_with(r#"[Arguments: {"town": "Paris"}]"#));
+  }
+
+  #[test]
+  fn it_should_return_the_tools_of_the_registered_functions() {
+    let tools: Vec<Tool> = create_tool_registry().tools();
+    assert_eq!(tools, vec![
+      Tool::function(FunctionDefinition::new("get_time")),
+      Tool::function(GetWeather::function_definition()),
+    ]);
+    assert_eq!(ToolRegistry::new().max_iterations(0).unwrap_err(), ChatCompletionError::MaxIterationsOutOfRange(0));
+    assert_eq!(ToolRegistry::new().get_max_iterations(), 10);
+  }
+}
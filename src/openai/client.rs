@@ -1,34 +1,91 @@
-use crate::openai::{Auth, ApiType};
-use crate::openai::error::{Error, ClientErrorType};
+use std::time::Duration;
 
-const OPENAI_ENDPOINT: &str = "https://api.openai.com/v1/";
+use reqwest::header::{HeaderMap, HeaderValue};
 
+use crate::openai::{Auth, ApiType, AzureApiSurface, ClientBuilder, MultipartForm, Operation, RetryPolicy};
+use crate::openai::credential::KeyHeader;
+use crate::openai::error::{Error, ClientErrorType, env_var};
+
+pub(crate) const OPENAI_ENDPOINT: &str = "https://api.openai.com/v1/";
+pub(crate) const USER_AGENT: &str = concat!("openai-rust/", env!("CARGO_PKG_VERSION"));
+
+/// Client of OpenAI or Azure OpenAI API. It is cheap to clone and the clones
+/// share the same connection pool and credential, so a single client can be used
+/// concurrently by many tasks.
+#[derive(Clone)]
 pub struct Client {
   api_endpoint: String,
   pub(crate) api_type: ApiType,
-  auth: Auth,
+  pub(crate) auth: Auth,
   pub(crate) http_client: reqwest::Client,
+  pub(crate) retry_policy: RetryPolicy,
+  pub(crate) default_headers: HeaderMap,
+  pub(crate) timeout: Option<Duration>,
+  pub(crate) azure_api_surface: AzureApiSurface,
 }
 
 impl Client {
   pub fn new_openai_client(auth: Auth) -> Client {
-    let auth = auth;
     Client::new(auth, OPENAI_ENDPOINT, ApiType::OpenAI)
   }
 
   pub fn new(auth: Auth, api_endpoint: &str, api_type: ApiType) -> Client {
     let api_endpoint: String = Client::update_api_endpoint_to_have_a_slash_add_the_end(api_endpoint);
     let http_client = reqwest::Client::new();
+    let mut default_headers: HeaderMap = HeaderMap::new();
+    default_headers.insert(reqwest::header::USER_AGENT, HeaderValue::from_static(USER_AGENT));
     Client {
       api_endpoint,
       api_type,
       auth,
       http_client,
+      retry_policy: RetryPolicy::default(),
+      default_headers,
+      timeout: None,
+      azure_api_surface: AzureApiSurface::default(),
     }
   }
 
+  /// Create a builder to configure the HTTP options of the client: timeouts, proxy,
+  /// default headers, user agent or a shared `reqwest::Client`.
+  /// 
+  /// # Examples
+  /// 
+  /// ```
+  /// # use std::time::Duration;
+  /// # use openai_rust::openai::{Auth, Client, ApiType};
+  /// let client = Client::builder(Auth::new(String::from("api-key")), "https://api.openai.com/v1/", ApiType::OpenAI)
+  ///   .timeout(Duration::from_secs(60))
+  ///   .organization("org-id")
+  ///   .build();
+  /// ```
+  /// 
+  pub fn builder(auth: Auth, api_endpoint: &str, api_type: ApiType) -> ClientBuilder {
+    ClientBuilder::new(auth, api_endpoint, api_type)
+  }
+
+  /// Set the policy used to retry the requests that fail with a retriable status
+  /// code or connection error. The default policy is [`crate::openai::RetryPolicy::new()`].
+  pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Client {
+    self.retry_policy = retry_policy;
+    self
+  }
+
+  /// Set the API surface used with Azure OpenAI. The default surface is the versioned
+  /// API with deployment-scoped paths, [`crate::openai::AzureApiSurface::Deployments`].
+  pub fn azure_api_surface(mut self, azure_api_surface: AzureApiSurface) -> Client {
+    self.azure_api_surface = azure_api_surface;
+    self
+  }
+
   /// Create a new client from the environment variables. It creates both the Auth
-  /// using [`crate::openai::Auth::from_env()`] and the client.
+  /// and the client.
+  /// 
+  /// If the API type is [`crate::openai::ApiType::OpenAI`] or [`crate::openai::ApiType::Azure`],
+  /// the Auth is created using [`crate::openai::Auth::api_key_from_env()`], reading the API key
+  /// of the environment variable OPENAI_API_KEY. If the API type is [`crate::openai::ApiType::AzureAD`],
+  /// the Auth is created using [`crate::openai::Auth::default_azure_credential()`] and OPENAI_API_KEY
+  /// is not used.
   /// 
   /// If the API type is [`crate::openai::ApiType::OpenAI`] then the api_endpoint is set
   /// to the default value of https://api.openai.com/v1/. The client is created using
@@ -40,17 +97,20 @@ impl Client {
   /// # Errors
   /// 
   /// This function will return an error if:
-  /// - [`crate::openai::Auth::from_env()`] returns an error, i.e. OPENAI_API_KEY environment
-  /// variable is not set.
+  /// - the API type is [`crate::openai::ApiType::OpenAI`] or [`crate::openai::ApiType::Azure`]
+  ///   and OPENAI_API_KEY environment variable is not set.
   /// - the API type is [`crate::openai::ApiType::Azure`] or [`crate::openai::ApiType::AzureAD`]
-  /// and OPENAI_API_ENDPOINT environment variable is not set.
+  ///   and OPENAI_API_ENDPOINT environment variable is not set.
   /// 
-  pub fn from_env(api_type: ApiType) -> Result<Client, std::env::VarError> {
-    let auth: Auth = Auth::from_env()?;
+  pub fn from_env(api_type: ApiType) -> Result<Client, Error> {
+    let auth: Auth = match api_type {
+      ApiType::AzureAD => Auth::default_azure_credential(),
+      ApiType::OpenAI | ApiType::Azure => Auth::api_key_from_env(KeyHeader::for_api_type(&api_type))?,
+    };
     match api_type {
       ApiType::OpenAI => Ok(Client::new_openai_client(auth)),
       ApiType::Azure | ApiType::AzureAD => {
-        let api_endpoint: String = std::env::var("OPENAI_API_ENDPOINT")?;
+        let api_endpoint: String = env_var("OPENAI_API_ENDPOINT")?;
         Ok(Client::new(auth, &api_endpoint, api_type))
       },
     }
@@ -64,58 +124,99 @@ impl Client {
     self.api_type.clone()
   }
 
-  /// Generate OpenAI API URI from the given attributes:
-  /// - api_path: The path of the API
-  /// - model_id: The model ID. It is required for Azure and Azure AD. For OpenAI, it is not used.
+  pub fn get_retry_policy(&self) -> RetryPolicy {
+    self.retry_policy.clone()
+  }
+
+  /// Generate OpenAI API URI of the given operation from the given attributes:
+  /// - operation: The operation of the API, see [`crate::openai::Operation`] for the routing rules.
+  /// - model_id: The model ID. It is required for the operations scoped to a deployment with Azure
+  ///   and Azure AD. For OpenAI, it is not used.
   /// - api_version: The API version. It is used for Azure and Azure AD. For OpenAI, it is not used.
   /// 
   /// If the API type is [`crate::openai::ApiType::Azure`] or [`crate::openai::ApiType::AzureAD`],
-  /// and if API version is not set, the default value of `2023-05-15` is used.
+  /// and if API version is not set, the default value of the operation is used, see
+  /// [`crate::openai::Operation::default_azure_api_version()`], except for the
+  /// [`crate::openai::AzureApiSurface::V1`] API which is not versioned.
   /// 
   /// # Errors
   /// 
   /// The function return an error if the API type is [`crate::openai::ApiType::Azure`] or
-  /// [`crate::openai::ApiType::AzureAD`] and the model ID is not set. The type of the error is
-  /// [`crate::openai::error::Error::ClientError`] with the value of
+  /// [`crate::openai::ApiType::AzureAD`], the operation is scoped to a deployment and the model ID
+  /// is not set. The type of the error is [`crate::openai::error::Error::ClientError`] with the value of
   /// [`crate::openai::error::ClientErrorType::ModelIdMissingToGenerateApiUriForAzure`].
   /// 
   /// # Examples
   /// 
   /// For OpenAI:
   /// ```
-  /// client.generate_api_uri("chat/completions", None, None);
+  /// # use openai_rust::openai::{Auth, Client, Operation};
+  /// # let client = Client::new_openai_client(Auth::new(String::from("api-key")));
+  /// client.generate_api_uri(&Operation::ChatCompletions, None, None);
   /// ```
   /// 
   /// For Azure and Azure AD:
   /// ```
-  /// client.generate_api_uri("chat/completions", Some("model-id"), Some("2023-05-15"));
+  /// # use openai_rust::openai::{Auth, Client, ApiType, Operation};
+  /// # let client = Client::new(Auth::new(String::from("api-key")), "https://my-resource-name.openai.azure.com/", ApiType::Azure);
+  /// client.generate_api_uri(&Operation::ChatCompletions, Some("model-id"), Some("2023-05-15"));
   /// ```
   /// 
   /// ```
-  /// client.generate_api_uri("chat/completions", Some("model-id"), None);
+  /// # use openai_rust::openai::{Auth, Client, ApiType, Operation};
+  /// # let client = Client::new(Auth::new(String::from("api-key")), "https://my-resource-name.openai.azure.com/", ApiType::Azure);
+  /// client.generate_api_uri(&Operation::Files, None, None);
   /// ```
   /// 
-  pub fn generate_api_uri(&self, api_path: &str, model_id: Option<&str>, api_version: Option<&str>) -> Result<String, Error> {
+  pub fn generate_api_uri(&self, operation: &Operation, model_id: Option<&str>, api_version: Option<&str>) -> Result<String, Error> {
+    let path: String = operation.path();
     match self.api_type {
-      ApiType::Azure | ApiType::AzureAD => {
-        match model_id {
-          Some(model_id) => {
-            let api_version: &str = match api_version {
-              Some(api_version) => api_version,
-              None => &"2023-05-15",
-            };
-            Ok(format!("{}openai/deployments/{}/{}?api-version={}", self.api_endpoint, model_id, api_path, api_version))
-          },
-          None => Err(Error::ClientError(ClientErrorType::ModelIdMissingToGenerateApiUriForAzure)),
-        }
+      ApiType::Azure | ApiType::AzureAD => match self.azure_api_surface {
+        AzureApiSurface::Deployments => {
+          let api_version: &str = api_version.unwrap_or(operation.default_azure_api_version());
+          if !operation.is_deployment_scoped() {
+            return Ok(format!("{}openai/{}?api-version={}", self.api_endpoint, path, api_version));
+          }
+          match model_id {
+            Some(model_id) => Ok(format!("{}openai/deployments/{}/{}?api-version={}", self.api_endpoint, model_id, path, api_version)),
+            None => Err(Error::ClientError(ClientErrorType::ModelIdMissingToGenerateApiUriForAzure)),
+          }
+        },
+        AzureApiSurface::V1 => match api_version {
+          Some(api_version) => Ok(format!("{}openai/v1/{}?api-version={}", self.api_endpoint, path, api_version)),
+          None => Ok(format!("{}openai/v1/{}", self.api_endpoint, path)),
+        },
       },
-      ApiType::OpenAI => Ok(format!("{}engines/{}", self.api_endpoint, api_path)),
+      ApiType::OpenAI => Ok(format!("{}{}", self.api_endpoint, path)),
+    }
+  }
+
+  /// Return true if the model ID must be sent in the body of the requests, i.e.
+  /// for OpenAI and for the v1 API of Azure OpenAI.
+  pub(crate) fn is_model_in_body(&self) -> bool {
+    self.api_type == ApiType::OpenAI || self.azure_api_surface == AzureApiSurface::V1
+  }
+
+  /// Add the model ID to the JSON body of a request when it must be sent in the
+  /// body. Otherwise, the model ID is the deployment in the path.
+  pub(crate) fn add_model_to_body(&self, request_body: &mut serde_json::Value, model_id: &str) {
+    if self.is_model_in_body() {
+      request_body["model"] = serde_json::Value::from(model_id);
+    }
+  }
+
+  /// Add the model ID to a multipart form when it must be sent in the body.
+  pub(crate) fn add_model_to_form(&self, form: MultipartForm, model_id: &str) -> MultipartForm {
+    if self.is_model_in_body() {
+      form.text("model", model_id)
+    } else {
+      form
     }
   }
 
   fn update_api_endpoint_to_have_a_slash_add_the_end(api_endpoint: &str) -> String {
-    if !api_endpoint.ends_with("/") {
-      api_endpoint.to_string() + &"/"
+    if !api_endpoint.ends_with('/') {
+      api_endpoint.to_string() + "/"
     } else {
       api_endpoint.to_string()
     }
@@ -127,23 +228,28 @@ impl Client {
 /* -------------------------------------------------------------------------- */
 
 #[cfg(test)]
-pub fn create_client_fom_env_variables(api_key: &str, api_type: ApiType, api_endpoint: Option<&str>) -> Result<Client, std::env::VarError> {
-  std::env::set_var("OPENAI_API_KEY", api_key.to_string());
+pub fn create_client_fom_env_variables(api_key: &str, api_type: ApiType, api_endpoint: Option<&str>) -> Result<Client, Error> {
+  std::env::set_var("OPENAI_API_KEY", api_key);
   if let Some(api_endpoint) = api_endpoint {
-    std::env::set_var("OPENAI_API_ENDPOINT", api_endpoint.to_string());
+    std::env::set_var("OPENAI_API_ENDPOINT", api_endpoint);
   }
   Client::from_env(api_type)
 }
 
 #[cfg(test)]
 mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, bearer_token, header};
   use crate::openai::auth::create_auth_with_given_api_key;
+  use crate::openai::credential::chained_credential::AZURE_ENVIRONMENT_LOCK;
+  use crate::openai::credential::client_secret_credential::mount_token_endpoint;
+  use crate::openai::requestor::Requestor;
   use super::*;
 
   #[test]
   fn it_should_create_openai_client_with_given_key_using_new_openai_client() {
     let api_key: String = String::from("12345abcd");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
     let auth: Auth = create_auth_with_given_api_key(&api_key);
 
     let client: Client = Client::new_openai_client(auth);
@@ -152,13 +258,13 @@ mod tests {
     assert_eq!(client.api_type, ApiType::OpenAI);
     assert_eq!(client.get_api_type(), ApiType::OpenAI);
     assert_eq!(client.api_endpoint, OPENAI_ENDPOINT);
-    assert_eq!(client.generate_api_uri(&api_path, None, None).unwrap(), String::from("https://api.openai.com/v1/engines/api/path"));
+    assert_eq!(client.generate_api_uri(&operation, None, None).unwrap(), String::from("https://api.openai.com/v1/chat/completions"));
   }
 
   #[test]
   fn it_should_create_openai_client_with_given_key_using_new() {
     let api_key: String = String::from("12345abcd");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
     let auth: Auth = create_auth_with_given_api_key(&api_key);
 
     let client: Client = Client::new(auth, "https://api.openai.com/v1/", ApiType::OpenAI);
@@ -167,13 +273,13 @@ mod tests {
     assert_eq!(client.api_type, ApiType::OpenAI);
     assert_eq!(client.get_api_type(), ApiType::OpenAI);
     assert_eq!(client.api_endpoint, OPENAI_ENDPOINT);
-    assert_eq!(client.generate_api_uri(&api_path, None, Some("not used")).unwrap(), String::from("https://api.openai.com/v1/engines/api/path"));
+    assert_eq!(client.generate_api_uri(&operation, None, Some("not used")).unwrap(), String::from("https://api.openai.com/v1/chat/completions"));
   }
 
   #[test]
   fn it_should_create_openai_client_with_env_variables_using_from_env() {
     let api_key: String = String::from("12345abcd");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
     
     let client: Client = create_client_fom_env_variables(&api_key, ApiType::OpenAI, None).unwrap();
     assert_eq!(client.auth.api_key, api_key);
@@ -181,13 +287,13 @@ mod tests {
     assert_eq!(client.api_type, ApiType::OpenAI);
     assert_eq!(client.get_api_type(), ApiType::OpenAI);
     assert_eq!(client.api_endpoint, OPENAI_ENDPOINT);
-    assert_eq!(client.generate_api_uri(&api_path, Some("not"), Some("used")).unwrap(), String::from("https://api.openai.com/v1/engines/api/path"));
+    assert_eq!(client.generate_api_uri(&operation, Some("not"), Some("used")).unwrap(), String::from("https://api.openai.com/v1/chat/completions"));
   }
 
   #[test]
   fn it_should_create_openai_client_with_given_key_using_new_with_missing_slash_at_the_end_of_the_api_endpoint() {
     let api_key: String = String::from("12345abcd");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
     let auth: Auth = create_auth_with_given_api_key(&api_key);
 
     let client: Client = Client::new(auth, "https://api.openai.com/v1", ApiType::OpenAI);
@@ -196,7 +302,7 @@ mod tests {
     assert_eq!(client.api_type, ApiType::OpenAI);
     assert_eq!(client.get_api_type(), ApiType::OpenAI);
     assert_eq!(client.api_endpoint, OPENAI_ENDPOINT);
-    assert_eq!(client.generate_api_uri(&api_path, None, None).unwrap(), String::from("https://api.openai.com/v1/engines/api/path"));
+    assert_eq!(client.generate_api_uri(&operation, None, None).unwrap(), String::from("https://api.openai.com/v1/chat/completions"));
   }
 
   #[test]
@@ -205,7 +311,7 @@ mod tests {
     let azure_api_version: String = String::from("2023-05-15");
     let azure_model_id: String = String::from("model-deployment-id");
     let azure_api_endpoint: String = String::from("https://my-resource-name.openai.azure.com/");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
     let auth: Auth = create_auth_with_given_api_key(&api_key);
 
     let client: Client = Client::new(auth, &azure_api_endpoint, ApiType::Azure);
@@ -214,7 +320,7 @@ mod tests {
     assert_eq!(client.api_type, ApiType::Azure);
     assert_eq!(client.get_api_type(), ApiType::Azure);
     assert_eq!(client.api_endpoint, azure_api_endpoint);
-    assert_eq!(client.generate_api_uri(&api_path, Some(&azure_model_id), Some(&azure_api_version)).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/api/path?api-version=2023-05-15"));
+    assert_eq!(client.generate_api_uri(&operation, Some(&azure_model_id), Some(&azure_api_version)).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/chat/completions?api-version=2023-05-15"));
   }
 
   #[test]
@@ -223,7 +329,7 @@ mod tests {
     let azure_api_version: String = String::from("2023-05-15");
     let azure_model_id: String = String::from("model-deployment-id");
     let azure_api_endpoint: String = String::from("https://my-resource-name.openai.azure.com/");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
 
     let client: Client = create_client_fom_env_variables(&api_key, ApiType::Azure, Some(&azure_api_endpoint)).unwrap();
     assert_eq!(client.auth.api_key, api_key);
@@ -231,7 +337,7 @@ mod tests {
     assert_eq!(client.api_type, ApiType::Azure);
     assert_eq!(client.get_api_type(), ApiType::Azure);
     assert_eq!(client.api_endpoint, azure_api_endpoint);
-    assert_eq!(client.generate_api_uri(&api_path, Some(&azure_model_id), Some(&azure_api_version)).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/api/path?api-version=2023-05-15"));
+    assert_eq!(client.generate_api_uri(&operation, Some(&azure_model_id), Some(&azure_api_version)).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/chat/completions?api-version=2023-05-15"));
   }
 
   #[test]
@@ -240,7 +346,7 @@ mod tests {
     let azure_api_version: String = String::from("2023-05-15");
     let azure_model_id: String = String::from("model-deployment-id");
     let azure_api_endpoint: String = String::from("https://my-resource-name.openai.azure.com/");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
     let auth: Auth = create_auth_with_given_api_key(&api_key);
 
     let client: Client = Client::new(auth, &azure_api_endpoint, ApiType::Azure);
@@ -249,7 +355,7 @@ mod tests {
     assert_eq!(client.api_type, ApiType::Azure);
     assert_eq!(client.get_api_type(), ApiType::Azure);
     assert_eq!(client.api_endpoint, azure_api_endpoint);
-    assert_eq!(client.generate_api_uri(&api_path, Some(&azure_model_id), Some(&azure_api_version)).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/api/path?api-version=2023-05-15"));
+    assert_eq!(client.generate_api_uri(&operation, Some(&azure_model_id), Some(&azure_api_version)).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/chat/completions?api-version=2023-05-15"));
   }
 
   #[test]
@@ -258,7 +364,7 @@ mod tests {
     let azure_api_version: String = String::from("2023-05-15");
     let azure_model_id: String = String::from("model-deployment-id");
     let azure_api_endpoint: String = String::from("https://my-resource-name.openai.azure.com/");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
     let auth: Auth = create_auth_with_given_api_key(&api_key);
 
     let client: Client = Client::new(auth, &azure_api_endpoint, ApiType::AzureAD);
@@ -267,7 +373,7 @@ mod tests {
     assert_eq!(client.api_type, ApiType::AzureAD);
     assert_eq!(client.get_api_type(), ApiType::AzureAD);
     assert_eq!(client.api_endpoint, azure_api_endpoint);
-    assert_eq!(client.generate_api_uri(&api_path, Some(&azure_model_id), Some(&azure_api_version)).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/api/path?api-version=2023-05-15"));
+    assert_eq!(client.generate_api_uri(&operation, Some(&azure_model_id), Some(&azure_api_version)).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/chat/completions?api-version=2023-05-15"));
   }
 
   #[test]
@@ -276,15 +382,59 @@ mod tests {
     let azure_api_version: String = String::from("2023-05-15");
     let azure_model_id: String = String::from("model-deployment-id");
     let azure_api_endpoint: String = String::from("https://my-resource-name.openai.azure.com/");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
 
     let client: Client = create_client_fom_env_variables(&api_key, ApiType::AzureAD, Some(&azure_api_endpoint)).unwrap();
-    assert_eq!(client.auth.api_key, api_key);
-    assert_eq!(client.get_api_key(), api_key);
+    // The default Azure credential is used instead of the API key
+    assert!(client.get_api_key().is_empty());
     assert_eq!(client.api_type, ApiType::AzureAD);
     assert_eq!(client.get_api_type(), ApiType::AzureAD);
     assert_eq!(client.api_endpoint, azure_api_endpoint);
-    assert_eq!(client.generate_api_uri(&api_path, Some(&azure_model_id), Some(&azure_api_version)).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/api/path?api-version=2023-05-15"));
+    assert_eq!(client.generate_api_uri(&operation, Some(&azure_model_id), Some(&azure_api_version)).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/chat/completions?api-version=2023-05-15"));
+  }
+
+  #[tokio::test]
+  async fn it_should_send_the_api_key_of_the_environment_variable_with_from_env() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/openai/deployments/model-deployment-id/chat/completions"))
+      .and(header("api-key", "12345abcd"))
+      .respond_with(ResponseTemplate::new(200))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = create_client_fom_env_variables("12345abcd", ApiType::Azure, Some("https://my-resource-name.openai.azure.com/")).unwrap();
+    let client: Client = Client::new(client.auth, &mock_server.uri(), ApiType::Azure);
+    client.post(Operation::ChatCompletions, "{}", Some("model-deployment-id"), None).await.unwrap();
+  }
+
+  #[tokio::test]
+  async fn it_should_send_a_bearer_token_of_the_default_azure_credential_with_azure_ad_from_env() {
+    let mock_server = MockServer::start().await;
+    mount_token_endpoint(&mock_server, "azure-ad-token", 3600, 1).await;
+    Mock::given(method("POST"))
+      .and(path("/openai/deployments/model-deployment-id/chat/completions"))
+      .and(bearer_token("azure-ad-token"))
+      .respond_with(ResponseTemplate::new(200))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = {
+      let _lock = AZURE_ENVIRONMENT_LOCK.lock().unwrap();
+      std::env::set_var("AZURE_TENANT_ID", "tenant-id");
+      std::env::set_var("AZURE_CLIENT_ID", "client-id");
+      std::env::set_var("AZURE_CLIENT_SECRET", "client-secret");
+      std::env::set_var("AZURE_AUTHORITY_HOST", mock_server.uri());
+      std::env::set_var("OPENAI_API_ENDPOINT", "https://my-resource-name.openai.azure.com/");
+      let client: Result<Client, Error> = Client::from_env(ApiType::AzureAD);
+      std::env::remove_var("AZURE_AUTHORITY_HOST");
+      client.unwrap()
+    };
+    // Send the request to the mock server with the credential of the client
+    let client: Client = Client::new(client.auth, &mock_server.uri(), ApiType::AzureAD);
+    client.post(Operation::ChatCompletions, "{}", Some("model-deployment-id"), None).await.unwrap();
   }
 
   #[test]
@@ -293,7 +443,7 @@ mod tests {
     let azure_api_version: String = String::from("2023-05-15");
     let azure_model_id: String = String::from("model-deployment-id");
     let azure_api_endpoint: String = String::from("https://my-resource-name.openai.azure.com/");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
     let auth: Auth = create_auth_with_given_api_key(&api_key);
 
     let client: Client = Client::new(auth, &azure_api_endpoint, ApiType::AzureAD);
@@ -302,7 +452,7 @@ mod tests {
     assert_eq!(client.api_type, ApiType::AzureAD);
     assert_eq!(client.get_api_type(), ApiType::AzureAD);
     assert_eq!(client.api_endpoint, azure_api_endpoint);
-    assert_eq!(client.generate_api_uri(&api_path, Some(&azure_model_id), Some(&azure_api_version)).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/api/path?api-version=2023-05-15"));
+    assert_eq!(client.generate_api_uri(&operation, Some(&azure_model_id), Some(&azure_api_version)).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/chat/completions?api-version=2023-05-15"));
   }
 
   #[test]
@@ -310,10 +460,10 @@ mod tests {
     let api_key: String = String::from("12345abcd");
     let azure_model_id: String = String::from("model-deployment-id");
     let azure_api_endpoint: String = String::from("https://my-resource-name.openai.azure.com/");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
 
     let client: Client = create_client_fom_env_variables(&api_key, ApiType::Azure, Some(&azure_api_endpoint)).unwrap();
-    assert_eq!(client.generate_api_uri(&api_path, Some(&azure_model_id), None).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/api/path?api-version=2023-05-15"));
+    assert_eq!(client.generate_api_uri(&operation, Some(&azure_model_id), None).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/chat/completions?api-version=2023-05-15"));
   }
 
   #[test]
@@ -321,45 +471,45 @@ mod tests {
     let api_key: String = String::from("12345abcd");
     let azure_model_id: String = String::from("model-deployment-id");
     let azure_api_endpoint: String = String::from("https://my-resource-name.openai.azure.com/");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
 
     let client: Client = create_client_fom_env_variables(&api_key, ApiType::AzureAD, Some(&azure_api_endpoint)).unwrap();
-    assert_eq!(client.generate_api_uri(&api_path, Some(&azure_model_id), None).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/api/path?api-version=2023-05-15"));
+    assert_eq!(client.generate_api_uri(&operation, Some(&azure_model_id), None).unwrap(), String::from("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/chat/completions?api-version=2023-05-15"));
   }
 
   #[test]
   fn it_should_not_return_an_error_when_model_id_is_not_set_for_openai_type() {
     let api_key: String = String::from("12345abcd");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
 
     let client: Client = create_client_fom_env_variables(&api_key, ApiType::OpenAI, None).unwrap();
-    let result = client.generate_api_uri(&api_path, None, Some("not used"));
+    let result = client.generate_api_uri(&operation, None, Some("not used"));
     assert!(result.is_ok());
-    assert_eq!(result.unwrap(), String::from("https://api.openai.com/v1/engines/api/path"));
+    assert_eq!(result.unwrap(), String::from("https://api.openai.com/v1/chat/completions"));
   }
 
   #[test]
   fn it_should_not_return_an_error_when_model_id_is_set_and_version_is_not_set_for_azure_type() {
     let api_key: String = String::from("12345abcd");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
 
     let client: Client = create_client_fom_env_variables(&api_key, ApiType::OpenAI, None).unwrap();
-    let result = client.generate_api_uri(&api_path, Some("model-id"), None);
+    let result = client.generate_api_uri(&operation, Some("model-id"), None);
     assert!(result.is_ok());
-    assert_eq!(result.unwrap(), String::from("https://api.openai.com/v1/engines/api/path"));
+    assert_eq!(result.unwrap(), String::from("https://api.openai.com/v1/chat/completions"));
   }
 
   #[test]
   fn it_should_return_an_error_when_model_id_is_not_set_for_azure_type() {
     let api_key: String = String::from("12345abcd");
     let azure_api_endpoint: String = String::from("https://my-resource-name.openai.azure.com/");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
 
     let client: Client = create_client_fom_env_variables(&api_key, ApiType::Azure, Some(&azure_api_endpoint)).unwrap();
-    let result = client.generate_api_uri(&api_path, None, None);
+    let result = client.generate_api_uri(&operation, None, None);
     match result {
       Ok(_) => panic!("It should return an error"),
-      Err(error) => assert_eq!(error, Error::ClientError(ClientErrorType::ModelIdMissingToGenerateApiUriForAzure)),
+      Err(error) => assert!(matches!(error, Error::ClientError(ClientErrorType::ModelIdMissingToGenerateApiUriForAzure))),
     }
   }
 
@@ -367,13 +517,76 @@ mod tests {
   fn it_should_return_an_error_when_model_id_is_not_set_for_azure_ad_type() {
     let api_key: String = String::from("12345abcd");
     let azure_api_endpoint: String = String::from("https://my-resource-name.openai.azure.com/");
-    let api_path: String = String::from("api/path");
+    let operation: Operation = Operation::ChatCompletions;
 
     let client: Client = create_client_fom_env_variables(&api_key, ApiType::Azure, Some(&azure_api_endpoint)).unwrap();
-    let result = client.generate_api_uri(&api_path, None, None);
+    let result = client.generate_api_uri(&operation, None, None);
     match result {
       Ok(_) => panic!("It should return an error"),
-      Err(error) => assert_eq!(error, Error::ClientError(ClientErrorType::ModelIdMissingToGenerateApiUriForAzure)),
+      Err(error) => assert!(matches!(error, Error::ClientError(ClientErrorType::ModelIdMissingToGenerateApiUriForAzure))),
+    }
+  }
+
+  fn all_operations() -> Vec<Operation<'static>> {
+    vec![
+      Operation::ChatCompletions, Operation::Completions, Operation::Embeddings,
+      Operation::AudioSpeech, Operation::AudioTranscriptions, Operation::AudioTranslations,
+      Operation::ImageGenerations, Operation::ImageEdits, Operation::ImageVariations,
+      Operation::Moderations, Operation::Models, Operation::Model("gpt-4"),
+      Operation::Deployments, Operation::Deployment("gpt-4"),
+      Operation::Files, Operation::File("file-1"), Operation::FileContent("file-1"),
+      Operation::FineTuningJobs, Operation::FineTuningJob("ftjob-1"), Operation::FineTuningJobCancel("ftjob-1"),
+      Operation::FineTuningJobEvents("ftjob-1"), Operation::FineTuningJobCheckpoints("ftjob-1"),
+      Operation::Batches, Operation::Batch("batch-1"), Operation::BatchCancel("batch-1"),
+    ]
+  }
+
+  #[test]
+  fn it_should_route_every_operation_for_openai() {
+    let client: Client = Client::new_openai_client(create_auth_with_given_api_key("12345abcd"));
+    for operation in all_operations() {
+      let api_uri: String = client.generate_api_uri(&operation, Some("gpt-4"), Some("not used")).unwrap();
+      assert_eq!(api_uri, format!("https://api.openai.com/v1/{}", operation.path()));
+    }
+    assert_eq!(client.generate_api_uri(&Operation::ChatCompletions, None, None).unwrap(), "https://api.openai.com/v1/chat/completions");
+    assert_eq!(client.generate_api_uri(&Operation::FileContent("file-1"), None, None).unwrap(), "https://api.openai.com/v1/files/file-1/content");
+  }
+
+  #[test]
+  fn it_should_route_every_operation_for_azure_deployments() {
+    for api_type in [ApiType::Azure, ApiType::AzureAD] {
+      let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), "https://my-resource-name.openai.azure.com", api_type);
+      for operation in all_operations() {
+        let api_uri: String = client.generate_api_uri(&operation, Some("model-deployment-id"), Some("2024-10-21")).unwrap();
+        if operation.is_deployment_scoped() {
+          assert_eq!(api_uri, format!("https://my-resource-name.openai.azure.com/openai/deployments/model-deployment-id/{}?api-version=2024-10-21", operation.path()));
+        } else {
+          assert_eq!(api_uri, format!("https://my-resource-name.openai.azure.com/openai/{}?api-version=2024-10-21", operation.path()));
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn it_should_not_require_the_model_id_for_azure_resource_scoped_operations() {
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), "https://my-resource-name.openai.azure.com/", ApiType::Azure);
+    assert_eq!(client.generate_api_uri(&Operation::Files, None, None).unwrap(), "https://my-resource-name.openai.azure.com/openai/files?api-version=2024-10-21");
+    assert_eq!(client.generate_api_uri(&Operation::FineTuningJobCancel("ftjob-1"), None, Some("2024-10-21")).unwrap(), "https://my-resource-name.openai.azure.com/openai/fine_tuning/jobs/ftjob-1/cancel?api-version=2024-10-21");
+    assert!(matches!(client.generate_api_uri(&Operation::Embeddings, None, None), Err(Error::ClientError(ClientErrorType::ModelIdMissingToGenerateApiUriForAzure))));
+  }
+
+  #[test]
+  fn it_should_route_every_operation_for_the_azure_v1_api() {
+    for api_type in [ApiType::Azure, ApiType::AzureAD] {
+      let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), "https://my-resource-name.openai.azure.com/", api_type)
+        .azure_api_surface(AzureApiSurface::V1);
+      assert!(client.is_model_in_body());
+      for operation in all_operations() {
+        let api_uri: String = client.generate_api_uri(&operation, None, None).unwrap();
+        assert_eq!(api_uri, format!("https://my-resource-name.openai.azure.com/openai/v1/{}", operation.path()));
+        let api_uri: String = client.generate_api_uri(&operation, Some("model-deployment-id"), Some("preview")).unwrap();
+        assert_eq!(api_uri, format!("https://my-resource-name.openai.azure.com/openai/v1/{}?api-version=preview", operation.path()));
+      }
     }
   }
 }
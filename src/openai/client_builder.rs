@@ -0,0 +1,269 @@
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::openai::{Auth, ApiType, AzureApiSurface, Client, RetryPolicy};
+use crate::openai::client::USER_AGENT;
+use crate::openai::error::{Error, ClientErrorType};
+
+/// Builder of a [`crate::openai::Client`] with HTTP options: timeouts, proxy,
+/// default headers and user agent. A preconfigured `reqwest::Client` can be
+/// given to share its connection pool, in this case the connect timeout and the
+/// proxy must be set on that client.
+pub struct ClientBuilder {
+  auth: Auth,
+  api_endpoint: String,
+  api_type: ApiType,
+  connect_timeout: Option<Duration>,
+  timeout: Option<Duration>,
+  proxy: Option<String>,
+  default_headers: Vec<(String, String)>,
+  user_agent_suffix: Option<String>,
+  http_client: Option<reqwest::Client>,
+  retry_policy: RetryPolicy,
+  azure_api_surface: AzureApiSurface,
+}
+
+impl ClientBuilder {
+  pub fn new(auth: Auth, api_endpoint: &str, api_type: ApiType) -> ClientBuilder {
+    ClientBuilder {
+      auth,
+      api_endpoint: api_endpoint.to_string(),
+      api_type,
+      connect_timeout: None,
+      timeout: None,
+      proxy: None,
+      default_headers: Vec::new(),
+      user_agent_suffix: None,
+      http_client: None,
+      retry_policy: RetryPolicy::default(),
+      azure_api_surface: AzureApiSurface::default(),
+    }
+  }
+
+  /// Set the timeout to establish the connection to the API.
+  pub fn connect_timeout(mut self, connect_timeout: Duration) -> ClientBuilder {
+    self.connect_timeout = Some(connect_timeout);
+    self
+  }
+
+  /// Set the timeout of each request, from the connection until the end of the
+  /// response body.
+  pub fn timeout(mut self, timeout: Duration) -> ClientBuilder {
+    self.timeout = Some(timeout);
+    self
+  }
+
+  /// Set the URL of the HTTP proxy used for all the requests.
+  pub fn proxy(mut self, proxy: &str) -> ClientBuilder {
+    self.proxy = Some(proxy.to_string());
+    self
+  }
+
+  /// Add a header sent with every request, e.g. `OpenAI-Organization`,
+  /// `OpenAI-Project` or `Ocp-Apim-Subscription-Key`.
+  pub fn default_header(mut self, name: &str, value: &str) -> ClientBuilder {
+    self.default_headers.push((name.to_string(), value.to_string()));
+    self
+  }
+
+  /// Set the OpenAI organization of the requests.
+  pub fn organization(self, organization: &str) -> ClientBuilder {
+    self.default_header("OpenAI-Organization", organization)
+  }
+
+  /// Set the OpenAI project of the requests.
+  pub fn project(self, project: &str) -> ClientBuilder {
+    self.default_header("OpenAI-Project", project)
+  }
+
+  /// Append the given suffix to the user agent of the crate.
+  pub fn user_agent_suffix(mut self, user_agent_suffix: &str) -> ClientBuilder {
+    self.user_agent_suffix = Some(user_agent_suffix.to_string());
+    self
+  }
+
+  /// Use the given `reqwest::Client` to send the requests.
+  pub fn http_client(mut self, http_client: reqwest::Client) -> ClientBuilder {
+    self.http_client = Some(http_client);
+    self
+  }
+
+  pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> ClientBuilder {
+    self.retry_policy = retry_policy;
+    self
+  }
+
+  pub fn azure_api_surface(mut self, azure_api_surface: AzureApiSurface) -> ClientBuilder {
+    self.azure_api_surface = azure_api_surface;
+    self
+  }
+
+  /// Build the client.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if:
+  /// - a default header has an invalid name or value. The type of the error is
+  ///   [`crate::openai::error::Error::ClientError`] with the value of
+  ///   [`crate::openai::error::ClientErrorType::InvalidHeader`].
+  /// - a `reqwest::Client` is given with a connect timeout or a proxy. The type of
+  ///   the error is [`crate::openai::error::Error::ClientError`] with the value of
+  ///   [`crate::openai::error::ClientErrorType::HttpClientOptionsConflict`].
+  /// - the proxy URL is invalid or the `reqwest::Client` cannot be built.
+  ///
+  pub fn build(self) -> Result<Client, Error> {
+    let mut default_headers: HeaderMap = HeaderMap::new();
+    let user_agent: String = match &self.user_agent_suffix {
+      Some(user_agent_suffix) => format!("{} {}", USER_AGENT, user_agent_suffix),
+      None => String::from(USER_AGENT),
+    };
+    default_headers.insert(reqwest::header::USER_AGENT, header_value(&user_agent)?);
+    for (name, value) in &self.default_headers {
+      let header_name = HeaderName::from_bytes(name.as_bytes())
+        .map_err(|_| Error::ClientError(ClientErrorType::InvalidHeader(name.clone())))?;
+      default_headers.insert(header_name, header_value(value).map_err(|_| Error::ClientError(ClientErrorType::InvalidHeader(name.clone())))?);
+    }
+
+    let http_client: reqwest::Client = match self.http_client {
+      Some(http_client) => {
+        if self.connect_timeout.is_some() || self.proxy.is_some() {
+          return Err(Error::ClientError(ClientErrorType::HttpClientOptionsConflict));
+        }
+        http_client
+      },
+      None => {
+        let mut http_client_builder = reqwest::Client::builder();
+        if let Some(connect_timeout) = self.connect_timeout {
+          http_client_builder = http_client_builder.connect_timeout(connect_timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+          http_client_builder = http_client_builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+        http_client_builder.build()?
+      },
+    };
+
+    let mut client: Client = Client::new(self.auth, &self.api_endpoint, self.api_type)
+      .retry_policy(self.retry_policy)
+      .azure_api_surface(self.azure_api_surface);
+    client.http_client = http_client;
+    client.default_headers = default_headers;
+    client.timeout = self.timeout;
+    Ok(client)
+  }
+}
+
+fn header_value(value: &str) -> Result<HeaderValue, Error> {
+  HeaderValue::from_str(value).map_err(|_| Error::ClientError(ClientErrorType::InvalidHeader(value.to_string())))
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, header};
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use crate::openai::client::OPENAI_ENDPOINT;
+  use crate::openai::Operation;
+  use crate::openai::requestor::Requestor;
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_send_the_default_headers_and_the_user_agent() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .and(header("OpenAI-Organization", "org-id"))
+      .and(header("OpenAI-Project", "project-id"))
+      .and(header("Ocp-Apim-Subscription-Key", "subscription-key"))
+      .and(header("User-Agent", format!("{} my-service/1.0", USER_AGENT).as_str()))
+      .respond_with(ResponseTemplate::new(200))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = ClientBuilder::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI)
+      .organization("org-id")
+      .project("project-id")
+      .default_header("Ocp-Apim-Subscription-Key", "subscription-key")
+      .user_agent_suffix("my-service/1.0")
+      .build()
+      .unwrap();
+    assert!(client.post(Operation::ChatCompletions, "{}", None, None).await.is_ok());
+  }
+
+  #[tokio::test]
+  async fn it_should_return_a_timeout_error_when_the_response_is_too_slow() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(500)))
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = ClientBuilder::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI)
+      .timeout(Duration::from_millis(50))
+      .retry_policy(RetryPolicy::no_retry())
+      .build()
+      .unwrap();
+    let error: Error = client.post(Operation::ChatCompletions, "{}", None, None).await.unwrap_err();
+    assert!(matches!(&error, Error::TransportError {source, attempts: 1} if source.is_timeout()));
+  }
+
+  #[tokio::test]
+  async fn it_should_send_the_requests_through_the_proxy() {
+    let proxy_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/chat/completions"))
+      .respond_with(ResponseTemplate::new(200))
+      .expect(1)
+      .mount(&proxy_server)
+      .await;
+
+    let client: Client = ClientBuilder::new(create_auth_with_given_api_key("12345abcd"), "http://api.openai.invalid/", ApiType::OpenAI)
+      .proxy(&proxy_server.uri())
+      .build()
+      .unwrap();
+    assert!(client.post(Operation::ChatCompletions, "{}", None, None).await.is_ok());
+  }
+
+  #[tokio::test]
+  async fn it_should_use_the_given_http_client() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(header("X-Shared-Client", "true"))
+      .respond_with(ResponseTemplate::new(200))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let mut shared_headers: HeaderMap = HeaderMap::new();
+    shared_headers.insert("X-Shared-Client", HeaderValue::from_static("true"));
+    let http_client: reqwest::Client = reqwest::Client::builder().default_headers(shared_headers).build().unwrap();
+    let client: Client = ClientBuilder::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI)
+      .http_client(http_client)
+      .build()
+      .unwrap();
+    assert!(client.post(Operation::ChatCompletions, "{}", None, None).await.is_ok());
+  }
+
+  #[test]
+  fn it_should_return_an_error_when_the_http_client_is_given_with_a_proxy() {
+    let result = ClientBuilder::new(create_auth_with_given_api_key("12345abcd"), OPENAI_ENDPOINT, ApiType::OpenAI)
+      .http_client(reqwest::Client::new())
+      .proxy("http://localhost:8080")
+      .build();
+    assert!(matches!(result, Err(Error::ClientError(ClientErrorType::HttpClientOptionsConflict))));
+  }
+
+  #[test]
+  fn it_should_return_an_error_when_a_default_header_is_invalid() {
+    let result = ClientBuilder::new(create_auth_with_given_api_key("12345abcd"), OPENAI_ENDPOINT, ApiType::OpenAI)
+      .default_header("Invalid Header", "value")
+      .build();
+    assert!(matches!(result, Err(Error::ClientError(ClientErrorType::InvalidHeader(name))) if name == "Invalid Header"));
+  }
+}
@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+
+use serde::{Serialize, Deserialize};
+
+use crate::openai::error::Error;
+use crate::openai::requestor::{Requestor, decode_json};
+use crate::openai::{Client, Operation};
+use crate::openai::completions::error::CompletionError;
+use crate::openai::completions::model::{CompletionResponse, Prompt};
+
+/// Request of the legacy text completions API, used by the instruct models and
+/// by the fine-tuned base models such as `babbage-002` and `davinci-002`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Completion {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  model: Option<String>,
+  prompt: Prompt,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  suffix: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  max_tokens: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  temperature: Option<f32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  top_p: Option<f32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  n: Option<u16>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  logprobs: Option<u8>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  echo: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  stop: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  presence_penalty: Option<f32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  frequency_penalty: Option<f32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  best_of: Option<u16>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  logit_bias: Option<HashMap<String, f32>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  user: Option<String>,
+}
+
+impl Default for Completion {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Completion {
+  pub fn new() -> Completion {
+    Completion {
+      model: None,
+      prompt: Prompt::String(String::new()),
+      suffix: None,
+      max_tokens: None,
+      temperature: None,
+      top_p: None,
+      n: None,
+      logprobs: None,
+      echo: None,
+      stop: None,
+      presence_penalty: None,
+      frequency_penalty: None,
+      best_of: None,
+      logit_bias: None,
+      user: None,
+    }
+  }
+
+  pub fn prompt(mut self, prompt: String) -> Result<Completion, CompletionError> {
+    if prompt.is_empty() {
+      Err(CompletionError::EmptyPrompt)
+    } else {
+      self.prompt = Prompt::String(prompt);
+      Ok(self)
+    }
+  }
+
+  pub fn prompts(mut self, prompts: Vec<String>) -> Result<Completion, CompletionError> {
+    let prompts: Prompt = Prompt::StringArray(prompts);
+    if prompts.is_empty() || prompts.has_empty_prompt() {
+      Err(CompletionError::EmptyPrompt)
+    } else {
+      self.prompt = prompts;
+      Ok(self)
+    }
+  }
+
+  pub fn suffix(mut self, suffix: String) -> Completion {
+    if suffix.is_empty() {
+      self.suffix = None;
+    } else {
+      self.suffix = Some(suffix);
+    }
+    self
+  }
+
+  pub fn max_tokens(mut self, max_tokens: u32) -> Completion {
+    self.max_tokens = Some(max_tokens);
+    self
+  }
+
+  pub fn temperature(mut self, temperature: f32) -> Result<Completion, CompletionError> {
+    if !(0.0..=2.0).contains(&temperature) {
+      Err(CompletionError::TemperatureValueOutOfRange(temperature))
+    } else {
+      self.temperature = Some(temperature);
+      Ok(self)
+    }
+  }
+
+  pub fn top_p(mut self, top_p: f32) -> Result<Completion, CompletionError> {
+    if !(0.0..=1.0).contains(&top_p) {
+      Err(CompletionError::TopPValueOutOfRange(top_p))
+    } else {
+      self.top_p = Some(top_p);
+      Ok(self)
+    }
+  }
+
+  pub fn n(mut self, n: u16) -> Completion {
+    self.n = Some(n);
+    self
+  }
+
+  /// Set the number of most likely tokens, between 0 and 5, for which the log
+  /// probabilities are returned.
+  pub fn logprobs(mut self, logprobs: u8) -> Result<Completion, CompletionError> {
+    if logprobs > 5 {
+      Err(CompletionError::LogprobsValueOutOfRange(logprobs))
+    } else {
+      self.logprobs = Some(logprobs);
+      Ok(self)
+    }
+  }
+
+  pub fn echo(mut self, echo: bool) -> Completion {
+    self.echo = Some(echo);
+    self
+  }
+
+  pub fn stop(mut self, stop: Vec<String>) -> Result<Completion, CompletionError> {
+    if stop.is_empty() {
+      self.stop = None;
+      Ok(self)
+    } else if stop.len() > 4 {
+      Err(CompletionError::StopSequencesOutOfRange(stop.len()))
+    } else {
+      self.stop = Some(stop);
+      Ok(self)
+    }
+  }
+
+  pub fn presence_penalty(mut self, presence_penalty: f32) -> Result<Completion, CompletionError> {
+    if !(-2.0..=2.0).contains(&presence_penalty) {
+      Err(CompletionError::PresencePenaltyValueOutOfRange(presence_penalty))
+    } else {
+      self.presence_penalty = Some(presence_penalty);
+      Ok(self)
+    }
+  }
+
+  pub fn frequency_penalty(mut self, frequency_penalty: f32) -> Result<Completion, CompletionError> {
+    if !(-2.0..=2.0).contains(&frequency_penalty) {
+      Err(CompletionError::FrequencyPenaltyValueOutOfRange(frequency_penalty))
+    } else {
+      self.frequency_penalty = Some(frequency_penalty);
+      Ok(self)
+    }
+  }
+
+  /// Set the number of completions, between 1 and 20, generated server-side from
+  /// which the best `n` are returned. It must be greater than or equal to `n`,
+  /// which is checked when the completion is created.
+  pub fn best_of(mut self, best_of: u16) -> Result<Completion, CompletionError> {
+    if !(1..=20).contains(&best_of) {
+      Err(CompletionError::BestOfValueOutOfRange(best_of))
+    } else {
+      self.best_of = Some(best_of);
+      Ok(self)
+    }
+  }
+
+  pub fn logit_bias(mut self, logit_bias: HashMap<String, f32>) -> Completion {
+    if logit_bias.is_empty() {
+      self.logit_bias = None;
+    } else {
+      self.logit_bias = Some(logit_bias);
+    }
+    self
+  }
+
+  pub fn user(mut self, user: String) -> Completion {
+    if user.is_empty() {
+      self.user = None;
+    } else {
+      self.user = Some(user);
+    }
+    self
+  }
+
+  /// Create a text completion of the prompt.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the prompt is empty, if `best_of` is
+  /// less than `n` or if the request fails.
+  ///
+  pub async fn create(&self, client: &Client, model_id: &str, api_version: Option<&str>) -> Result<CompletionResponse, Error> {
+    let request_body = self.request_body(client, model_id)?;
+    let response = client.post(Operation::Completions, &request_body, Some(model_id), api_version).await?;
+    decode_json::<CompletionResponse>(response).await
+  }
+
+  fn request_body(&self, client: &Client, model_id: &str) -> Result<String, Error> {
+    if self.prompt.is_empty() || self.prompt.has_empty_prompt() {
+      return Err(CompletionError::EmptyPrompt.into());
+    }
+    if let Some(best_of) = self.best_of {
+      let n: u16 = self.n.unwrap_or(1);
+      if best_of < n {
+        return Err(CompletionError::BestOfLessThanN {best_of, n}.into());
+      }
+    }
+    let mut request_body: serde_json::Value = serde_json::to_value(self)?;
+    client.add_model_to_body(&mut request_body, model_id);
+    Ok(serde_json::to_string(&request_body)?)
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, body_json};
+  use crate::openai::ApiType;
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use crate::openai::completions::model::Logprobs;
+  use super::*;
+
+  const COMPLETION_RESPONSE: &str = r#"{"id": "cmpl-1", "object": "text_completion", "created": 1694268190, "model": "gpt-35-turbo-instruct", "choices": [{"text": "Once upon a time", "index": 0, "logprobs": {"tokens": ["Once", " upon"], "token_logprobs": [null, -0.5], "top_logprobs": [null, {" upon": -0.5}], "text_offset": [0, 4]}, "finish_reason": "length"}], "usage": {"prompt_tokens": 2, "completion_tokens": 4, "total_tokens": 6}}"#;
+
+  #[tokio::test]
+  async fn it_should_create_completion_with_azure_deployment() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/openai/deployments/instruct-deployment/completions"))
+      .and(body_json(serde_json::json!({"prompt": "Once", "suffix": "end.", "max_tokens": 5, "logprobs": 1, "echo": true, "best_of": 2, "n": 2, "temperature": 0.5})))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_string(COMPLETION_RESPONSE)
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let completion_response: CompletionResponse = Completion::new()
+      .prompt(String::from("Once")).unwrap()
+      .suffix(String::from("end."))
+      .max_tokens(5)
+      .logprobs(1).unwrap()
+      .echo(true)
+      .n(2)
+      .best_of(2).unwrap()
+      .temperature(0.5).unwrap()
+      .create(&client, "instruct-deployment", None).await.unwrap();
+    assert_eq!(completion_response.object, "text_completion");
+    assert_eq!(completion_response.choices[0].text, "Once upon a time");
+    assert_eq!(completion_response.choices[0].finish_reason, Some(String::from("length")));
+    let logprobs: &Logprobs = completion_response.choices[0].logprobs.as_ref().unwrap();
+    assert_eq!(logprobs.token_logprobs, vec![None, Some(-0.5)]);
+    assert_eq!(completion_response.usage.unwrap().total_tokens, 6);
+  }
+
+  #[tokio::test]
+  async fn it_should_create_completion_with_openai() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/completions"))
+      .and(body_json(serde_json::json!({"model": "gpt-3.5-turbo-instruct", "prompt": ["Once", "Twice"]})))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_string(COMPLETION_RESPONSE)
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let completion = Completion::new().prompts(vec![String::from("Once"), String::from("Twice")]).unwrap();
+    assert!(completion.create(&client, "gpt-3.5-turbo-instruct", None).await.is_ok());
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_best_of_is_less_than_n() {
+    let client: Client = Client::new_openai_client(create_auth_with_given_api_key("12345abcd"));
+    let result = Completion::new()
+      .prompt(String::from("Once")).unwrap()
+      .n(3)
+      .best_of(2).unwrap()
+      .create(&client, "gpt-3.5-turbo-instruct", None).await;
+    assert!(matches!(result, Err(Error::CompletionValidationError(CompletionError::BestOfLessThanN {best_of: 2, n: 3}))));
+    let result = Completion::new().create(&client, "gpt-3.5-turbo-instruct", None).await;
+    assert!(matches!(result, Err(Error::CompletionValidationError(CompletionError::EmptyPrompt))));
+  }
+
+  #[test]
+  fn it_should_return_an_error_when_the_parameters_are_out_of_range() {
+    assert_eq!(Completion::new().prompt(String::new()).unwrap_err(), CompletionError::EmptyPrompt);
+    assert_eq!(Completion::new().prompts(vec![String::from("Once"), String::new()]).unwrap_err(), CompletionError::EmptyPrompt);
+    assert_eq!(Completion::new().temperature(2.5).unwrap_err(), CompletionError::TemperatureValueOutOfRange(2.5));
+    assert_eq!(Completion::new().top_p(-0.1).unwrap_err(), CompletionError::TopPValueOutOfRange(-0.1));
+    assert_eq!(Completion::new().logprobs(6).unwrap_err(), CompletionError::LogprobsValueOutOfRange(6));
+    assert_eq!(Completion::new().best_of(21).unwrap_err(), CompletionError::BestOfValueOutOfRange(21));
+    assert_eq!(Completion::new().presence_penalty(-3.0).unwrap_err(), CompletionError::PresencePenaltyValueOutOfRange(-3.0));
+    assert_eq!(Completion::new().frequency_penalty(3.0).unwrap_err(), CompletionError::FrequencyPenaltyValueOutOfRange(3.0));
+    assert_eq!(Completion::new().stop(vec![String::from("a"); 5]).unwrap_err(), CompletionError::StopSequencesOutOfRange(5));
+  }
+}
@@ -0,0 +1,59 @@
+use std::error::Error;
+use std::fmt::{Display, Debug};
+
+#[derive(Clone, PartialEq)]
+pub enum CompletionError {
+  BestOfLessThanN {best_of: u16, n: u16},
+  BestOfValueOutOfRange(u16),
+  EmptyPrompt,
+  FrequencyPenaltyValueOutOfRange(f32),
+  LogprobsValueOutOfRange(u8),
+  PresencePenaltyValueOutOfRange(f32),
+  StopSequencesOutOfRange(usize),
+  TemperatureValueOutOfRange(f32),
+  TopPValueOutOfRange(f32),
+}
+
+impl CompletionError {
+  fn label(&self) -> &'static str {
+    match self {
+      CompletionError::BestOfLessThanN {..} => "BestOfLessThanN",
+      CompletionError::BestOfValueOutOfRange(_) => "BestOfValueOutOfRange",
+      CompletionError::EmptyPrompt => "EmptyPrompt",
+      CompletionError::FrequencyPenaltyValueOutOfRange(_) => "FrequencyPenaltyValueOutOfRange",
+      CompletionError::LogprobsValueOutOfRange(_) => "LogprobsValueOutOfRange",
+      CompletionError::PresencePenaltyValueOutOfRange(_) => "PresencePenaltyValueOutOfRange",
+      CompletionError::StopSequencesOutOfRange(_) => "StopSequencesOutOfRange",
+      CompletionError::TemperatureValueOutOfRange(_) => "TemperatureValueOutOfRange",
+      CompletionError::TopPValueOutOfRange(_) => "TopPValueOutOfRange",
+    }
+  }
+
+  fn error_message(&self) -> String {
+    match self {
+      CompletionError::BestOfLessThanN {best_of, n} => format!("Best of value must be greater than or equal to n [Best of: {}, n: {}]", best_of, n),
+      CompletionError::BestOfValueOutOfRange(best_of) => format!("Best of value must be between 1 and 20 [Given value: {}]", best_of),
+      CompletionError::EmptyPrompt => String::from("Prompt cannot be empty."),
+      CompletionError::FrequencyPenaltyValueOutOfRange(frequency_penalty) => format!("Frequency penalty value must be between -2.0 and 2.0 [Given value: {}]", frequency_penalty),
+      CompletionError::LogprobsValueOutOfRange(logprobs) => format!("Logprobs value must be between 0 and 5 [Given value: {}]", logprobs),
+      CompletionError::PresencePenaltyValueOutOfRange(presence_penalty) => format!("Presence penalty value must be between -2.0 and 2.0 [Given value: {}]", presence_penalty),
+      CompletionError::StopSequencesOutOfRange(sequences_count) => format!("Stop value must have between 0 and 4 sequences [Number of sequences: {}]", sequences_count),
+      CompletionError::TemperatureValueOutOfRange(temperature) => format!("Temperature value must be between 0.0 and 2.0 [Given value: {}]", temperature),
+      CompletionError::TopPValueOutOfRange(top_p) => format!("Top P value must be between 0.0 and 1.0 [Given value: {}]", top_p),
+    }
+  }
+}
+
+impl Debug for CompletionError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "[{}] {}", self.label(), self.error_message())
+  }
+}
+
+impl Display for CompletionError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.error_message())
+  }
+}
+
+impl Error for CompletionError {}
@@ -0,0 +1,6 @@
+pub mod error;
+pub mod model;
+
+mod completion;
+
+pub use completion::Completion;
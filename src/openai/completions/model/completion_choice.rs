@@ -0,0 +1,11 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::completions::model::Logprobs;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CompletionChoice {
+  pub text: String,
+  pub index: u16,
+  pub logprobs: Option<Logprobs>,
+  pub finish_reason: Option<String>,
+}
@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::completions::model::{CompletionChoice, Usage};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CompletionResponse {
+  pub id: String,
+  pub object: String,
+  pub created: u64,
+  pub model: String,
+  pub choices: Vec<CompletionChoice>,
+  pub usage: Option<Usage>,
+}
@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+
+use serde::{Serialize, Deserialize};
+
+/// Log probabilities of the generated tokens, returned when `logprobs` is set.
+/// The log probability of the first token is null when the prompt is echoed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Logprobs {
+  pub tokens: Vec<String>,
+  pub token_logprobs: Vec<Option<f32>>,
+  pub top_logprobs: Option<Vec<Option<HashMap<String, f32>>>>,
+  pub text_offset: Vec<u32>,
+}
@@ -0,0 +1,10 @@
+mod completion_choice;
+mod completion_response;
+mod logprobs;
+mod prompt;
+
+pub use completion_choice::CompletionChoice;
+pub use completion_response::CompletionResponse;
+pub use logprobs::Logprobs;
+pub use prompt::Prompt;
+pub use crate::openai::chat::model::Usage;
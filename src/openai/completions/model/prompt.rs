@@ -0,0 +1,45 @@
+use serde::{Serialize, Deserialize};
+
+/// Prompt to complete: a single text or an array of texts completed independently.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Prompt {
+  String(String),
+  StringArray(Vec<String>),
+}
+
+impl Prompt {
+  /// Return true if there is no text to complete.
+  pub fn is_empty(&self) -> bool {
+    match self {
+      Prompt::String(_) => false,
+      Prompt::StringArray(prompts) => prompts.is_empty(),
+    }
+  }
+
+  /// Return true if one of the texts to complete is empty.
+  pub fn has_empty_prompt(&self) -> bool {
+    match self {
+      Prompt::String(prompt) => prompt.is_empty(),
+      Prompt::StringArray(prompts) => prompts.iter().any(String::is_empty),
+    }
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_not_be_empty_when_one_of_the_prompts_is_empty() {
+    let prompt: Prompt = Prompt::StringArray(vec![String::from("Hello"), String::new()]);
+    assert!(!prompt.is_empty());
+    assert!(prompt.has_empty_prompt());
+    assert!(Prompt::StringArray(Vec::new()).is_empty());
+    assert!(Prompt::String(String::new()).has_empty_prompt());
+  }
+}
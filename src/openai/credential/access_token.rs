@@ -0,0 +1,108 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::openai::error::Error;
+
+/// Tokens are refreshed when they expire in less than 5 minutes.
+const REFRESH_MARGIN: Duration = Duration::from_secs(300);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessToken {
+  pub token: String,
+  pub expires_on: Instant,
+}
+
+impl AccessToken {
+  pub fn new(token: String, expires_in: Duration) -> AccessToken {
+    AccessToken {
+      token,
+      expires_on: Instant::now() + expires_in,
+    }
+  }
+
+  /// Return true if the token is expired or expires shortly and must be refreshed.
+  pub fn needs_refresh(&self) -> bool {
+    self.expires_on <= Instant::now() + REFRESH_MARGIN
+  }
+}
+
+/// Response of an OAuth2 token endpoint.
+#[derive(Debug, Deserialize)]
+pub(crate) struct TokenResponse {
+  pub access_token: String,
+  pub expires_in: u64,
+}
+
+impl From<TokenResponse> for AccessToken {
+  fn from(token_response: TokenResponse) -> Self {
+    AccessToken::new(token_response.access_token, Duration::from_secs(token_response.expires_in))
+  }
+}
+
+/// Request an access token to the token endpoint of the authority with the
+/// given form parameters of the client credentials flow.
+pub(crate) async fn request_client_credentials_token(http_client: &reqwest::Client, token_uri: &str, form: &[(&str, &str)]) -> Result<AccessToken, Error> {
+  let response = http_client.post(token_uri)
+    .form(form)
+    .send()
+    .await?;
+  if !response.status().is_success() {
+    return Err(Error::AuthenticationError {status: response.status().as_u16(), message: response.text().await?});
+  }
+  let token_response = response.json::<TokenResponse>().await?;
+  Ok(token_response.into())
+}
+
+/// Cache of an access token shared by the credentials. The token is requested
+/// again when it needs to be refreshed.
+pub(crate) struct TokenCache {
+  access_token: Mutex<Option<AccessToken>>,
+}
+
+impl TokenCache {
+  pub(crate) fn new() -> TokenCache {
+    TokenCache {
+      access_token: Mutex::new(None),
+    }
+  }
+
+  pub(crate) async fn get_or_request<F, Fut>(&self, request_token: F) -> Result<AccessToken, Error>
+  where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<AccessToken, Error>>,
+  {
+    let mut access_token = self.access_token.lock().await;
+    if let Some(token) = access_token.as_ref() {
+      if !token.needs_refresh() {
+        return Ok(token.clone());
+      }
+    }
+    let token: AccessToken = request_token().await?;
+    *access_token = Some(token.clone());
+    Ok(token)
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_not_need_refresh_when_token_expires_later() {
+    let access_token: AccessToken = AccessToken::new(String::from("token"), Duration::from_secs(3600));
+    assert!(!access_token.needs_refresh());
+  }
+
+  #[test]
+  fn it_should_need_refresh_when_token_expires_shortly() {
+    let access_token: AccessToken = AccessToken::new(String::from("token"), Duration::from_secs(60));
+    assert!(access_token.needs_refresh());
+  }
+}
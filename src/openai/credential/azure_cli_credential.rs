@@ -0,0 +1,176 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::openai::credential::{AccessToken, CredentialHeader, CredentialProvider};
+use crate::openai::credential::access_token::TokenCache;
+use crate::openai::credential::managed_identity_credential::DEFAULT_RESOURCE;
+use crate::openai::error::Error;
+
+/// Lifetime of the tokens returned without `expires_on` by older versions of the
+/// Azure CLI. Their `expiresOn` is a local time without offset, which cannot be
+/// converted reliably. The tokens are valid for at least an hour, so they are
+/// cached for a conservative 15 minutes.
+const DEFAULT_TOKEN_LIFETIME: Duration = Duration::from_secs(900);
+
+/// Azure AD (Entra ID) credential using the account logged in the Azure CLI. The
+/// token is requested with `az account get-access-token`.
+pub struct AzureCliCredential {
+  program: String,
+  tenant_id: Option<String>,
+  resource: String,
+  token_cache: TokenCache,
+}
+
+impl AzureCliCredential {
+  pub fn new() -> AzureCliCredential {
+    AzureCliCredential {
+      program: String::from("az"),
+      tenant_id: None,
+      resource: String::from(DEFAULT_RESOURCE),
+      token_cache: TokenCache::new(),
+    }
+  }
+
+  /// Set the tenant of the requested token. If it is not set, the tenant of the
+  /// logged in account is used.
+  pub fn tenant_id(mut self, tenant_id: String) -> AzureCliCredential {
+    self.tenant_id = Some(tenant_id);
+    self
+  }
+
+  /// Set the resource of the requested token. The default value is
+  /// https://cognitiveservices.azure.com.
+  pub fn resource(mut self, resource: &str) -> AzureCliCredential {
+    self.resource = resource.to_string();
+    self
+  }
+
+  /// Get an access token from the Azure CLI. The cached token is returned if it
+  /// does not expire shortly.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the Azure CLI is not installed or if
+  /// no account is logged in. The type of the error is
+  /// [`crate::openai::error::Error::CredentialUnavailable`].
+  ///
+  pub async fn get_token(&self) -> Result<AccessToken, Error> {
+    self.token_cache.get_or_request(|| self.request_token()).await
+  }
+
+  async fn request_token(&self) -> Result<AccessToken, Error> {
+    let mut command = tokio::process::Command::new(&self.program);
+    command.args(["account", "get-access-token", "--output", "json", "--resource", &self.resource]);
+    if let Some(tenant_id) = &self.tenant_id {
+      command.args(["--tenant", tenant_id]);
+    }
+    let output = match command.output().await {
+      Ok(output) => output,
+      Err(error) => return Err(Error::CredentialUnavailable(format!("Azure CLI cannot be executed: {}", error))),
+    };
+    if !output.status.success() {
+      let message: String = String::from_utf8_lossy(&output.stderr).trim().to_string();
+      return Err(Error::CredentialUnavailable(format!("Azure CLI failed to get an access token: {}", message)));
+    }
+    let token_response = serde_json::from_slice::<AzureCliTokenResponse>(&output.stdout)?;
+    Ok(token_response.into())
+  }
+}
+
+#[async_trait]
+impl CredentialProvider for AzureCliCredential {
+  async fn get_credential(&self) -> Result<CredentialHeader, Error> {
+    Ok(self.get_token().await?.into())
+  }
+}
+
+impl Default for AzureCliCredential {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Output of `az account get-access-token`. `expires_on`, a Unix timestamp, is
+/// only returned by recent versions of the Azure CLI.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureCliTokenResponse {
+  access_token: String,
+  #[serde(rename = "expires_on")]
+  expires_on: Option<u64>,
+}
+
+impl From<AzureCliTokenResponse> for AccessToken {
+  fn from(token_response: AzureCliTokenResponse) -> Self {
+    let expires_in: Duration = match token_response.expires_on {
+      Some(expires_on) => {
+        let now: u64 = SystemTime::now().duration_since(UNIX_EPOCH).map(|now| now.as_secs()).unwrap_or_default();
+        Duration::from_secs(expires_on.saturating_sub(now))
+      },
+      None => DEFAULT_TOKEN_LIFETIME,
+    };
+    AccessToken::new(token_response.access_token, expires_in)
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use std::os::unix::fs::PermissionsExt;
+  use super::*;
+
+  fn create_azure_cli_script(name: &str, script: &str) -> String {
+    let program = std::env::temp_dir().join(name);
+    std::fs::write(&program, script).unwrap();
+    std::fs::set_permissions(&program, std::fs::Permissions::from_mode(0o755)).unwrap();
+    program.to_string_lossy().to_string()
+  }
+
+  #[tokio::test]
+  async fn it_should_get_the_access_token_from_the_azure_cli() {
+    let expires_on: u64 = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() + 3600;
+    let script: String = format!("#!/bin/sh\n[ \"$*\" = \"account get-access-token --output json --resource https://cognitiveservices.azure.com --tenant tenant-id\" ] || exit 1\necho '{{\"accessToken\": \"cli-token\", \"expiresOn\": \"2023-10-31 11:25:24.000000\", \"expires_on\": {}, \"tokenType\": \"Bearer\"}}'\n", expires_on);
+
+    let mut credential: AzureCliCredential = AzureCliCredential::new().tenant_id(String::from("tenant-id"));
+    credential.program = create_azure_cli_script("openai-rust-az-success", &script);
+    let credential_header: CredentialHeader = credential.get_credential().await.unwrap();
+    assert_eq!(credential_header.value, "Bearer cli-token");
+    assert!(!credential.get_token().await.unwrap().needs_refresh());
+  }
+
+  #[tokio::test]
+  async fn it_should_cache_the_token_of_an_azure_cli_without_expires_on() {
+    // The script fails when it is called a second time
+    let calls_file = std::env::temp_dir().join("openai-rust-az-legacy-calls");
+    let _ = std::fs::remove_file(&calls_file);
+    let script: String = format!("#!/bin/sh\n[ -e {0} ] && exit 1\ntouch {0}\necho '{{\"accessToken\": \"legacy-token\", \"expiresOn\": \"2023-10-31 11:25:24.000000\", \"tokenType\": \"Bearer\"}}'\n", calls_file.display());
+
+    let mut credential: AzureCliCredential = AzureCliCredential::new();
+    credential.program = create_azure_cli_script("openai-rust-az-legacy", &script);
+    let access_token: AccessToken = credential.get_token().await.unwrap();
+    assert_eq!(access_token.token, "legacy-token");
+    assert!(!access_token.needs_refresh());
+    assert_eq!(credential.get_token().await.unwrap(), access_token);
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_azure_cli_fails() {
+    let mut credential: AzureCliCredential = AzureCliCredential::new();
+    credential.program = create_azure_cli_script("openai-rust-az-failure", "#!/bin/sh\necho 'Please run az login' >&2\nexit 1\n");
+    let error = credential.get_token().await.unwrap_err();
+    assert!(matches!(error, Error::CredentialUnavailable(message) if message == "Azure CLI failed to get an access token: Please run az login"));
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_azure_cli_is_not_installed() {
+    let mut credential: AzureCliCredential = AzureCliCredential::new();
+    credential.program = String::from("/does/not/exist/az");
+    let error = credential.get_token().await.unwrap_err();
+    assert!(matches!(error, Error::CredentialUnavailable(_)));
+  }
+}
@@ -0,0 +1,166 @@
+use std::sync::OnceLock;
+
+use async_trait::async_trait;
+
+use crate::openai::credential::{AzureCliCredential, ClientSecretCredential, CredentialHeader, CredentialProvider, ManagedIdentityCredential, WorkloadIdentityCredential};
+use crate::openai::error::Error;
+
+/// Credential trying each provider in order until one of them returns a
+/// credential. The first provider that succeeds is used for all the following
+/// requests.
+pub struct ChainedCredential {
+  providers: Vec<Box<dyn CredentialProvider>>,
+  selected_provider: OnceLock<usize>,
+}
+
+impl ChainedCredential {
+  pub fn new(providers: Vec<Box<dyn CredentialProvider>>) -> ChainedCredential {
+    ChainedCredential {
+      providers,
+      selected_provider: OnceLock::new(),
+    }
+  }
+
+  /// Create the chain used by Azure's DefaultAzureCredential:
+  /// 1. [`crate::openai::credential::ClientSecretCredential`] if AZURE_TENANT_ID,
+  ///    AZURE_CLIENT_ID and AZURE_CLIENT_SECRET are set.
+  /// 2. [`crate::openai::credential::WorkloadIdentityCredential`] if AZURE_TENANT_ID,
+  ///    AZURE_CLIENT_ID and AZURE_FEDERATED_TOKEN_FILE are set.
+  /// 3. [`crate::openai::credential::ManagedIdentityCredential`].
+  /// 4. [`crate::openai::credential::AzureCliCredential`].
+  pub fn default_azure_credential() -> ChainedCredential {
+    let mut providers: Vec<Box<dyn CredentialProvider>> = Vec::new();
+    if let Ok(credential) = ClientSecretCredential::from_env() {
+      providers.push(Box::new(credential));
+    }
+    if let Ok(credential) = WorkloadIdentityCredential::from_env() {
+      providers.push(Box::new(credential));
+    }
+    providers.push(Box::new(ManagedIdentityCredential::from_env()));
+    providers.push(Box::new(AzureCliCredential::new()));
+    ChainedCredential::new(providers)
+  }
+}
+
+#[async_trait]
+impl CredentialProvider for ChainedCredential {
+  /// Get the credential of the first provider that succeeds.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if all the providers fail. The type of the
+  /// error is [`crate::openai::error::Error::CredentialUnavailable`] and its message
+  /// contains the error of each provider.
+  ///
+  async fn get_credential(&self) -> Result<CredentialHeader, Error> {
+    if let Some(index) = self.selected_provider.get() {
+      return self.providers[*index].get_credential().await;
+    }
+    let mut errors: Vec<String> = Vec::new();
+    for (index, provider) in self.providers.iter().enumerate() {
+      match provider.get_credential().await {
+        Ok(credential_header) => {
+          let _ = self.selected_provider.set(index);
+          return Ok(credential_header);
+        },
+        Err(error) => errors.push(error.to_string()),
+      }
+    }
+    Err(Error::CredentialUnavailable(format!("No credential provider succeeded [{}]", errors.join("; "))))
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+/// The tests setting the environment variables read by DefaultAzureCredential
+/// create the credential while holding this lock.
+#[cfg(test)]
+pub(crate) static AZURE_ENVIRONMENT_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, body_string_contains, header};
+  use crate::openai::credential::{EnvironmentKeyCredential, KeyHeader, StaticKeyCredential};
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_return_the_credential_of_the_first_provider_that_succeeds() {
+    let credential: ChainedCredential = ChainedCredential::new(vec![
+      Box::new(EnvironmentKeyCredential::new("CHAINED_CREDENTIAL_TEST_MISSING", KeyHeader::ApiKey)),
+      Box::new(StaticKeyCredential::new(String::from("first"), KeyHeader::ApiKey)),
+      Box::new(StaticKeyCredential::new(String::from("second"), KeyHeader::ApiKey)),
+    ]);
+    assert_eq!(credential.get_credential().await.unwrap(), CredentialHeader::api_key("first"));
+    assert_eq!(credential.selected_provider.get(), Some(&1));
+    assert_eq!(credential.get_credential().await.unwrap(), CredentialHeader::api_key("first"));
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_all_providers_fail() {
+    let credential: ChainedCredential = ChainedCredential::new(vec![
+      Box::new(EnvironmentKeyCredential::new("CHAINED_CREDENTIAL_TEST_MISSING_1", KeyHeader::ApiKey)),
+      Box::new(EnvironmentKeyCredential::new("CHAINED_CREDENTIAL_TEST_MISSING_2", KeyHeader::ApiKey)),
+    ]);
+    let error = credential.get_credential().await.unwrap_err();
+    assert!(matches!(error, Error::CredentialUnavailable(message) if message == "No credential provider succeeded [Credential is unavailable: Environment variable CHAINED_CREDENTIAL_TEST_MISSING_1 is not set; Credential is unavailable: Environment variable CHAINED_CREDENTIAL_TEST_MISSING_2 is not set]"));
+  }
+
+  #[tokio::test]
+  async fn it_should_try_the_providers_of_the_default_azure_credential_in_order() {
+    let token_file_path = std::env::temp_dir().join("openai-rust-default-azure-credential-token");
+    std::fs::write(&token_file_path, "federated-token").unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/tenant-id/oauth2/v2.0/token"))
+      .and(body_string_contains("client_secret=client-secret"))
+      .respond_with(ResponseTemplate::new(401).set_body_string("client secret rejected"))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("POST"))
+      .and(path("/tenant-id/oauth2/v2.0/token"))
+      .and(body_string_contains("client_assertion=federated-token"))
+      .respond_with(ResponseTemplate::new(401).set_body_string("federated token rejected"))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("GET"))
+      .and(path("/msi/token"))
+      .and(header("X-IDENTITY-HEADER", "identity-header"))
+      .respond_with(ResponseTemplate::new(400).set_body_string("identity not found"))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let credential: ChainedCredential = {
+      let _lock = AZURE_ENVIRONMENT_LOCK.lock().unwrap();
+      std::env::set_var("AZURE_TENANT_ID", "tenant-id");
+      std::env::set_var("AZURE_CLIENT_ID", "client-id");
+      std::env::set_var("AZURE_CLIENT_SECRET", "client-secret");
+      std::env::set_var("AZURE_FEDERATED_TOKEN_FILE", &token_file_path);
+      std::env::set_var("AZURE_AUTHORITY_HOST", mock_server.uri());
+      std::env::set_var("IDENTITY_ENDPOINT", format!("{}/msi/token", mock_server.uri()));
+      std::env::set_var("IDENTITY_HEADER", "identity-header");
+      let credential: ChainedCredential = ChainedCredential::default_azure_credential();
+      for variable in ["AZURE_FEDERATED_TOKEN_FILE", "AZURE_AUTHORITY_HOST", "IDENTITY_ENDPOINT", "IDENTITY_HEADER"] {
+        std::env::remove_var(variable);
+      }
+      credential
+    };
+    assert_eq!(credential.providers.len(), 4);
+
+    // The Azure CLI is the last provider, it fails without a logged in account
+    let message: String = match credential.get_credential().await.unwrap_err() {
+      Error::CredentialUnavailable(message) => message,
+      error => panic!("Unexpected error: {:?}", error),
+    };
+    let positions: Vec<usize> = ["client secret rejected", "federated token rejected", "identity not found", "Azure CLI"].iter()
+      .map(|error| message.find(error).unwrap_or_else(|| panic!("Missing error {} in {}", error, message)))
+      .collect();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]), "Unexpected order of the providers: {}", message);
+    std::fs::remove_file(&token_file_path).unwrap();
+  }
+}
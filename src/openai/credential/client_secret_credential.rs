@@ -0,0 +1,192 @@
+use async_trait::async_trait;
+
+use crate::openai::credential::{AccessToken, CredentialHeader, CredentialProvider};
+use crate::openai::credential::access_token::{TokenCache, request_client_credentials_token};
+use crate::openai::error::{Error, env_var};
+
+pub(crate) const DEFAULT_AUTHORITY_HOST: &str = "https://login.microsoftonline.com/";
+pub(crate) const DEFAULT_SCOPE: &str = "https://cognitiveservices.azure.com/.default";
+
+/// Azure AD (Entra ID) credential using the OAuth2 client credentials flow with
+/// a client secret. The access token is cached and refreshed shortly before it
+/// expires.
+pub struct ClientSecretCredential {
+  tenant_id: String,
+  client_id: String,
+  client_secret: String,
+  authority_host: String,
+  scope: String,
+  http_client: reqwest::Client,
+  token_cache: TokenCache,
+}
+
+impl ClientSecretCredential {
+  pub fn new(tenant_id: String, client_id: String, client_secret: String) -> ClientSecretCredential {
+    ClientSecretCredential {
+      tenant_id,
+      client_id,
+      client_secret,
+      authority_host: String::from(DEFAULT_AUTHORITY_HOST),
+      scope: String::from(DEFAULT_SCOPE),
+      http_client: reqwest::Client::new(),
+      token_cache: TokenCache::new(),
+    }
+  }
+
+  /// Create the credential from the environment variables AZURE_TENANT_ID,
+  /// AZURE_CLIENT_ID and AZURE_CLIENT_SECRET. The authority host is set to the
+  /// value of AZURE_AUTHORITY_HOST if it is defined.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if AZURE_TENANT_ID, AZURE_CLIENT_ID or
+  /// AZURE_CLIENT_SECRET environment variable is not set.
+  ///
+  pub fn from_env() -> Result<ClientSecretCredential, Error> {
+    let tenant_id: String = env_var("AZURE_TENANT_ID")?;
+    let client_id: String = env_var("AZURE_CLIENT_ID")?;
+    let client_secret: String = env_var("AZURE_CLIENT_SECRET")?;
+    let credential = ClientSecretCredential::new(tenant_id, client_id, client_secret);
+    match std::env::var("AZURE_AUTHORITY_HOST") {
+      Ok(authority_host) => Ok(credential.authority_host(&authority_host)),
+      Err(_) => Ok(credential),
+    }
+  }
+
+  /// Set the authority host used to request the token. The default value is
+  /// https://login.microsoftonline.com/.
+  pub fn authority_host(mut self, authority_host: &str) -> ClientSecretCredential {
+    self.authority_host = if authority_host.ends_with('/') {
+      authority_host.to_string()
+    } else {
+      authority_host.to_string() + "/"
+    };
+    self
+  }
+
+  /// Set the scope of the requested token. The default value is
+  /// https://cognitiveservices.azure.com/.default.
+  pub fn scope(mut self, scope: &str) -> ClientSecretCredential {
+    self.scope = scope.to_string();
+    self
+  }
+
+  pub fn get_tenant_id(&self) -> String {
+    self.tenant_id.clone()
+  }
+
+  pub fn get_client_id(&self) -> String {
+    self.client_id.clone()
+  }
+
+  /// Get an access token. The cached token is returned if it does not expire
+  /// shortly, otherwise a new token is requested to the authority.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the token request fails. If the
+  /// authority responds with an error status, the type of the error is
+  /// [`crate::openai::error::Error::AuthenticationError`].
+  ///
+  pub async fn get_token(&self) -> Result<AccessToken, Error> {
+    self.token_cache.get_or_request(|| self.request_token()).await
+  }
+
+  async fn request_token(&self) -> Result<AccessToken, Error> {
+    let token_uri: String = format!("{}{}/oauth2/v2.0/token", self.authority_host, self.tenant_id);
+    request_client_credentials_token(&self.http_client, &token_uri, &[
+      ("grant_type", "client_credentials"),
+      ("client_id", &self.client_id),
+      ("client_secret", &self.client_secret),
+      ("scope", &self.scope),
+    ]).await
+  }
+}
+
+#[async_trait]
+impl CredentialProvider for ClientSecretCredential {
+  async fn get_credential(&self) -> Result<CredentialHeader, Error> {
+    Ok(self.get_token().await?.into())
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+pub fn create_client_secret_credential(authority_host: &str) -> ClientSecretCredential {
+  ClientSecretCredential::new(String::from("tenant-id"), String::from("client-id"), String::from("client-secret"))
+    .authority_host(authority_host)
+}
+
+#[cfg(test)]
+pub async fn mount_token_endpoint(mock_server: &wiremock::MockServer, access_token: &str, expires_in: u64, expected_calls: u64) {
+  use wiremock::{Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, body_string_contains};
+
+  Mock::given(method("POST"))
+    .and(path("/tenant-id/oauth2/v2.0/token"))
+    .and(body_string_contains("grant_type=client_credentials"))
+    .and(body_string_contains("client_id=client-id"))
+    .and(body_string_contains("client_secret=client-secret"))
+    .and(body_string_contains("scope=https%3A%2F%2Fcognitiveservices.azure.com%2F.default"))
+    .respond_with(ResponseTemplate::new(200)
+      .set_body_json(serde_json::json!({"token_type": "Bearer", "expires_in": expires_in, "access_token": access_token}))
+    )
+    .expect(expected_calls)
+    .mount(mock_server)
+    .await;
+}
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::method;
+  use super::*;
+
+  #[test]
+  fn it_should_create_client_secret_credential_with_environment_variables() {
+    std::env::set_var("AZURE_TENANT_ID", "tenant-id");
+    std::env::set_var("AZURE_CLIENT_ID", "client-id");
+    std::env::set_var("AZURE_CLIENT_SECRET", "client-secret");
+
+    let credential: ClientSecretCredential = ClientSecretCredential::from_env().unwrap();
+    assert_eq!(credential.get_tenant_id(), "tenant-id");
+    assert_eq!(credential.get_client_id(), "client-id");
+    assert_eq!(credential.client_secret, "client-secret");
+  }
+
+  #[tokio::test]
+  async fn it_should_request_a_token_and_cache_it() {
+    let mock_server = MockServer::start().await;
+    mount_token_endpoint(&mock_server, "access-token", 3600, 1).await;
+
+    let credential: ClientSecretCredential = create_client_secret_credential(&mock_server.uri());
+    assert_eq!(credential.get_token().await.unwrap().token, "access-token");
+    assert_eq!(credential.get_token().await.unwrap().token, "access-token");
+  }
+
+  #[tokio::test]
+  async fn it_should_refresh_the_token_when_it_expires_shortly() {
+    let mock_server = MockServer::start().await;
+    mount_token_endpoint(&mock_server, "access-token", 60, 2).await;
+
+    let credential: ClientSecretCredential = create_client_secret_credential(&mock_server.uri());
+    assert_eq!(credential.get_token().await.unwrap().token, "access-token");
+    assert_eq!(credential.get_token().await.unwrap().token, "access-token");
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_authentication_error_when_the_token_request_fails() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .respond_with(ResponseTemplate::new(401).set_body_string("invalid_client"))
+      .mount(&mock_server)
+      .await;
+
+    let credential: ClientSecretCredential = create_client_secret_credential(&mock_server.uri());
+    let error = credential.get_token().await.unwrap_err();
+    assert!(matches!(error, Error::AuthenticationError {status: 401, message} if message == "invalid_client"));
+  }
+}
@@ -0,0 +1,54 @@
+use std::time::Instant;
+
+use async_trait::async_trait;
+
+use crate::openai::credential::AccessToken;
+use crate::openai::error::Error;
+
+/// Header sent with every request to authenticate to the API.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CredentialHeader {
+  pub name: String,
+  pub value: String,
+  /// Instant when the credential expires. It is `None` for credentials that do
+  /// not expire such as API keys.
+  pub expires_on: Option<Instant>,
+}
+
+impl CredentialHeader {
+  /// Create an `api-key` header as expected by Azure OpenAI.
+  pub fn api_key(api_key: &str) -> CredentialHeader {
+    CredentialHeader {
+      name: String::from("api-key"),
+      value: api_key.to_string(),
+      expires_on: None,
+    }
+  }
+
+  /// Create an `Authorization: Bearer` header as expected by OpenAI and by Azure
+  /// OpenAI with Azure AD.
+  pub fn bearer(token: &str) -> CredentialHeader {
+    CredentialHeader {
+      name: reqwest::header::AUTHORIZATION.to_string(),
+      value: format!("Bearer {}", token),
+      expires_on: None,
+    }
+  }
+}
+
+impl From<AccessToken> for CredentialHeader {
+  fn from(access_token: AccessToken) -> Self {
+    CredentialHeader {
+      expires_on: Some(access_token.expires_on),
+      ..CredentialHeader::bearer(&access_token.token)
+    }
+  }
+}
+
+/// Provider of the credential used to authenticate the requests. The provider
+/// is called before every request, so it is responsible for caching the
+/// credential if it is expensive to get.
+#[async_trait]
+pub trait CredentialProvider: Send + Sync {
+  async fn get_credential(&self) -> Result<CredentialHeader, Error>;
+}
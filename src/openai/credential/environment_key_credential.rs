@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+
+use crate::openai::credential::{CredentialHeader, CredentialProvider, KeyHeader};
+use crate::openai::error::Error;
+
+/// Credential with an API key read from an environment variable. The variable
+/// is read on every call so that a rotated key is used without restarting.
+pub struct EnvironmentKeyCredential {
+  variable: String,
+  key_header: KeyHeader,
+}
+
+impl EnvironmentKeyCredential {
+  pub fn new(variable: &str, key_header: KeyHeader) -> EnvironmentKeyCredential {
+    EnvironmentKeyCredential {
+      variable: variable.to_string(),
+      key_header,
+    }
+  }
+}
+
+#[async_trait]
+impl CredentialProvider for EnvironmentKeyCredential {
+  async fn get_credential(&self) -> Result<CredentialHeader, Error> {
+    match std::env::var(&self.variable) {
+      Ok(api_key) => Ok(self.key_header.credential_header(&api_key)),
+      Err(_) => Err(Error::CredentialUnavailable(format!("Environment variable {} is not set", self.variable))),
+    }
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_return_the_api_key_of_the_environment_variable() {
+    std::env::set_var("ENVIRONMENT_KEY_CREDENTIAL_TEST_KEY", "12345abcd");
+
+    let credential: EnvironmentKeyCredential = EnvironmentKeyCredential::new("ENVIRONMENT_KEY_CREDENTIAL_TEST_KEY", KeyHeader::ApiKey);
+    assert_eq!(credential.get_credential().await.unwrap(), CredentialHeader::api_key("12345abcd"));
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_environment_variable_is_not_set() {
+    let credential: EnvironmentKeyCredential = EnvironmentKeyCredential::new("ENVIRONMENT_KEY_CREDENTIAL_TEST_MISSING", KeyHeader::Bearer);
+    let error = credential.get_credential().await.unwrap_err();
+    assert!(matches!(error, Error::CredentialUnavailable(message) if message == "Environment variable ENVIRONMENT_KEY_CREDENTIAL_TEST_MISSING is not set"));
+  }
+}
@@ -0,0 +1,269 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::openai::credential::{AccessToken, CredentialHeader, CredentialProvider};
+use crate::openai::credential::access_token::TokenCache;
+use crate::openai::error::Error;
+
+pub(crate) const IMDS_ENDPOINT: &str = "http://169.254.169.254/metadata/identity/oauth2/token";
+const IMDS_API_VERSION: &str = "2018-02-01";
+const APP_SERVICE_API_VERSION: &str = "2019-08-01";
+pub(crate) const DEFAULT_RESOURCE: &str = "https://cognitiveservices.azure.com";
+const IMDS_CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Source of the managed identity token.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ManagedIdentitySource {
+  /// Azure Instance Metadata Service (IMDS) available on Azure VMs.
+  Imds,
+  /// App Service and Azure Functions identity endpoint, authenticated with the
+  /// value of the IDENTITY_HEADER environment variable.
+  AppService { identity_header: String },
+}
+
+/// Azure AD (Entra ID) credential using the managed identity of the Azure
+/// resource running the code. The access token is cached and refreshed shortly
+/// before it expires.
+pub struct ManagedIdentityCredential {
+  source: ManagedIdentitySource,
+  endpoint: String,
+  client_id: Option<String>,
+  resource: String,
+  http_client: reqwest::Client,
+  token_cache: TokenCache,
+}
+
+impl ManagedIdentityCredential {
+  /// Create a credential using the Azure Instance Metadata Service (IMDS).
+  pub fn new() -> ManagedIdentityCredential {
+    ManagedIdentityCredential::with_source(ManagedIdentitySource::Imds, IMDS_ENDPOINT)
+  }
+
+  /// Create a credential using the App Service identity endpoint and header.
+  pub fn app_service(identity_endpoint: &str, identity_header: String) -> ManagedIdentityCredential {
+    ManagedIdentityCredential::with_source(ManagedIdentitySource::AppService { identity_header }, identity_endpoint)
+  }
+
+  /// Create a credential using the App Service identity endpoint if the
+  /// environment variables IDENTITY_ENDPOINT and IDENTITY_HEADER are set,
+  /// otherwise using the Azure Instance Metadata Service (IMDS).
+  pub fn from_env() -> ManagedIdentityCredential {
+    match (std::env::var("IDENTITY_ENDPOINT"), std::env::var("IDENTITY_HEADER")) {
+      (Ok(identity_endpoint), Ok(identity_header)) => ManagedIdentityCredential::app_service(&identity_endpoint, identity_header),
+      _ => ManagedIdentityCredential::new(),
+    }
+  }
+
+  fn with_source(source: ManagedIdentitySource, endpoint: &str) -> ManagedIdentityCredential {
+    ManagedIdentityCredential {
+      source,
+      endpoint: endpoint.to_string(),
+      client_id: None,
+      resource: String::from(DEFAULT_RESOURCE),
+      http_client: reqwest::Client::builder()
+        // IMDS is not reachable outside of Azure, do not wait for it when probing the credential
+        .connect_timeout(IMDS_CONNECT_TIMEOUT)
+        .build()
+        .unwrap_or_default(),
+      token_cache: TokenCache::new(),
+    }
+  }
+
+  /// Set the client ID of the user-assigned managed identity. If it is not set,
+  /// the system-assigned managed identity is used.
+  pub fn client_id(mut self, client_id: String) -> ManagedIdentityCredential {
+    self.client_id = Some(client_id);
+    self
+  }
+
+  /// Set the URL of the token endpoint. The default value is the IMDS endpoint
+  /// http://169.254.169.254/metadata/identity/oauth2/token.
+  pub fn endpoint(mut self, endpoint: &str) -> ManagedIdentityCredential {
+    self.endpoint = endpoint.to_string();
+    self
+  }
+
+  /// Set the resource of the requested token. The default value is
+  /// https://cognitiveservices.azure.com.
+  pub fn resource(mut self, resource: &str) -> ManagedIdentityCredential {
+    self.resource = resource.to_string();
+    self
+  }
+
+  pub fn get_source(&self) -> ManagedIdentitySource {
+    self.source.clone()
+  }
+
+  pub fn get_client_id(&self) -> Option<String> {
+    self.client_id.clone()
+  }
+
+  /// Get an access token. The cached token is returned if it does not expire
+  /// shortly, otherwise a new token is requested to the managed identity endpoint.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the token request fails. If the
+  /// endpoint responds with an error status, the type of the error is
+  /// [`crate::openai::error::Error::AuthenticationError`].
+  ///
+  pub async fn get_token(&self) -> Result<AccessToken, Error> {
+    self.token_cache.get_or_request(|| self.request_token()).await
+  }
+
+  async fn request_token(&self) -> Result<AccessToken, Error> {
+    let mut query: Vec<(&str, &str)> = vec![("resource", &self.resource)];
+    if let Some(client_id) = &self.client_id {
+      query.push(("client_id", client_id));
+    }
+    let request_builder = match &self.source {
+      ManagedIdentitySource::Imds => {
+        query.push(("api-version", IMDS_API_VERSION));
+        self.http_client.get(&self.endpoint).header("Metadata", "true")
+      },
+      ManagedIdentitySource::AppService { identity_header } => {
+        query.push(("api-version", APP_SERVICE_API_VERSION));
+        self.http_client.get(&self.endpoint).header("X-IDENTITY-HEADER", identity_header)
+      },
+    };
+    let response = request_builder.query(&query).send().await?;
+    if !response.status().is_success() {
+      return Err(Error::AuthenticationError {status: response.status().as_u16(), message: response.text().await?});
+    }
+    let token_response = response.json::<ManagedIdentityTokenResponse>().await?;
+    Ok(token_response.into_access_token())
+  }
+}
+
+#[async_trait]
+impl CredentialProvider for ManagedIdentityCredential {
+  async fn get_credential(&self) -> Result<CredentialHeader, Error> {
+    Ok(self.get_token().await?.into())
+  }
+}
+
+impl Default for ManagedIdentityCredential {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Response of the managed identity endpoints. IMDS returns `expires_in` and
+/// `expires_on` as strings while App Service only returns `expires_on`.
+#[derive(Debug, Deserialize)]
+struct ManagedIdentityTokenResponse {
+  access_token: String,
+  expires_in: Option<serde_json::Value>,
+  expires_on: Option<serde_json::Value>,
+}
+
+impl ManagedIdentityTokenResponse {
+  fn into_access_token(self) -> AccessToken {
+    let expires_in: u64 = match (as_seconds(&self.expires_in), as_seconds(&self.expires_on)) {
+      (Some(expires_in), _) => expires_in,
+      (None, Some(expires_on)) => {
+        let now: u64 = SystemTime::now().duration_since(UNIX_EPOCH).map(|now| now.as_secs()).unwrap_or_default();
+        expires_on.saturating_sub(now)
+      },
+      // Without expiration, the token is requested again on the next call
+      (None, None) => 0,
+    };
+    AccessToken::new(self.access_token, Duration::from_secs(expires_in))
+  }
+}
+
+fn as_seconds(value: &Option<serde_json::Value>) -> Option<u64> {
+  match value {
+    Some(serde_json::Value::Number(number)) => number.as_u64(),
+    Some(serde_json::Value::String(string)) => string.parse::<u64>().ok(),
+    _ => None,
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, header, query_param};
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_request_a_token_to_imds_and_cache_it() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/metadata/identity/oauth2/token"))
+      .and(header("Metadata", "true"))
+      .and(query_param("api-version", IMDS_API_VERSION))
+      .and(query_param("resource", DEFAULT_RESOURCE))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_json(serde_json::json!({"access_token": "access-token", "expires_in": "3599", "expires_on": "1506484173", "token_type": "Bearer"}))
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let credential: ManagedIdentityCredential = ManagedIdentityCredential::new()
+      .endpoint(&format!("{}/metadata/identity/oauth2/token", mock_server.uri()));
+    assert_eq!(credential.get_source(), ManagedIdentitySource::Imds);
+    assert_eq!(credential.get_token().await.unwrap().token, "access-token");
+    assert_eq!(credential.get_token().await.unwrap().token, "access-token");
+  }
+
+  #[tokio::test]
+  async fn it_should_request_a_token_for_a_user_assigned_identity() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/metadata/identity/oauth2/token"))
+      .and(query_param("client_id", "client-id"))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_json(serde_json::json!({"access_token": "user-assigned-token", "expires_in": 3599}))
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let credential: ManagedIdentityCredential = ManagedIdentityCredential::new()
+      .endpoint(&format!("{}/metadata/identity/oauth2/token", mock_server.uri()))
+      .client_id(String::from("client-id"));
+    assert_eq!(credential.get_client_id(), Some(String::from("client-id")));
+    assert_eq!(credential.get_token().await.unwrap().token, "user-assigned-token");
+  }
+
+  #[tokio::test]
+  async fn it_should_request_a_token_to_app_service_identity_endpoint() {
+    let mock_server = MockServer::start().await;
+    let expires_on: u64 = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() + 3600;
+    Mock::given(method("GET"))
+      .and(path("/msi/token"))
+      .and(header("X-IDENTITY-HEADER", "identity-header"))
+      .and(query_param("api-version", APP_SERVICE_API_VERSION))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_json(serde_json::json!({"access_token": "app-service-token", "expires_on": expires_on.to_string()}))
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let credential: ManagedIdentityCredential = ManagedIdentityCredential::app_service(&format!("{}/msi/token", mock_server.uri()), String::from("identity-header"));
+    assert_eq!(credential.get_token().await.unwrap().token, "app-service-token");
+    assert_eq!(credential.get_token().await.unwrap().token, "app-service-token");
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_authentication_error_when_no_identity_is_assigned() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .respond_with(ResponseTemplate::new(400).set_body_string("identity not found"))
+      .mount(&mock_server)
+      .await;
+
+    let credential: ManagedIdentityCredential = ManagedIdentityCredential::new().endpoint(&mock_server.uri());
+    let error = credential.get_token().await.unwrap_err();
+    assert!(matches!(error, Error::AuthenticationError {status: 400, message} if message == "identity not found"));
+  }
+}
@@ -0,0 +1,19 @@
+mod access_token;
+mod azure_cli_credential;
+pub(crate) mod chained_credential;
+pub(crate) mod client_secret_credential;
+mod credential_provider;
+mod environment_key_credential;
+mod managed_identity_credential;
+mod static_key_credential;
+mod workload_identity_credential;
+
+pub use access_token::AccessToken;
+pub use azure_cli_credential::AzureCliCredential;
+pub use chained_credential::ChainedCredential;
+pub use client_secret_credential::ClientSecretCredential;
+pub use credential_provider::{CredentialHeader, CredentialProvider};
+pub use environment_key_credential::EnvironmentKeyCredential;
+pub use managed_identity_credential::{ManagedIdentityCredential, ManagedIdentitySource};
+pub use static_key_credential::{KeyHeader, StaticKeyCredential};
+pub use workload_identity_credential::WorkloadIdentityCredential;
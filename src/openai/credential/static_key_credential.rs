@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+
+use crate::openai::ApiType;
+use crate::openai::credential::{CredentialHeader, CredentialProvider};
+use crate::openai::error::Error;
+
+/// Header used to send an API key.
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeyHeader {
+  /// `api-key` header used by Azure OpenAI.
+  ApiKey,
+  /// `Authorization: Bearer` header used by OpenAI.
+  Bearer,
+}
+
+impl KeyHeader {
+  /// Get the header expected by the given API type for an API key.
+  pub fn for_api_type(api_type: &ApiType) -> KeyHeader {
+    match api_type {
+      ApiType::Azure => KeyHeader::ApiKey,
+      ApiType::AzureAD | ApiType::OpenAI => KeyHeader::Bearer,
+    }
+  }
+
+  pub(crate) fn credential_header(&self, key: &str) -> CredentialHeader {
+    match self {
+      KeyHeader::ApiKey => CredentialHeader::api_key(key),
+      KeyHeader::Bearer => CredentialHeader::bearer(key),
+    }
+  }
+}
+
+/// Credential with a static API key that never expires.
+pub struct StaticKeyCredential {
+  credential_header: CredentialHeader,
+}
+
+impl StaticKeyCredential {
+  pub fn new(api_key: String, key_header: KeyHeader) -> StaticKeyCredential {
+    StaticKeyCredential {
+      credential_header: key_header.credential_header(&api_key),
+    }
+  }
+}
+
+#[async_trait]
+impl CredentialProvider for StaticKeyCredential {
+  async fn get_credential(&self) -> Result<CredentialHeader, Error> {
+    Ok(self.credential_header.clone())
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_return_the_api_key_in_the_api_key_header() {
+    let credential: StaticKeyCredential = StaticKeyCredential::new(String::from("12345abcd"), KeyHeader::ApiKey);
+    let credential_header: CredentialHeader = credential.get_credential().await.unwrap();
+    assert_eq!(credential_header, CredentialHeader {name: String::from("api-key"), value: String::from("12345abcd"), expires_on: None});
+  }
+
+  #[tokio::test]
+  async fn it_should_return_the_api_key_as_a_bearer_token() {
+    let credential: StaticKeyCredential = StaticKeyCredential::new(String::from("12345abcd"), KeyHeader::for_api_type(&ApiType::OpenAI));
+    let credential_header: CredentialHeader = credential.get_credential().await.unwrap();
+    assert_eq!(credential_header, CredentialHeader {name: String::from("authorization"), value: String::from("Bearer 12345abcd"), expires_on: None});
+  }
+}
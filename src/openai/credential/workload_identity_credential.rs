@@ -0,0 +1,147 @@
+use async_trait::async_trait;
+
+use crate::openai::credential::{AccessToken, CredentialHeader, CredentialProvider};
+use crate::openai::credential::access_token::{TokenCache, request_client_credentials_token};
+use crate::openai::credential::client_secret_credential::{DEFAULT_AUTHORITY_HOST, DEFAULT_SCOPE};
+use crate::openai::error::{Error, env_var};
+
+const CLIENT_ASSERTION_TYPE: &str = "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
+/// Azure AD (Entra ID) credential using workload identity federation, e.g. on
+/// Azure Kubernetes Service. The federated token is read from the token file
+/// on every token request because it is rotated by the platform.
+pub struct WorkloadIdentityCredential {
+  tenant_id: String,
+  client_id: String,
+  token_file_path: String,
+  authority_host: String,
+  scope: String,
+  http_client: reqwest::Client,
+  token_cache: TokenCache,
+}
+
+impl WorkloadIdentityCredential {
+  pub fn new(tenant_id: String, client_id: String, token_file_path: String) -> WorkloadIdentityCredential {
+    WorkloadIdentityCredential {
+      tenant_id,
+      client_id,
+      token_file_path,
+      authority_host: String::from(DEFAULT_AUTHORITY_HOST),
+      scope: String::from(DEFAULT_SCOPE),
+      http_client: reqwest::Client::new(),
+      token_cache: TokenCache::new(),
+    }
+  }
+
+  /// Create the credential from the environment variables AZURE_TENANT_ID,
+  /// AZURE_CLIENT_ID and AZURE_FEDERATED_TOKEN_FILE set by the workload identity
+  /// webhook. The authority host is set to the value of AZURE_AUTHORITY_HOST if
+  /// it is defined.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if AZURE_TENANT_ID, AZURE_CLIENT_ID or
+  /// AZURE_FEDERATED_TOKEN_FILE environment variable is not set.
+  ///
+  pub fn from_env() -> Result<WorkloadIdentityCredential, Error> {
+    let tenant_id: String = env_var("AZURE_TENANT_ID")?;
+    let client_id: String = env_var("AZURE_CLIENT_ID")?;
+    let token_file_path: String = env_var("AZURE_FEDERATED_TOKEN_FILE")?;
+    let credential = WorkloadIdentityCredential::new(tenant_id, client_id, token_file_path);
+    match std::env::var("AZURE_AUTHORITY_HOST") {
+      Ok(authority_host) => Ok(credential.authority_host(&authority_host)),
+      Err(_) => Ok(credential),
+    }
+  }
+
+  /// Set the authority host used to request the token. The default value is
+  /// https://login.microsoftonline.com/.
+  pub fn authority_host(mut self, authority_host: &str) -> WorkloadIdentityCredential {
+    self.authority_host = if authority_host.ends_with('/') {
+      authority_host.to_string()
+    } else {
+      authority_host.to_string() + "/"
+    };
+    self
+  }
+
+  /// Set the scope of the requested token. The default value is
+  /// https://cognitiveservices.azure.com/.default.
+  pub fn scope(mut self, scope: &str) -> WorkloadIdentityCredential {
+    self.scope = scope.to_string();
+    self
+  }
+
+  /// Get an access token by exchanging the federated token. The cached token is
+  /// returned if it does not expire shortly.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the token file cannot be read or if
+  /// the token request fails.
+  ///
+  pub async fn get_token(&self) -> Result<AccessToken, Error> {
+    self.token_cache.get_or_request(|| self.request_token()).await
+  }
+
+  async fn request_token(&self) -> Result<AccessToken, Error> {
+    let client_assertion: String = tokio::fs::read_to_string(&self.token_file_path).await?;
+    let token_uri: String = format!("{}{}/oauth2/v2.0/token", self.authority_host, self.tenant_id);
+    request_client_credentials_token(&self.http_client, &token_uri, &[
+      ("grant_type", "client_credentials"),
+      ("client_id", &self.client_id),
+      ("client_assertion_type", CLIENT_ASSERTION_TYPE),
+      ("client_assertion", client_assertion.trim()),
+      ("scope", &self.scope),
+    ]).await
+  }
+}
+
+#[async_trait]
+impl CredentialProvider for WorkloadIdentityCredential {
+  async fn get_credential(&self) -> Result<CredentialHeader, Error> {
+    Ok(self.get_token().await?.into())
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, body_string_contains};
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_exchange_the_federated_token_for_an_access_token() {
+    let token_file_path = std::env::temp_dir().join("openai-rust-workload-identity-token");
+    std::fs::write(&token_file_path, "federated-token\n").unwrap();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/tenant-id/oauth2/v2.0/token"))
+      .and(body_string_contains("client_assertion=federated-token&"))
+      .and(body_string_contains("client_assertion_type=urn%3Aietf%3Aparams%3Aoauth%3Aclient-assertion-type%3Ajwt-bearer"))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_json(serde_json::json!({"token_type": "Bearer", "expires_in": 3600, "access_token": "access-token"}))
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let credential: WorkloadIdentityCredential = WorkloadIdentityCredential::new(String::from("tenant-id"), String::from("client-id"), token_file_path.to_string_lossy().to_string())
+      .authority_host(&mock_server.uri());
+    let credential_header: CredentialHeader = credential.get_credential().await.unwrap();
+    assert_eq!(credential_header.value, "Bearer access-token");
+    assert!(credential_header.expires_on.is_some());
+    assert_eq!(credential.get_token().await.unwrap().token, "access-token");
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_token_file_does_not_exist() {
+    let credential: WorkloadIdentityCredential = WorkloadIdentityCredential::new(String::from("tenant-id"), String::from("client-id"), String::from("/does/not/exist"));
+    assert!(credential.get_token().await.is_err());
+  }
+}
@@ -0,0 +1,255 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::error::Error;
+use crate::openai::requestor::{Requestor, decode_json};
+use crate::openai::{Client, Operation};
+use crate::openai::embeddings::error::EmbeddingsError;
+use crate::openai::embeddings::model::{EmbeddingInput, EmbeddingsResponse, EncodingFormat, Usage};
+
+/// Maximum number of inputs accepted by the API in a single request.
+const MAX_BATCH_SIZE: usize = 2048;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Embeddings {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  model: Option<String>,
+  input: EmbeddingInput,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  dimensions: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  encoding_format: Option<EncodingFormat>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  user: Option<String>,
+  #[serde(skip, default = "default_batch_size")]
+  batch_size: usize,
+}
+
+fn default_batch_size() -> usize {
+  MAX_BATCH_SIZE
+}
+
+impl Default for Embeddings {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Embeddings {
+  pub fn new() -> Embeddings {
+    Embeddings {
+      model: None,
+      input: EmbeddingInput::StringArray(Vec::new()),
+      dimensions: None,
+      encoding_format: None,
+      user: None,
+      batch_size: MAX_BATCH_SIZE,
+    }
+  }
+
+  pub fn input(mut self, input: String) -> Embeddings {
+    self.input = EmbeddingInput::String(input);
+    self
+  }
+
+  pub fn inputs(mut self, inputs: Vec<String>) -> Embeddings {
+    self.input = EmbeddingInput::StringArray(inputs);
+    self
+  }
+
+  pub fn dimensions(mut self, dimensions: u32) -> Result<Embeddings, EmbeddingsError> {
+    if dimensions == 0 {
+      Err(EmbeddingsError::DimensionsOutOfRange(dimensions))
+    } else {
+      self.dimensions = Some(dimensions);
+      Ok(self)
+    }
+  }
+
+  pub fn encoding_format(mut self, encoding_format: EncodingFormat) -> Embeddings {
+    self.encoding_format = Some(encoding_format);
+    self
+  }
+
+  pub fn user(mut self, user: String) -> Embeddings {
+    if user.is_empty() {
+      self.user = None;
+    } else {
+      self.user = Some(user);
+    }
+    self
+  }
+
+  /// Set the maximum number of inputs sent in a single request. The default and
+  /// maximum value is 2048.
+  pub fn batch_size(mut self, batch_size: usize) -> Result<Embeddings, EmbeddingsError> {
+    if !(1..=MAX_BATCH_SIZE).contains(&batch_size) {
+      Err(EmbeddingsError::BatchSizeOutOfRange(batch_size))
+    } else {
+      self.batch_size = batch_size;
+      Ok(self)
+    }
+  }
+
+  /// Create the embeddings of the input. When there are more inputs than the batch
+  /// size, the inputs are split into batches sent one after the other, and the
+  /// responses are merged: the embeddings are indexed by their position in the
+  /// input and the usage is the sum of the usage of every batch.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the input or one of the inputs is empty,
+  /// or if one of the requests fails.
+  ///
+  pub async fn create(&self, client: &Client, model_id: &str, api_version: Option<&str>) -> Result<EmbeddingsResponse, Error> {
+    if self.input.is_empty() || self.input.has_empty_input() {
+      return Err(EmbeddingsError::EmptyInput.into());
+    }
+    let inputs: &Vec<String> = match &self.input {
+      EmbeddingInput::StringArray(inputs) if inputs.len() > self.batch_size => inputs,
+      input => return self.send(client, input, model_id, api_version).await,
+    };
+
+    let mut embeddings_response = EmbeddingsResponse {
+      object: String::from("list"),
+      data: Vec::with_capacity(inputs.len()),
+      model: String::new(),
+      usage: Usage::default(),
+    };
+    for (batch_index, batch) in inputs.chunks(self.batch_size).enumerate() {
+      let batch_response: EmbeddingsResponse = self.send(client, &EmbeddingInput::StringArray(batch.to_vec()), model_id, api_version).await?;
+      let offset: usize = batch_index * self.batch_size;
+      embeddings_response.data.extend(batch_response.data.into_iter().map(|mut embedding| {
+        embedding.index += offset;
+        embedding
+      }));
+      embeddings_response.model = batch_response.model;
+      embeddings_response.usage.prompt_tokens += batch_response.usage.prompt_tokens;
+      embeddings_response.usage.total_tokens += batch_response.usage.total_tokens;
+    }
+    embeddings_response.data.sort_by_key(|embedding| embedding.index);
+    Ok(embeddings_response)
+  }
+
+  async fn send(&self, client: &Client, input: &EmbeddingInput, model_id: &str, api_version: Option<&str>) -> Result<EmbeddingsResponse, Error> {
+    let request_body = self.request_body(client, input, model_id)?;
+    let response = client.post(Operation::Embeddings, &request_body, Some(model_id), api_version).await?;
+    decode_json::<EmbeddingsResponse>(response).await
+  }
+
+  fn request_body(&self, client: &Client, input: &EmbeddingInput, model_id: &str) -> Result<String, Error> {
+    let mut request_body: serde_json::Value = serde_json::to_value(self)?;
+    request_body["input"] = serde_json::to_value(input)?;
+    client.add_model_to_body(&mut request_body, model_id);
+    Ok(serde_json::to_string(&request_body)?)
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, body_json};
+  use crate::openai::ApiType;
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use crate::openai::embeddings::model::EmbeddingVector;
+  use super::*;
+
+  fn embeddings_response_body(count: usize, prompt_tokens: u32) -> serde_json::Value {
+    let data: Vec<serde_json::Value> = (0..count)
+      .map(|index| serde_json::json!({"object": "embedding", "index": index, "embedding": [index as f32, 0.5]}))
+      .collect();
+    serde_json::json!({"object": "list", "data": data, "model": "text-embedding-3-small", "usage": {"prompt_tokens": prompt_tokens, "total_tokens": prompt_tokens}})
+  }
+
+  #[tokio::test]
+  async fn it_should_create_embeddings_with_openai() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/embeddings"))
+      .and(body_json(serde_json::json!({"model": "text-embedding-3-small", "input": "Hello", "dimensions": 256, "encoding_format": "float", "user": "user-1"})))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_json(embeddings_response_body(1, 1))
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let embeddings_response: EmbeddingsResponse = Embeddings::new()
+      .input(String::from("Hello"))
+      .dimensions(256).unwrap()
+      .encoding_format(EncodingFormat::Float)
+      .user(String::from("user-1"))
+      .create(&client, "text-embedding-3-small", None).await.unwrap();
+    assert_eq!(embeddings_response.data.len(), 1);
+    assert_eq!(embeddings_response.data[0].embedding, EmbeddingVector::Float(vec![0.0, 0.5]));
+    assert_eq!(embeddings_response.usage, Usage {prompt_tokens: 1, total_tokens: 1});
+  }
+
+  #[tokio::test]
+  async fn it_should_create_embeddings_with_azure_deployment() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/openai/deployments/embedding-deployment/embeddings"))
+      .and(body_json(serde_json::json!({"input": ["Hello", "World"]})))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_json(embeddings_response_body(2, 2))
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let embeddings_response: EmbeddingsResponse = Embeddings::new()
+      .inputs(vec![String::from("Hello"), String::from("World")])
+      .create(&client, "embedding-deployment", Some("2024-10-21")).await.unwrap();
+    assert_eq!(embeddings_response.data.len(), 2);
+  }
+
+  #[tokio::test]
+  async fn it_should_split_the_inputs_into_batches_and_merge_the_responses() {
+    let mock_server = MockServer::start().await;
+    for (inputs, count) in [(vec!["a", "b"], 2), (vec!["c", "d"], 2), (vec!["e"], 1)] {
+      Mock::given(method("POST"))
+        .and(path("/embeddings"))
+        .and(body_json(serde_json::json!({"model": "text-embedding-3-small", "input": inputs})))
+        .respond_with(ResponseTemplate::new(200)
+          .set_body_json(embeddings_response_body(count, count as u32))
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    }
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let inputs: Vec<String> = ["a", "b", "c", "d", "e"].iter().map(|input| input.to_string()).collect();
+    let embeddings_response: EmbeddingsResponse = Embeddings::new()
+      .inputs(inputs)
+      .batch_size(2).unwrap()
+      .create(&client, "text-embedding-3-small", None).await.unwrap();
+    let indexes: Vec<usize> = embeddings_response.data.iter().map(|embedding| embedding.index).collect();
+    assert_eq!(indexes, vec![0, 1, 2, 3, 4]);
+    assert_eq!(embeddings_response.data[4].embedding, EmbeddingVector::Float(vec![0.0, 0.5]));
+    assert_eq!(embeddings_response.usage, Usage {prompt_tokens: 5, total_tokens: 5});
+    assert_eq!(embeddings_response.model, "text-embedding-3-small");
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_input_is_empty() {
+    let client: Client = Client::new_openai_client(create_auth_with_given_api_key("12345abcd"));
+    let result = Embeddings::new().create(&client, "text-embedding-3-small", None).await;
+    assert!(matches!(result, Err(Error::EmbeddingsValidationError(EmbeddingsError::EmptyInput))));
+    let result = Embeddings::new().inputs(vec![String::from("Hello"), String::new()]).create(&client, "text-embedding-3-small", None).await;
+    assert!(matches!(result, Err(Error::EmbeddingsValidationError(EmbeddingsError::EmptyInput))));
+  }
+
+  #[test]
+  fn it_should_return_an_error_when_the_parameters_are_out_of_range() {
+    assert_eq!(Embeddings::new().dimensions(0).unwrap_err(), EmbeddingsError::DimensionsOutOfRange(0));
+    assert_eq!(Embeddings::new().batch_size(0).unwrap_err(), EmbeddingsError::BatchSizeOutOfRange(0));
+    assert_eq!(Embeddings::new().batch_size(2049).unwrap_err(), EmbeddingsError::BatchSizeOutOfRange(2049));
+  }
+}
@@ -0,0 +1,44 @@
+use std::error::Error;
+use std::fmt::{Display, Debug};
+
+#[derive(Clone, PartialEq)]
+pub enum EmbeddingsError {
+  BatchSizeOutOfRange(usize),
+  DimensionsOutOfRange(u32),
+  EmptyInput,
+  InvalidBase64Embedding,
+}
+
+impl EmbeddingsError {
+  fn label(&self) -> &'static str {
+    match self {
+      EmbeddingsError::BatchSizeOutOfRange(_) => "BatchSizeOutOfRange",
+      EmbeddingsError::DimensionsOutOfRange(_) => "DimensionsOutOfRange",
+      EmbeddingsError::EmptyInput => "EmptyInput",
+      EmbeddingsError::InvalidBase64Embedding => "InvalidBase64Embedding",
+    }
+  }
+
+  fn error_message(&self) -> String {
+    match self {
+      EmbeddingsError::BatchSizeOutOfRange(batch_size) => format!("Batch size must be between 1 and 2048 [Given value: {}]", batch_size),
+      EmbeddingsError::DimensionsOutOfRange(dimensions) => format!("Dimensions must be greater than 0 [Given value: {}]", dimensions),
+      EmbeddingsError::EmptyInput => String::from("Input cannot be empty."),
+      EmbeddingsError::InvalidBase64Embedding => String::from("Embedding is not a valid base64 encoded array of 32-bit floats."),
+    }
+  }
+}
+
+impl Debug for EmbeddingsError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "[{}] {}", self.label(), self.error_message())
+  }
+}
+
+impl Display for EmbeddingsError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.error_message())
+  }
+}
+
+impl Error for EmbeddingsError {}
@@ -0,0 +1,6 @@
+pub mod error;
+pub mod model;
+
+mod embeddings_request;
+
+pub use embeddings_request::Embeddings;
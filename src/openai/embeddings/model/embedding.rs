@@ -0,0 +1,10 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::embeddings::model::EmbeddingVector;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Embedding {
+  pub index: usize,
+  pub object: String,
+  pub embedding: EmbeddingVector,
+}
@@ -0,0 +1,50 @@
+use serde::{Serialize, Deserialize};
+
+/// Input to embed: a single text or an array of texts.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EmbeddingInput {
+  String(String),
+  StringArray(Vec<String>),
+}
+
+impl EmbeddingInput {
+  pub fn len(&self) -> usize {
+    match self {
+      EmbeddingInput::String(_) => 1,
+      EmbeddingInput::StringArray(inputs) => inputs.len(),
+    }
+  }
+
+  /// Return true if there is no text to embed.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Return true if one of the texts to embed is empty.
+  pub fn has_empty_input(&self) -> bool {
+    match self {
+      EmbeddingInput::String(input) => input.is_empty(),
+      EmbeddingInput::StringArray(inputs) => inputs.iter().any(String::is_empty),
+    }
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_not_be_empty_when_one_of_the_inputs_is_empty() {
+    let input: EmbeddingInput = EmbeddingInput::StringArray(vec![String::new()]);
+    assert_eq!(input.len(), 1);
+    assert!(!input.is_empty());
+    assert!(input.has_empty_input());
+    assert!(EmbeddingInput::StringArray(Vec::new()).is_empty());
+    assert!(!EmbeddingInput::String(String::from("Hello")).has_empty_input());
+  }
+}
@@ -0,0 +1,61 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::{Serialize, Deserialize};
+
+use crate::openai::embeddings::error::EmbeddingsError;
+
+/// Embedding returned as an array of floats or, when the encoding format is
+/// [`crate::openai::embeddings::model::EncodingFormat::Base64`], as the base64
+/// encoding of the little-endian 32-bit floats.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EmbeddingVector {
+  Float(Vec<f32>),
+  Base64(String),
+}
+
+impl EmbeddingVector {
+  /// Get the embedding as floats, decoding it if it is base64 encoded.
+  /// 
+  /// # Errors
+  /// 
+  /// This function will return [`crate::openai::embeddings::error::EmbeddingsError::InvalidBase64Embedding`]
+  /// if the embedding is not valid base64 or if its length is not a multiple of 4 bytes.
+  /// 
+  pub fn to_floats(&self) -> Result<Vec<f32>, EmbeddingsError> {
+    match self {
+      EmbeddingVector::Float(embedding) => Ok(embedding.clone()),
+      EmbeddingVector::Base64(embedding) => {
+        let bytes: Vec<u8> = STANDARD.decode(embedding).map_err(|_| EmbeddingsError::InvalidBase64Embedding)?;
+        let floats = bytes.chunks_exact(4);
+        if !floats.remainder().is_empty() {
+          return Err(EmbeddingsError::InvalidBase64Embedding);
+        }
+        Ok(floats.map(|float| f32::from_le_bytes([float[0], float[1], float[2], float[3]])).collect())
+      },
+    }
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_decode_base64_embedding() {
+    let bytes: Vec<u8> = [0.5_f32, -1.25_f32].iter().flat_map(|float| float.to_le_bytes()).collect();
+    let embedding: EmbeddingVector = EmbeddingVector::Base64(STANDARD.encode(bytes));
+    assert_eq!(embedding.to_floats().unwrap(), vec![0.5, -1.25]);
+    assert_eq!(EmbeddingVector::Float(vec![0.5]).to_floats().unwrap(), vec![0.5]);
+  }
+
+  #[test]
+  fn it_should_return_an_error_when_base64_embedding_is_invalid() {
+    assert_eq!(EmbeddingVector::Base64(String::from("not base64!")).to_floats(), Err(EmbeddingsError::InvalidBase64Embedding));
+    assert_eq!(EmbeddingVector::Base64(STANDARD.encode([0_u8; 3])).to_floats(), Err(EmbeddingsError::InvalidBase64Embedding));
+  }
+}
@@ -0,0 +1,11 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::embeddings::model::{Embedding, Usage};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingsResponse {
+  pub object: String,
+  pub data: Vec<Embedding>,
+  pub model: String,
+  pub usage: Usage,
+}
@@ -0,0 +1,13 @@
+mod embedding;
+mod embedding_input;
+mod embedding_vector;
+mod embeddings_response;
+mod encoding_format;
+mod usage;
+
+pub use embedding::Embedding;
+pub use embedding_input::EmbeddingInput;
+pub use embedding_vector::EmbeddingVector;
+pub use embeddings_response::EmbeddingsResponse;
+pub use encoding_format::EncodingFormat;
+pub use usage::Usage;
@@ -0,0 +1,7 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Usage {
+  pub prompt_tokens: u32,
+  pub total_tokens: u32,
+}
@@ -1,30 +1,138 @@
 use std::fmt::{Display, Debug};
 
-#[derive(PartialEq, Eq)]
+use crate::openai::audio::error::AudioError;
+use crate::openai::batch::error::BatchError;
+use crate::openai::chat::error::ChatCompletionError;
+use crate::openai::completions::error::CompletionError;
+use crate::openai::embeddings::error::EmbeddingsError;
+use crate::openai::files::error::FileError;
+use crate::openai::fine_tuning::error::FineTuningError;
+use crate::openai::images::error::ImageError;
+use crate::openai::moderations::error::ModerationError;
+use crate::openai::moderations::model::ModerationResult;
+
+pub use crate::openai::api_error::{ApiError, ContentFilterDetectedResult, ContentFilterResults, ContentFilterSeverityResult, ErrorKind, InnerError};
+
+/// Error returned by every function of the crate. It is `Send + Sync + 'static`
+/// so it can be returned from spawned tasks, and the underlying error is
+/// available with [`std::error::Error::source()`].
 pub enum Error {
-  ApiError {status: u16, message: String},
+  ApiError(Box<ApiError>),
+  AudioValidationError(AudioError),
+  AuthenticationError {status: u16, message: String},
+  BatchValidationError(BatchError),
+  ChatCompletionValidationError(ChatCompletionError),
   ClientError(ClientErrorType),
+  CompletionValidationError(CompletionError),
+  ContentFlagged(Box<ModerationResult>),
+  CredentialUnavailable(String),
+  EmbeddingsValidationError(EmbeddingsError),
+  EnvironmentVariableError {name: String, source: std::env::VarError},
+  FileValidationError(FileError),
+  FineTuningValidationError(FineTuningError),
+  ImageValidationError(ImageError),
+  IoError(std::io::Error),
+  ModerationValidationError(ModerationError),
+  SerializationError(serde_json::Error),
+  TransportError {source: reqwest::Error, attempts: u32},
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum ClientErrorType {
+  HttpClientOptionsConflict,
+  InvalidHeader(String),
   ModelIdMissingToGenerateApiUriForAzure,
 }
 
 impl Error {
   fn label(&self) -> &'static str {
     match self {
-      Error::ApiError {..} => "ApiError",
+      Error::ApiError(_) => "ApiError",
+      Error::AudioValidationError(_) => "AudioValidationError",
+      Error::AuthenticationError {..} => "AuthenticationError",
+      Error::BatchValidationError(_) => "BatchValidationError",
+      Error::ChatCompletionValidationError(_) => "ChatCompletionValidationError",
       Error::ClientError(_) => "ClientError",
+      Error::CompletionValidationError(_) => "CompletionValidationError",
+      Error::ContentFlagged(_) => "ContentFlagged",
+      Error::CredentialUnavailable(_) => "CredentialUnavailable",
+      Error::EmbeddingsValidationError(_) => "EmbeddingsValidationError",
+      Error::EnvironmentVariableError {..} => "EnvironmentVariableError",
+      Error::FileValidationError(_) => "FileValidationError",
+      Error::FineTuningValidationError(_) => "FineTuningValidationError",
+      Error::ImageValidationError(_) => "ImageValidationError",
+      Error::IoError(_) => "IoError",
+      Error::ModerationValidationError(_) => "ModerationValidationError",
+      Error::SerializationError(_) => "SerializationError",
+      Error::TransportError {..} => "TransportError",
     }
   }
 
   fn error_message(&self) -> String {
     match self {
-      Error::ApiError {status, message} => format!("(HTTP {}) {}]", status, message),
+      Error::ApiError(api_error) => match &api_error.code {
+        Some(code) => format!("(HTTP {}) {}: {} [Attempts: {}]", api_error.status, code, api_error.message, api_error.attempts),
+        None => format!("(HTTP {}) {} [Attempts: {}]", api_error.status, api_error.message, api_error.attempts),
+      },
+      Error::AudioValidationError(source) => source.to_string(),
+      Error::AuthenticationError {status, message} => format!("Failed to acquire access token (HTTP {}) {}", status, message),
+      Error::BatchValidationError(source) => source.to_string(),
+      Error::ChatCompletionValidationError(source) => source.to_string(),
+      Error::ClientError(ClientErrorType::HttpClientOptionsConflict) => String::from("Connect timeout and proxy must be set on the given HTTP client"),
+      Error::ClientError(ClientErrorType::InvalidHeader(header)) => format!("Invalid header: {}", header),
       Error::ClientError(ClientErrorType::ModelIdMissingToGenerateApiUriForAzure) => String::from("Model ID is required to generate API URI for Azure"),
+      Error::CompletionValidationError(source) => source.to_string(),
+      Error::ContentFlagged(result) => format!("Content flagged by moderation: {}", result.categories.flagged().join(", ")),
+      Error::CredentialUnavailable(message) => format!("Credential is unavailable: {}", message),
+      Error::EmbeddingsValidationError(source) => source.to_string(),
+      Error::EnvironmentVariableError {name, source} => format!("Environment variable {} cannot be read: {}", name, source),
+      Error::FileValidationError(source) => source.to_string(),
+      Error::FineTuningValidationError(source) => source.to_string(),
+      Error::ImageValidationError(source) => source.to_string(),
+      Error::IoError(source) => source.to_string(),
+      Error::ModerationValidationError(source) => source.to_string(),
+      Error::SerializationError(source) => format!("Serialization failed: {}", source),
+      Error::TransportError {source, attempts} => format!("{} [Attempts: {}]", source, attempts),
+    }
+  }
+
+  /// Get the error returned by the API if it is an API error.
+  pub fn api_error(&self) -> Option<&ApiError> {
+    match self {
+      Error::ApiError(api_error) => Some(api_error.as_ref()),
+      _ => None,
+    }
+  }
+
+  /// Return true if the same request may succeed when it is sent again.
+  pub fn is_retriable(&self) -> bool {
+    match self {
+      Error::ApiError(api_error) => api_error.is_retriable(),
+      Error::TransportError {source, ..} => source.is_connect() || source.is_timeout(),
+      _ => false,
     }
   }
+
+  pub fn is_context_length_exceeded(&self) -> bool {
+    self.api_error().is_some_and(ApiError::is_context_length_exceeded)
+  }
+
+  pub fn is_rate_limit_exceeded(&self) -> bool {
+    self.api_error().is_some_and(ApiError::is_rate_limit_exceeded)
+  }
+
+  pub fn is_insufficient_quota(&self) -> bool {
+    self.api_error().is_some_and(ApiError::is_insufficient_quota)
+  }
+
+  pub fn is_content_filtered(&self) -> bool {
+    self.api_error().is_some_and(ApiError::is_content_filtered)
+  }
+}
+
+/// Read the environment variable with the given name.
+pub(crate) fn env_var(name: &str) -> Result<String, Error> {
+  std::env::var(name).map_err(|source| Error::EnvironmentVariableError {name: name.to_string(), source})
 }
 
 impl Debug for Error {
@@ -39,4 +147,132 @@ impl Display for Error {
   }
 }
 
-impl std::error::Error for Error {}
\ No newline at end of file
+impl std::error::Error for Error {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      Error::AudioValidationError(source) => Some(source),
+      Error::BatchValidationError(source) => Some(source),
+      Error::ChatCompletionValidationError(source) => Some(source),
+      Error::CompletionValidationError(source) => Some(source),
+      Error::EmbeddingsValidationError(source) => Some(source),
+      Error::EnvironmentVariableError {source, ..} => Some(source),
+      Error::FileValidationError(source) => Some(source),
+      Error::FineTuningValidationError(source) => Some(source),
+      Error::ImageValidationError(source) => Some(source),
+      Error::IoError(source) => Some(source),
+      Error::ModerationValidationError(source) => Some(source),
+      Error::SerializationError(source) => Some(source),
+      Error::TransportError {source, ..} => Some(source),
+      _ => None,
+    }
+  }
+}
+
+impl From<AudioError> for Error {
+  fn from(error: AudioError) -> Self {
+    Error::AudioValidationError(error)
+  }
+}
+
+impl From<BatchError> for Error {
+  fn from(error: BatchError) -> Self {
+    Error::BatchValidationError(error)
+  }
+}
+
+impl From<ChatCompletionError> for Error {
+  fn from(error: ChatCompletionError) -> Self {
+    Error::ChatCompletionValidationError(error)
+  }
+}
+
+impl From<CompletionError> for Error {
+  fn from(error: CompletionError) -> Self {
+    Error::CompletionValidationError(error)
+  }
+}
+
+impl From<EmbeddingsError> for Error {
+  fn from(error: EmbeddingsError) -> Self {
+    Error::EmbeddingsValidationError(error)
+  }
+}
+
+impl From<FileError> for Error {
+  fn from(error: FileError) -> Self {
+    Error::FileValidationError(error)
+  }
+}
+
+impl From<FineTuningError> for Error {
+  fn from(error: FineTuningError) -> Self {
+    Error::FineTuningValidationError(error)
+  }
+}
+
+impl From<ImageError> for Error {
+  fn from(error: ImageError) -> Self {
+    Error::ImageValidationError(error)
+  }
+}
+
+impl From<ModerationError> for Error {
+  fn from(error: ModerationError) -> Self {
+    Error::ModerationValidationError(error)
+  }
+}
+
+impl From<reqwest::Error> for Error {
+  fn from(error: reqwest::Error) -> Self {
+    Error::TransportError {source: error, attempts: 1}
+  }
+}
+
+impl From<serde_json::Error> for Error {
+  fn from(error: serde_json::Error) -> Self {
+    Error::SerializationError(error)
+  }
+}
+
+impl From<std::io::Error> for Error {
+  fn from(error: std::io::Error) -> Self {
+    Error::IoError(error)
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use std::error::Error as _;
+  use super::*;
+
+  fn assert_send_sync_static<T: Send + Sync + 'static>() {}
+
+  #[test]
+  fn it_should_be_send_sync_and_static() {
+    assert_send_sync_static::<Error>();
+  }
+
+  #[test]
+  fn it_should_chain_the_source_of_the_error() {
+    let error: Error = ChatCompletionError::EmptyMessages.into();
+    assert!(matches!(error, Error::ChatCompletionValidationError(ChatCompletionError::EmptyMessages)));
+    assert_eq!(error.source().unwrap().to_string(), ChatCompletionError::EmptyMessages.to_string());
+
+    let error: Error = serde_json::from_str::<serde_json::Value>("{").unwrap_err().into();
+    assert!(error.source().unwrap().is::<serde_json::Error>());
+
+    let error: Error = Error::ClientError(ClientErrorType::ModelIdMissingToGenerateApiUriForAzure);
+    assert!(error.source().is_none());
+  }
+
+  #[test]
+  fn it_should_return_an_error_when_the_environment_variable_is_not_set() {
+    let error: Error = env_var("ERROR_TEST_MISSING_ENVIRONMENT_VARIABLE").unwrap_err();
+    assert!(matches!(&error, Error::EnvironmentVariableError {name, source: std::env::VarError::NotPresent} if name == "ERROR_TEST_MISSING_ENVIRONMENT_VARIABLE"));
+    assert!(error.source().is_some());
+  }
+}
@@ -0,0 +1,166 @@
+use futures::stream::{self, Stream, StreamExt};
+
+const DATA_FIELD: &str = "data:";
+const DONE_SENTINEL: &str = "[DONE]";
+
+/// Incremental parser for server-sent events (SSE). Bytes are fed as they are
+/// received from the network and the `data` payload of every complete event
+/// is returned. Comments, other fields (`event`, `id`, `retry`) and the
+/// `[DONE]` sentinel sent by OpenAI and Azure OpenAI are not returned.
+pub(crate) struct EventStreamParser {
+  buffer: Vec<u8>,
+  data: Vec<String>,
+  done: bool,
+}
+
+impl EventStreamParser {
+  pub(crate) fn new() -> EventStreamParser {
+    EventStreamParser {
+      buffer: Vec::new(),
+      data: Vec::new(),
+      done: false,
+    }
+  }
+
+  /// Return true when the `[DONE]` sentinel has been received.
+  pub(crate) fn is_done(&self) -> bool {
+    self.done
+  }
+
+  /// Feed the parser with the given bytes and return the data of the events
+  /// completed by these bytes.
+  pub(crate) fn feed(&mut self, bytes: &[u8]) -> Vec<String> {
+    self.buffer.extend_from_slice(bytes);
+    let mut events: Vec<String> = Vec::new();
+    while let Some(position) = self.buffer.iter().position(|byte| *byte == b'\n') {
+      let line: Vec<u8> = self.buffer.drain(..=position).collect();
+      let line: String = String::from_utf8_lossy(&line).to_string();
+      let line: &str = line.trim_end_matches(['\n', '\r']);
+      if let Some(event) = self.parse_line(line) {
+        events.push(event);
+      }
+      if self.done {
+        break;
+      }
+    }
+    events
+  }
+
+  /// Flush the event that is still pending when the stream ends without a
+  /// trailing blank line.
+  pub(crate) fn finish(&mut self) -> Option<String> {
+    if !self.buffer.is_empty() {
+      let line: String = String::from_utf8_lossy(&self.buffer).to_string();
+      self.buffer.clear();
+      if let Some(event) = self.parse_line(line.trim_end_matches('\r')) {
+        return Some(event);
+      }
+    }
+    self.parse_line("")
+  }
+
+  fn parse_line(&mut self, line: &str) -> Option<String> {
+    if self.done {
+      return None;
+    }
+    if line.is_empty() {
+      return self.dispatch();
+    }
+    if let Some(value) = line.strip_prefix(DATA_FIELD) {
+      let value: &str = value.strip_prefix(' ').unwrap_or(value);
+      self.data.push(value.to_string());
+    }
+    None
+  }
+
+  fn dispatch(&mut self) -> Option<String> {
+    if self.data.is_empty() {
+      return None;
+    }
+    let data: String = self.data.join("\n");
+    self.data.clear();
+    if data == DONE_SENTINEL {
+      self.done = true;
+      None
+    } else {
+      Some(data)
+    }
+  }
+}
+
+/// Convert the body of the response into a stream of event data. The stream
+/// ends when the `[DONE]` sentinel is received or when the body is consumed.
+pub(crate) fn data_events(response: reqwest::Response) -> impl Stream<Item = Result<String, reqwest::Error>> {
+  let state = (response.bytes_stream().boxed(), EventStreamParser::new(), false);
+  stream::unfold(state, |(mut bytes_stream, mut parser, finished)| async move {
+    if finished || parser.is_done() {
+      return None;
+    }
+    match bytes_stream.next().await {
+      Some(Ok(bytes)) => {
+        let events: Vec<Result<String, reqwest::Error>> = parser.feed(&bytes).into_iter().map(Ok).collect();
+        Some((stream::iter(events), (bytes_stream, parser, false)))
+      },
+      Some(Err(error)) => Some((stream::iter(vec![Err(error)]), (bytes_stream, parser, true))),
+      None => {
+        let events: Vec<Result<String, reqwest::Error>> = parser.finish().into_iter().map(Ok).collect();
+        Some((stream::iter(events), (bytes_stream, parser, true)))
+      },
+    }
+  }).flatten()
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_parse_data_of_complete_events() {
+    let mut parser: EventStreamParser = EventStreamParser::new();
+
+    let events: Vec<String> = parser.feed(b"data: {\"a\":1}\n\ndata: {\"b\":2}\n\n");
+    assert_eq!(events, vec![String::from("{\"a\":1}"), String::from("{\"b\":2}")]);
+    assert!(!parser.is_done());
+  }
+
+  #[test]
+  fn it_should_parse_events_split_across_chunks() {
+    let mut parser: EventStreamParser = EventStreamParser::new();
+
+    assert!(parser.feed(b"data: {\"con").is_empty());
+    assert!(parser.feed(b"tent\":\"Hel").is_empty());
+    assert!(parser.feed(b"lo\"}\r\n").is_empty());
+    let events: Vec<String> = parser.feed(b"\r\n");
+    assert_eq!(events, vec![String::from("{\"content\":\"Hello\"}")]);
+  }
+
+  #[test]
+  fn it_should_join_multiple_data_lines_and_ignore_other_fields() {
+    let mut parser: EventStreamParser = EventStreamParser::new();
+
+    let events: Vec<String> = parser.feed(b": keep-alive\nevent: message\nid: 1\ndata: first\ndata:second\n\n");
+    assert_eq!(events, vec![String::from("first\nsecond")]);
+  }
+
+  #[test]
+  fn it_should_stop_at_done_sentinel() {
+    let mut parser: EventStreamParser = EventStreamParser::new();
+
+    let events: Vec<String> = parser.feed(b"data: {}\n\ndata: [DONE]\n\ndata: {\"ignored\":true}\n\n");
+    assert_eq!(events, vec![String::from("{}")]);
+    assert!(parser.is_done());
+    assert_eq!(parser.finish(), None);
+  }
+
+  #[test]
+  fn it_should_flush_pending_event_when_stream_ends() {
+    let mut parser: EventStreamParser = EventStreamParser::new();
+
+    assert!(parser.feed(b"data: {}\n").is_empty());
+    assert_eq!(parser.finish(), Some(String::from("{}")));
+  }
+}
@@ -0,0 +1,41 @@
+use std::error::Error;
+use std::fmt::{Display, Debug};
+
+#[derive(Clone, PartialEq)]
+pub enum FileError {
+  EmptyFile,
+  LimitValueOutOfRange(u32),
+  MissingFileName,
+}
+
+impl FileError {
+  fn label(&self) -> &'static str {
+    match self {
+      FileError::EmptyFile => "EmptyFile",
+      FileError::LimitValueOutOfRange(_) => "LimitValueOutOfRange",
+      FileError::MissingFileName => "MissingFileName",
+    }
+  }
+
+  fn error_message(&self) -> String {
+    match self {
+      FileError::EmptyFile => String::from("File cannot be empty."),
+      FileError::LimitValueOutOfRange(limit) => format!("Limit value must be between 1 and 10000 [Given value: {}]", limit),
+      FileError::MissingFileName => String::from("File name is required."),
+    }
+  }
+}
+
+impl Debug for FileError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "[{}] {}", self.label(), self.error_message())
+  }
+}
+
+impl Display for FileError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.error_message())
+  }
+}
+
+impl Error for FileError {}
@@ -0,0 +1,246 @@
+use std::path::Path;
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite};
+
+use crate::openai::error::Error;
+use crate::openai::requestor::{Requestor, decode_json, write_body};
+use crate::openai::{Client, MultipartForm, Operation};
+use crate::openai::files::error::FileError;
+use crate::openai::files::model::{DeletedFile, FileList, FileListParams, FileObject, FilePurpose};
+
+/// Upload a file with the given name and content. The file is processed
+/// asynchronously, its status tells when it can be used.
+///
+/// # Errors
+///
+/// This function will return an error if the file name or the content is empty or
+/// if the request fails.
+///
+pub async fn upload(client: &Client, file_name: &str, bytes: Vec<u8>, purpose: FilePurpose, api_version: Option<&str>) -> Result<FileObject, Error> {
+  if file_name.is_empty() {
+    return Err(FileError::MissingFileName.into());
+  }
+  if bytes.is_empty() {
+    return Err(FileError::EmptyFile.into());
+  }
+  let form: MultipartForm = MultipartForm::new()
+    .text("purpose", purpose.as_str())
+    .file("file", file_name, bytes, None);
+  let response = client.post_multipart(Operation::Files, &form, None, api_version).await?;
+  decode_json::<FileObject>(response).await
+}
+
+/// Upload the file at the given path, named after the last component of the path.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read, if it is empty or
+/// if the request fails.
+///
+pub async fn upload_from_path<P: AsRef<Path>>(client: &Client, path: P, purpose: FilePurpose, api_version: Option<&str>) -> Result<FileObject, Error> {
+  let path: &Path = path.as_ref();
+  let file_name: String = path.file_name()
+    .map(|file_name| file_name.to_string_lossy().to_string())
+    .ok_or(FileError::MissingFileName)?;
+  let bytes: Vec<u8> = tokio::fs::read(path).await?;
+  upload(client, &file_name, bytes, purpose, api_version).await
+}
+
+/// Upload the content read from the given reader with the given file name. The
+/// whole content is read in memory before the upload so the request can be
+/// retried, the reader should not be larger than the 512 MB accepted by the
+/// Files API.
+///
+/// # Errors
+///
+/// This function will return an error if the reader fails, if the content is empty
+/// or if the request fails.
+///
+pub async fn upload_from_reader<R: AsyncRead + Unpin>(client: &Client, file_name: &str, reader: &mut R, purpose: FilePurpose, api_version: Option<&str>) -> Result<FileObject, Error> {
+  let mut bytes: Vec<u8> = Vec::new();
+  reader.read_to_end(&mut bytes).await?;
+  upload(client, file_name, bytes, purpose, api_version).await
+}
+
+/// List a page of the files of the organization or of the Azure OpenAI resource.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails.
+///
+pub async fn list(client: &Client, params: &FileListParams, api_version: Option<&str>) -> Result<FileList, Error> {
+  let response = client.get(Operation::Files, &params.to_query(), None, api_version).await?;
+  decode_json::<FileList>(response).await
+}
+
+/// Retrieve the metadata of the file with the given ID.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails, e.g. when the file
+/// does not exist.
+///
+pub async fn retrieve(client: &Client, file_id: &str, api_version: Option<&str>) -> Result<FileObject, Error> {
+  let response = client.get(Operation::File(file_id), &[], None, api_version).await?;
+  decode_json::<FileObject>(response).await
+}
+
+/// Return the content of the file with the given ID.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails.
+///
+pub async fn content(client: &Client, file_id: &str, api_version: Option<&str>) -> Result<Vec<u8>, Error> {
+  let response = client.get(Operation::FileContent(file_id), &[], None, api_version).await?;
+  Ok(response.bytes().await?.to_vec())
+}
+
+/// Write the content of the file with the given ID to the given writer as it is
+/// received. Return the number of bytes written.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails or if the content cannot
+/// be written.
+///
+pub async fn download<W: AsyncWrite + Unpin>(client: &Client, file_id: &str, writer: &mut W, api_version: Option<&str>) -> Result<u64, Error> {
+  let response = client.get(Operation::FileContent(file_id), &[], None, api_version).await?;
+  write_body(response, writer).await
+}
+
+/// Delete the file with the given ID.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails.
+///
+pub async fn delete(client: &Client, file_id: &str, api_version: Option<&str>) -> Result<DeletedFile, Error> {
+  let response = client.delete(Operation::File(file_id), None, api_version).await?;
+  decode_json::<DeletedFile>(response).await
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, query_param};
+  use crate::openai::ApiType;
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use crate::openai::files::model::{FileStatus, SortOrder};
+  use super::*;
+
+  const FILE_OBJECT: &str = r#"{"id": "file-abc123", "object": "file", "bytes": 120000, "created_at": 1677610602, "filename": "train.jsonl", "purpose": "fine-tune", "status": "processed"}"#;
+
+  #[tokio::test]
+  async fn it_should_upload_a_file_from_a_path() {
+    let file_path = std::env::temp_dir().join("openai-rust-upload-train.jsonl");
+    tokio::fs::write(&file_path, b"{\"messages\": []}\n").await.unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/files"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(FILE_OBJECT))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let file: FileObject = upload_from_path(&client, &file_path, FilePurpose::FineTune, None).await.unwrap();
+    tokio::fs::remove_file(&file_path).await.unwrap();
+    assert_eq!(file.purpose, FilePurpose::FineTune);
+    assert!(file.status.unwrap().is_processed());
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: String = String::from_utf8_lossy(&requests[0].body).to_string();
+    assert!(body.contains("name=\"purpose\"\r\n\r\nfine-tune"));
+    assert!(body.contains("name=\"file\"; filename=\"openai-rust-upload-train.jsonl\""));
+    assert!(body.contains("{\"messages\": []}"));
+  }
+
+  #[tokio::test]
+  async fn it_should_upload_a_file_from_a_reader_with_azure() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/openai/files"))
+      .and(query_param("api-version", "2024-10-21"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"status": "pending", "bytes": 16, "purpose": "batch", "filename": "batch.jsonl", "id": "file-xyz", "created_at": 1677610602, "object": "file"}"#))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let mut reader: &[u8] = b"{\"custom_id\": 1}";
+    let file: FileObject = upload_from_reader(&client, "batch.jsonl", &mut reader, FilePurpose::Batch, None).await.unwrap();
+    assert_eq!(file.status, Some(FileStatus::Pending));
+    assert!(!file.status.unwrap().is_terminal());
+  }
+
+  #[tokio::test]
+  async fn it_should_list_a_page_of_files() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/files"))
+      .and(query_param("purpose", "fine-tune"))
+      .and(query_param("limit", "1"))
+      .and(query_param("after", "file-000"))
+      .and(query_param("order", "asc"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(format!(r#"{{"object": "list", "data": [{}], "has_more": true, "first_id": "file-abc123", "last_id": "file-abc123"}}"#, FILE_OBJECT)))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let params: FileListParams = FileListParams::new()
+      .purpose(FilePurpose::FineTune)
+      .limit(1).unwrap()
+      .after(String::from("file-000"))
+      .order(SortOrder::Asc);
+    let file_list: FileList = list(&client, &params, None).await.unwrap();
+    assert!(file_list.has_more);
+    assert_eq!(file_list.last_id, Some(String::from("file-abc123")));
+    assert_eq!(file_list.data[0].bytes, Some(120000));
+  }
+
+  #[tokio::test]
+  async fn it_should_retrieve_download_and_delete_a_file() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/files/file-abc123"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(FILE_OBJECT))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("GET"))
+      .and(path("/files/file-abc123/content"))
+      .respond_with(ResponseTemplate::new(200).set_body_bytes(b"line 1\nline 2\n".to_vec()))
+      .expect(2)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("DELETE"))
+      .and(path("/files/file-abc123"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"id": "file-abc123", "object": "file", "deleted": true}"#))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let file: FileObject = retrieve(&client, "file-abc123", None).await.unwrap();
+    assert_eq!(file.filename, "train.jsonl");
+    assert_eq!(content(&client, "file-abc123", None).await.unwrap(), b"line 1\nline 2\n".to_vec());
+    let mut writer: Vec<u8> = Vec::new();
+    assert_eq!(download(&client, "file-abc123", &mut writer, None).await.unwrap(), 14);
+    assert_eq!(writer, b"line 1\nline 2\n".to_vec());
+    assert!(delete(&client, "file-abc123", None).await.unwrap().deleted);
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_file_is_empty() {
+    let client: Client = Client::new_openai_client(create_auth_with_given_api_key("12345abcd"));
+    let result = upload(&client, "train.jsonl", Vec::new(), FilePurpose::FineTune, None).await;
+    assert!(matches!(result, Err(Error::FileValidationError(FileError::EmptyFile))));
+    assert_eq!(FileListParams::new().limit(0).unwrap_err(), FileError::LimitValueOutOfRange(0));
+  }
+}
@@ -0,0 +1,6 @@
+pub mod error;
+pub mod model;
+
+mod files_api;
+
+pub use files_api::{content, delete, download, list, retrieve, upload, upload_from_path, upload_from_reader};
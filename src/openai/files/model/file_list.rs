@@ -0,0 +1,15 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::files::model::FileObject;
+
+/// Page of files. Pass the `last_id` as the `after` parameter to get the next page
+/// while `has_more` is true.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileList {
+  pub object: String,
+  pub data: Vec<FileObject>,
+  #[serde(default)]
+  pub has_more: bool,
+  pub first_id: Option<String>,
+  pub last_id: Option<String>,
+}
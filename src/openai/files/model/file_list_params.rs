@@ -0,0 +1,65 @@
+use crate::openai::files::error::FileError;
+use crate::openai::files::model::{FilePurpose, SortOrder};
+
+/// Query parameters to filter and paginate the list of files.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileListParams {
+  purpose: Option<FilePurpose>,
+  limit: Option<u32>,
+  after: Option<String>,
+  order: Option<SortOrder>,
+}
+
+impl FileListParams {
+  pub fn new() -> FileListParams {
+    FileListParams::default()
+  }
+
+  /// Only list the files with the given purpose.
+  pub fn purpose(mut self, purpose: FilePurpose) -> FileListParams {
+    self.purpose = Some(purpose);
+    self
+  }
+
+  /// Set the number of files per page, between 1 and 10000.
+  pub fn limit(mut self, limit: u32) -> Result<FileListParams, FileError> {
+    if !(1..=10000).contains(&limit) {
+      Err(FileError::LimitValueOutOfRange(limit))
+    } else {
+      self.limit = Some(limit);
+      Ok(self)
+    }
+  }
+
+  /// Set the ID of the last file of the previous page.
+  pub fn after(mut self, after: String) -> FileListParams {
+    if after.is_empty() {
+      self.after = None;
+    } else {
+      self.after = Some(after);
+    }
+    self
+  }
+
+  pub fn order(mut self, order: SortOrder) -> FileListParams {
+    self.order = Some(order);
+    self
+  }
+
+  pub(crate) fn to_query(&self) -> Vec<(&'static str, String)> {
+    let mut query: Vec<(&'static str, String)> = Vec::new();
+    if let Some(purpose) = self.purpose {
+      query.push(("purpose", purpose.as_str().to_string()));
+    }
+    if let Some(limit) = self.limit {
+      query.push(("limit", limit.to_string()));
+    }
+    if let Some(after) = &self.after {
+      query.push(("after", after.clone()));
+    }
+    if let Some(order) = self.order {
+      query.push(("order", order.as_str().to_string()));
+    }
+    query
+  }
+}
@@ -0,0 +1,16 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::files::model::{FilePurpose, FileStatus};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileObject {
+  pub id: String,
+  pub object: String,
+  pub bytes: Option<u64>,
+  pub created_at: u64,
+  pub expires_at: Option<u64>,
+  pub filename: String,
+  pub purpose: FilePurpose,
+  pub status: Option<FileStatus>,
+  pub status_details: Option<String>,
+}
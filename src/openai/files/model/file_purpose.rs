@@ -0,0 +1,38 @@
+use serde::{Serialize, Deserialize};
+
+/// Intended use of a file. The `*_output` and `*_results` purposes are set by the
+/// API on the files it creates and cannot be used for an upload.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum FilePurpose {
+  #[serde(rename = "assistants")]
+  Assistants,
+  #[serde(rename = "assistants_output")]
+  AssistantsOutput,
+  #[serde(rename = "batch")]
+  Batch,
+  #[serde(rename = "batch_output")]
+  BatchOutput,
+  #[serde(rename = "fine-tune")]
+  FineTune,
+  #[serde(rename = "fine-tune-results")]
+  FineTuneResults,
+  #[serde(rename = "user_data")]
+  UserData,
+  #[serde(rename = "vision")]
+  Vision,
+}
+
+impl FilePurpose {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      FilePurpose::Assistants => "assistants",
+      FilePurpose::AssistantsOutput => "assistants_output",
+      FilePurpose::Batch => "batch",
+      FilePurpose::BatchOutput => "batch_output",
+      FilePurpose::FineTune => "fine-tune",
+      FilePurpose::FineTuneResults => "fine-tune-results",
+      FilePurpose::UserData => "user_data",
+      FilePurpose::Vision => "vision",
+    }
+  }
+}
@@ -0,0 +1,27 @@
+use serde::{Serialize, Deserialize};
+
+/// Status of a file. OpenAI only reports `uploaded`, `processed` and `error`, the
+/// other statuses are reported by Azure OpenAI while the file is imported.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileStatus {
+  Uploaded,
+  Pending,
+  Running,
+  Processed,
+  Error,
+  Deleting,
+  Deleted,
+}
+
+impl FileStatus {
+  /// Return true if the file can be used, e.g. by a fine-tuning job.
+  pub fn is_processed(&self) -> bool {
+    matches!(self, FileStatus::Processed)
+  }
+
+  /// Return true if the status will not change anymore.
+  pub fn is_terminal(&self) -> bool {
+    matches!(self, FileStatus::Processed | FileStatus::Error | FileStatus::Deleted)
+  }
+}
@@ -0,0 +1,15 @@
+mod deleted_file;
+mod file_list;
+mod file_list_params;
+mod file_object;
+mod file_purpose;
+mod file_status;
+mod sort_order;
+
+pub use deleted_file::DeletedFile;
+pub use file_list::FileList;
+pub use file_list_params::FileListParams;
+pub use file_object::FileObject;
+pub use file_purpose::FilePurpose;
+pub use file_status::FileStatus;
+pub use sort_order::SortOrder;
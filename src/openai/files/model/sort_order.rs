@@ -0,0 +1,18 @@
+use serde::{Serialize, Deserialize};
+
+/// Sort order of a list by the `created_at` timestamp.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+  Asc,
+  Desc,
+}
+
+impl SortOrder {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      SortOrder::Asc => "asc",
+      SortOrder::Desc => "desc",
+    }
+  }
+}
@@ -0,0 +1,47 @@
+use std::error::Error;
+use std::fmt::{Display, Debug};
+
+#[derive(Clone, PartialEq)]
+pub enum FineTuningError {
+  BatchSizeOutOfRange(u32),
+  EmptyTrainingFile,
+  LearningRateMultiplierOutOfRange(f64),
+  NEpochsOutOfRange(u32),
+  SuffixTooLong(usize),
+}
+
+impl FineTuningError {
+  fn label(&self) -> &'static str {
+    match self {
+      FineTuningError::BatchSizeOutOfRange(_) => "BatchSizeOutOfRange",
+      FineTuningError::EmptyTrainingFile => "EmptyTrainingFile",
+      FineTuningError::LearningRateMultiplierOutOfRange(_) => "LearningRateMultiplierOutOfRange",
+      FineTuningError::NEpochsOutOfRange(_) => "NEpochsOutOfRange",
+      FineTuningError::SuffixTooLong(_) => "SuffixTooLong",
+    }
+  }
+
+  fn error_message(&self) -> String {
+    match self {
+      FineTuningError::BatchSizeOutOfRange(batch_size) => format!("Batch size must be between 1 and 256 [Given value: {}]", batch_size),
+      FineTuningError::EmptyTrainingFile => String::from("Training file ID cannot be empty."),
+      FineTuningError::LearningRateMultiplierOutOfRange(multiplier) => format!("Learning rate multiplier must be greater than 0.0 and at most 10.0 [Given value: {}]", multiplier),
+      FineTuningError::NEpochsOutOfRange(n_epochs) => format!("Number of epochs must be between 1 and 50 [Given value: {}]", n_epochs),
+      FineTuningError::SuffixTooLong(length) => format!("Suffix must have at most 64 characters [Number of characters: {}]", length),
+    }
+  }
+}
+
+impl Debug for FineTuningError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "[{}] {}", self.label(), self.error_message())
+  }
+}
+
+impl Display for FineTuningError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.error_message())
+  }
+}
+
+impl Error for FineTuningError {}
@@ -0,0 +1,146 @@
+use crate::openai::error::Error;
+use crate::openai::requestor::{Requestor, decode_json};
+use crate::openai::{Client, Operation};
+use crate::openai::fine_tuning::model::{FineTuningJob, FineTuningJobCheckpointList, FineTuningJobEventList, FineTuningJobList, FineTuningListParams};
+
+/// List a page of the fine-tuning jobs of the organization or of the Azure OpenAI
+/// resource.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails.
+///
+pub async fn list(client: &Client, params: &FineTuningListParams, api_version: Option<&str>) -> Result<FineTuningJobList, Error> {
+  let response = client.get(Operation::FineTuningJobs, &params.to_query(), None, api_version).await?;
+  decode_json::<FineTuningJobList>(response).await
+}
+
+/// Retrieve the fine-tuning job with the given ID, e.g. to poll its status.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails.
+///
+pub async fn retrieve(client: &Client, job_id: &str, api_version: Option<&str>) -> Result<FineTuningJob, Error> {
+  let response = client.get(Operation::FineTuningJob(job_id), &[], None, api_version).await?;
+  decode_json::<FineTuningJob>(response).await
+}
+
+/// Cancel the fine-tuning job with the given ID and return it.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails, e.g. when the job is
+/// already finished.
+///
+pub async fn cancel(client: &Client, job_id: &str, api_version: Option<&str>) -> Result<FineTuningJob, Error> {
+  let response = client.post(Operation::FineTuningJobCancel(job_id), "{}", None, api_version).await?;
+  decode_json::<FineTuningJob>(response).await
+}
+
+/// List a page of the events of the fine-tuning job with the given ID.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails.
+///
+pub async fn list_events(client: &Client, job_id: &str, params: &FineTuningListParams, api_version: Option<&str>) -> Result<FineTuningJobEventList, Error> {
+  let response = client.get(Operation::FineTuningJobEvents(job_id), &params.to_query(), None, api_version).await?;
+  decode_json::<FineTuningJobEventList>(response).await
+}
+
+/// List a page of the checkpoints of the fine-tuning job with the given ID.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails.
+///
+pub async fn list_checkpoints(client: &Client, job_id: &str, params: &FineTuningListParams, api_version: Option<&str>) -> Result<FineTuningJobCheckpointList, Error> {
+  let response = client.get(Operation::FineTuningJobCheckpoints(job_id), &params.to_query(), None, api_version).await?;
+  decode_json::<FineTuningJobCheckpointList>(response).await
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, query_param};
+  use crate::openai::ApiType;
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use crate::openai::fine_tuning::model::FineTuningJobStatus;
+  use super::*;
+
+  const FINE_TUNING_JOB: &str = r#"{"object": "fine_tuning.job", "id": "ftjob-abc123", "model": "gpt-4o-mini-2024-07-18", "created_at": 1721764800, "finished_at": 1721768400, "fine_tuned_model": "ft:gpt-4o-mini-2024-07-18:org:notes:abc123", "status": "succeeded", "training_file": "file-abc123", "result_files": ["file-result"], "trained_tokens": 5768}"#;
+
+  #[tokio::test]
+  async fn it_should_list_and_retrieve_fine_tuning_jobs() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/fine_tuning/jobs"))
+      .and(query_param("after", "ftjob-000"))
+      .and(query_param("limit", "1"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(format!(r#"{{"object": "list", "data": [{}], "has_more": true}}"#, FINE_TUNING_JOB)))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("GET"))
+      .and(path("/fine_tuning/jobs/ftjob-abc123"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(FINE_TUNING_JOB))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let params: FineTuningListParams = FineTuningListParams::new().after(String::from("ftjob-000")).limit(1);
+    let job_list: FineTuningJobList = list(&client, &params, None).await.unwrap();
+    assert!(job_list.has_more);
+    assert_eq!(job_list.data[0].result_files, vec![String::from("file-result")]);
+    let job: FineTuningJob = retrieve(&client, "ftjob-abc123", None).await.unwrap();
+    assert!(job.status.is_terminal());
+    assert_eq!(job.fine_tuned_model, Some(String::from("ft:gpt-4o-mini-2024-07-18:org:notes:abc123")));
+  }
+
+  #[tokio::test]
+  async fn it_should_cancel_a_fine_tuning_job_with_azure() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/openai/fine_tuning/jobs/ftjob-xyz/cancel"))
+      .and(query_param("api-version", "2024-10-21"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"status": "cancelled", "model": "gpt-35-turbo-0125", "training_file": "file-abc123", "id": "ftjob-xyz", "created_at": 1721764800, "object": "fine_tuning.job"}"#))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let job: FineTuningJob = cancel(&client, "ftjob-xyz", None).await.unwrap();
+    assert_eq!(job.status, FineTuningJobStatus::Cancelled);
+  }
+
+  #[tokio::test]
+  async fn it_should_list_the_events_and_checkpoints_of_a_fine_tuning_job() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/fine_tuning/jobs/ftjob-abc123/events"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"object": "list", "data": [{"object": "fine_tuning.job.event", "id": "ftevent-1", "created_at": 1721764800, "level": "info", "message": "Step 100/100: training loss=0.12", "type": "metrics", "data": {"step": 100, "train_loss": 0.12}}], "has_more": false}"#))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("GET"))
+      .and(path("/fine_tuning/jobs/ftjob-abc123/checkpoints"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"object": "list", "data": [{"object": "fine_tuning.job.checkpoint", "id": "ftckpt-1", "created_at": 1721764800, "fine_tuned_model_checkpoint": "ft:gpt-4o-mini-2024-07-18:org:notes:abc123:ckpt-step-100", "metrics": {"step": 100.0, "train_loss": 0.12, "train_mean_token_accuracy": 0.96}, "fine_tuning_job_id": "ftjob-abc123", "step_number": 100}], "first_id": "ftckpt-1", "last_id": "ftckpt-1", "has_more": false}"#))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let event_list: FineTuningJobEventList = list_events(&client, "ftjob-abc123", &FineTuningListParams::new(), None).await.unwrap();
+    assert_eq!(event_list.data[0].event_type, Some(String::from("metrics")));
+    assert_eq!(event_list.data[0].data.as_ref().unwrap()["step"], 100);
+    let checkpoint_list: FineTuningJobCheckpointList = list_checkpoints(&client, "ftjob-abc123", &FineTuningListParams::new(), None).await.unwrap();
+    assert_eq!(checkpoint_list.data[0].step_number, 100);
+    assert_eq!(checkpoint_list.data[0].metrics.train_loss, Some(0.12));
+  }
+}
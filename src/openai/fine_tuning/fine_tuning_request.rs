@@ -0,0 +1,208 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::error::Error;
+use crate::openai::requestor::{Requestor, decode_json};
+use crate::openai::{Client, Operation};
+use crate::openai::fine_tuning::error::FineTuningError;
+use crate::openai::fine_tuning::model::{FineTuningJob, Hyperparameter, Hyperparameters};
+
+/// Request to create a fine-tuning job from uploaded training and validation files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FineTuning {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  model: Option<String>,
+  training_file: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  validation_file: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  hyperparameters: Option<Hyperparameters>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  suffix: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  seed: Option<u64>,
+}
+
+impl Default for FineTuning {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl FineTuning {
+  pub fn new() -> FineTuning {
+    FineTuning {
+      model: None,
+      training_file: String::new(),
+      validation_file: None,
+      hyperparameters: None,
+      suffix: None,
+      seed: None,
+    }
+  }
+
+  /// Set the ID of the uploaded training file, with the `fine-tune` purpose.
+  pub fn training_file(mut self, training_file: String) -> Result<FineTuning, FineTuningError> {
+    if training_file.is_empty() {
+      Err(FineTuningError::EmptyTrainingFile)
+    } else {
+      self.training_file = training_file;
+      Ok(self)
+    }
+  }
+
+  pub fn validation_file(mut self, validation_file: String) -> FineTuning {
+    if validation_file.is_empty() {
+      self.validation_file = None;
+    } else {
+      self.validation_file = Some(validation_file);
+    }
+    self
+  }
+
+  /// Set the number of epochs, between 1 and 50. It is chosen from the size of the
+  /// training data when it is not set.
+  pub fn n_epochs(mut self, n_epochs: u32) -> Result<FineTuning, FineTuningError> {
+    if !(1..=50).contains(&n_epochs) {
+      Err(FineTuningError::NEpochsOutOfRange(n_epochs))
+    } else {
+      self.hyperparameters_mut().n_epochs = Some(Hyperparameter::Value(n_epochs));
+      Ok(self)
+    }
+  }
+
+  pub fn batch_size(mut self, batch_size: u32) -> Result<FineTuning, FineTuningError> {
+    if !(1..=256).contains(&batch_size) {
+      Err(FineTuningError::BatchSizeOutOfRange(batch_size))
+    } else {
+      self.hyperparameters_mut().batch_size = Some(Hyperparameter::Value(batch_size));
+      Ok(self)
+    }
+  }
+
+  pub fn learning_rate_multiplier(mut self, learning_rate_multiplier: f64) -> Result<FineTuning, FineTuningError> {
+    if learning_rate_multiplier <= 0.0 || learning_rate_multiplier > 10.0 {
+      Err(FineTuningError::LearningRateMultiplierOutOfRange(learning_rate_multiplier))
+    } else {
+      self.hyperparameters_mut().learning_rate_multiplier = Some(Hyperparameter::Value(learning_rate_multiplier));
+      Ok(self)
+    }
+  }
+
+  /// Set the suffix, of at most 64 characters, added to the name of the fine-tuned
+  /// model.
+  pub fn suffix(mut self, suffix: String) -> Result<FineTuning, FineTuningError> {
+    let length: usize = suffix.chars().count();
+    if length > 64 {
+      Err(FineTuningError::SuffixTooLong(length))
+    } else if suffix.is_empty() {
+      self.suffix = None;
+      Ok(self)
+    } else {
+      self.suffix = Some(suffix);
+      Ok(self)
+    }
+  }
+
+  pub fn seed(mut self, seed: u64) -> FineTuning {
+    self.seed = Some(seed);
+    self
+  }
+
+  /// Create the fine-tuning job of the given base model. The model is always sent
+  /// in the body as fine-tuning is not bound to a deployment.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the training file is not set or if the
+  /// request fails.
+  ///
+  pub async fn create(&self, client: &Client, model_id: &str, api_version: Option<&str>) -> Result<FineTuningJob, Error> {
+    if self.training_file.is_empty() {
+      return Err(FineTuningError::EmptyTrainingFile.into());
+    }
+    let mut request_body: serde_json::Value = serde_json::to_value(self)?;
+    request_body["model"] = serde_json::Value::from(model_id);
+    let response = client.post(Operation::FineTuningJobs, &serde_json::to_string(&request_body)?, None, api_version).await?;
+    decode_json::<FineTuningJob>(response).await
+  }
+
+  fn hyperparameters_mut(&mut self) -> &mut Hyperparameters {
+    self.hyperparameters.get_or_insert(Hyperparameters {
+      n_epochs: None,
+      batch_size: None,
+      learning_rate_multiplier: None,
+    })
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, body_json, query_param};
+  use crate::openai::ApiType;
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use crate::openai::fine_tuning::model::FineTuningJobStatus;
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_create_a_fine_tuning_job_with_openai() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/fine_tuning/jobs"))
+      .and(body_json(serde_json::json!({"model": "gpt-4o-mini-2024-07-18", "training_file": "file-abc123", "validation_file": "file-def456", "hyperparameters": {"n_epochs": 3, "learning_rate_multiplier": 0.5}, "suffix": "notes", "seed": 42})))
+      .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"object": "fine_tuning.job", "id": "ftjob-abc123", "model": "gpt-4o-mini-2024-07-18", "created_at": 1721764800, "fine_tuned_model": null, "organization_id": "org-123", "result_files": [], "status": "validating_files", "validation_file": "file-def456", "training_file": "file-abc123", "hyperparameters": {"n_epochs": 3, "batch_size": "auto", "learning_rate_multiplier": 0.5}, "seed": 42, "suffix": "notes"}"#))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let job: FineTuningJob = FineTuning::new()
+      .training_file(String::from("file-abc123")).unwrap()
+      .validation_file(String::from("file-def456"))
+      .n_epochs(3).unwrap()
+      .learning_rate_multiplier(0.5).unwrap()
+      .suffix(String::from("notes")).unwrap()
+      .seed(42)
+      .create(&client, "gpt-4o-mini-2024-07-18", None).await.unwrap();
+    assert_eq!(job.status, FineTuningJobStatus::ValidatingFiles);
+    let hyperparameters: Hyperparameters = job.hyperparameters.unwrap();
+    assert_eq!(hyperparameters.n_epochs, Some(Hyperparameter::Value(3)));
+    assert_eq!(hyperparameters.batch_size, Some(Hyperparameter::Auto));
+  }
+
+  #[tokio::test]
+  async fn it_should_create_a_fine_tuning_job_with_azure() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/openai/fine_tuning/jobs"))
+      .and(query_param("api-version", "2024-10-21"))
+      .and(body_json(serde_json::json!({"model": "gpt-35-turbo-0125", "training_file": "file-abc123"})))
+      .respond_with(ResponseTemplate::new(201).set_body_string(r#"{"hyperparameters": {"n_epochs": "auto"}, "status": "pending", "model": "gpt-35-turbo-0125", "training_file": "file-abc123", "id": "ftjob-xyz", "created_at": 1721764800, "object": "fine_tuning.job"}"#))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let job: FineTuningJob = FineTuning::new()
+      .training_file(String::from("file-abc123")).unwrap()
+      .create(&client, "gpt-35-turbo-0125", None).await.unwrap();
+    assert_eq!(job.status, FineTuningJobStatus::Pending);
+    assert!(!job.status.is_terminal());
+    assert_eq!(job.hyperparameters.unwrap().n_epochs, Some(Hyperparameter::Auto));
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_training_file_is_missing() {
+    let client: Client = Client::new_openai_client(create_auth_with_given_api_key("12345abcd"));
+    let result = FineTuning::new().create(&client, "gpt-4o-mini", None).await;
+    assert!(matches!(result, Err(Error::FineTuningValidationError(FineTuningError::EmptyTrainingFile))));
+    assert_eq!(FineTuning::new().n_epochs(0).unwrap_err(), FineTuningError::NEpochsOutOfRange(0));
+    assert_eq!(FineTuning::new().batch_size(257).unwrap_err(), FineTuningError::BatchSizeOutOfRange(257));
+    assert_eq!(FineTuning::new().learning_rate_multiplier(0.0).unwrap_err(), FineTuningError::LearningRateMultiplierOutOfRange(0.0));
+    assert_eq!(FineTuning::new().suffix("a".repeat(65)).unwrap_err(), FineTuningError::SuffixTooLong(65));
+  }
+}
@@ -0,0 +1,10 @@
+pub mod error;
+pub mod model;
+
+mod fine_tuning_api;
+mod fine_tuning_request;
+mod training_file_validator;
+
+pub use fine_tuning_api::{cancel, list, list_checkpoints, list_events, retrieve};
+pub use fine_tuning_request::FineTuning;
+pub use training_file_validator::TrainingFileValidator;
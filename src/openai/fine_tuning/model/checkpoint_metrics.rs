@@ -0,0 +1,12 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckpointMetrics {
+  pub step: Option<f64>,
+  pub train_loss: Option<f64>,
+  pub train_mean_token_accuracy: Option<f64>,
+  pub valid_loss: Option<f64>,
+  pub valid_mean_token_accuracy: Option<f64>,
+  pub full_valid_loss: Option<f64>,
+  pub full_valid_mean_token_accuracy: Option<f64>,
+}
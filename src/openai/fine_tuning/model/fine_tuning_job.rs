@@ -0,0 +1,25 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::fine_tuning::model::{FineTuningJobError, FineTuningJobStatus, Hyperparameters};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FineTuningJob {
+  pub id: String,
+  pub object: String,
+  pub created_at: u64,
+  pub finished_at: Option<u64>,
+  pub estimated_finish: Option<u64>,
+  pub model: String,
+  pub fine_tuned_model: Option<String>,
+  pub organization_id: Option<String>,
+  pub status: FineTuningJobStatus,
+  pub hyperparameters: Option<Hyperparameters>,
+  pub training_file: String,
+  pub validation_file: Option<String>,
+  #[serde(default)]
+  pub result_files: Vec<String>,
+  pub trained_tokens: Option<u64>,
+  pub error: Option<FineTuningJobError>,
+  pub seed: Option<u64>,
+  pub suffix: Option<String>,
+}
@@ -0,0 +1,16 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::fine_tuning::model::CheckpointMetrics;
+
+/// Model checkpoint created at the end of an epoch of a fine-tuning job. It can be
+/// used like a fine-tuned model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FineTuningJobCheckpoint {
+  pub id: String,
+  pub object: String,
+  pub created_at: u64,
+  pub fine_tuned_model_checkpoint: String,
+  pub fine_tuning_job_id: String,
+  pub step_number: u64,
+  pub metrics: CheckpointMetrics,
+}
@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::fine_tuning::model::FineTuningJobCheckpoint;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FineTuningJobCheckpointList {
+  pub object: String,
+  pub data: Vec<FineTuningJobCheckpoint>,
+  #[serde(default)]
+  pub has_more: bool,
+  pub first_id: Option<String>,
+  pub last_id: Option<String>,
+}
@@ -0,0 +1,9 @@
+use serde::{Serialize, Deserialize};
+
+/// Reason why a fine-tuning job failed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FineTuningJobError {
+  pub code: Option<String>,
+  pub message: Option<String>,
+  pub param: Option<String>,
+}
@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FineTuningJobEvent {
+  pub id: String,
+  pub object: String,
+  pub created_at: u64,
+  pub level: String,
+  pub message: String,
+  #[serde(rename = "type")]
+  pub event_type: Option<String>,
+  pub data: Option<serde_json::Value>,
+}
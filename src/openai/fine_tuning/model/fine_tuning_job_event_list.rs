@@ -0,0 +1,12 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::fine_tuning::model::FineTuningJobEvent;
+
+/// Page of the events of a fine-tuning job, the most recent first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FineTuningJobEventList {
+  pub object: String,
+  pub data: Vec<FineTuningJobEvent>,
+  #[serde(default)]
+  pub has_more: bool,
+}
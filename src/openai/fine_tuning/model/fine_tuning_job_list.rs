@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::fine_tuning::model::FineTuningJob;
+
+/// Page of fine-tuning jobs. Pass the ID of the last job as the `after` parameter
+/// to get the next page while `has_more` is true.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FineTuningJobList {
+  pub object: String,
+  pub data: Vec<FineTuningJob>,
+  #[serde(default)]
+  pub has_more: bool,
+}
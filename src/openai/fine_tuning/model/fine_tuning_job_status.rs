@@ -0,0 +1,23 @@
+use serde::{Serialize, Deserialize};
+
+/// Status of a fine-tuning job. Azure OpenAI reports `created` and `pending` where
+/// OpenAI reports `validating_files` and `queued`.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FineTuningJobStatus {
+  Created,
+  Pending,
+  ValidatingFiles,
+  Queued,
+  Running,
+  Succeeded,
+  Failed,
+  Cancelled,
+}
+
+impl FineTuningJobStatus {
+  /// Return true if the status will not change anymore.
+  pub fn is_terminal(&self) -> bool {
+    matches!(self, FineTuningJobStatus::Succeeded | FineTuningJobStatus::Failed | FineTuningJobStatus::Cancelled)
+  }
+}
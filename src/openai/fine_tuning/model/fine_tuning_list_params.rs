@@ -0,0 +1,40 @@
+/// Query parameters to paginate the lists of fine-tuning jobs, events and
+/// checkpoints.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FineTuningListParams {
+  after: Option<String>,
+  limit: Option<u32>,
+}
+
+impl FineTuningListParams {
+  pub fn new() -> FineTuningListParams {
+    FineTuningListParams::default()
+  }
+
+  /// Set the ID of the last item of the previous page.
+  pub fn after(mut self, after: String) -> FineTuningListParams {
+    if after.is_empty() {
+      self.after = None;
+    } else {
+      self.after = Some(after);
+    }
+    self
+  }
+
+  /// Set the number of items per page, 20 by default.
+  pub fn limit(mut self, limit: u32) -> FineTuningListParams {
+    self.limit = Some(limit);
+    self
+  }
+
+  pub(crate) fn to_query(&self) -> Vec<(&'static str, String)> {
+    let mut query: Vec<(&'static str, String)> = Vec::new();
+    if let Some(after) = &self.after {
+      query.push(("after", after.clone()));
+    }
+    if let Some(limit) = self.limit {
+      query.push(("limit", limit.to_string()));
+    }
+    query
+  }
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+/// Hyperparameter of a fine-tuning job, either chosen by the API from the training
+/// data or set to the given value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Hyperparameter<T> {
+  Auto,
+  Value(T),
+}
+
+impl<T: Serialize> Serialize for Hyperparameter<T> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match self {
+      Hyperparameter::Auto => serializer.serialize_str("auto"),
+      Hyperparameter::Value(value) => value.serialize(serializer),
+    }
+  }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Hyperparameter<T> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw<T> {
+      Value(T),
+      Text(String),
+    }
+
+    match Raw::<T>::deserialize(deserializer)? {
+      Raw::Value(value) => Ok(Hyperparameter::Value(value)),
+      Raw::Text(text) if text == "auto" => Ok(Hyperparameter::Auto),
+      Raw::Text(text) => Err(D::Error::custom(format!("invalid hyperparameter value: {}", text))),
+    }
+  }
+}
@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::fine_tuning::model::Hyperparameter;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Hyperparameters {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub n_epochs: Option<Hyperparameter<u32>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub batch_size: Option<Hyperparameter<u32>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub learning_rate_multiplier: Option<Hyperparameter<f64>>,
+}
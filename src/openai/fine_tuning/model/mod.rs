@@ -0,0 +1,29 @@
+mod checkpoint_metrics;
+mod fine_tuning_job;
+mod fine_tuning_job_checkpoint;
+mod fine_tuning_job_checkpoint_list;
+mod fine_tuning_job_error;
+mod fine_tuning_job_event;
+mod fine_tuning_job_event_list;
+mod fine_tuning_job_list;
+mod fine_tuning_job_status;
+mod fine_tuning_list_params;
+mod hyperparameter;
+mod hyperparameters;
+mod training_file_report;
+mod training_issue;
+
+pub use checkpoint_metrics::CheckpointMetrics;
+pub use fine_tuning_job::FineTuningJob;
+pub use fine_tuning_job_checkpoint::FineTuningJobCheckpoint;
+pub use fine_tuning_job_checkpoint_list::FineTuningJobCheckpointList;
+pub use fine_tuning_job_error::FineTuningJobError;
+pub use fine_tuning_job_event::FineTuningJobEvent;
+pub use fine_tuning_job_event_list::FineTuningJobEventList;
+pub use fine_tuning_job_list::FineTuningJobList;
+pub use fine_tuning_job_status::FineTuningJobStatus;
+pub use fine_tuning_list_params::FineTuningListParams;
+pub use hyperparameter::Hyperparameter;
+pub use hyperparameters::Hyperparameters;
+pub use training_file_report::TrainingFileReport;
+pub use training_issue::{TrainingIssue, TrainingIssueKind};
@@ -0,0 +1,24 @@
+use crate::openai::fine_tuning::model::TrainingIssue;
+
+/// Result of the validation of a training file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TrainingFileReport {
+  /// Number of examples, i.e. of non-blank lines.
+  pub examples: usize,
+  /// Estimated number of tokens of each valid example.
+  pub token_counts: Vec<usize>,
+  pub issues: Vec<TrainingIssue>,
+}
+
+impl TrainingFileReport {
+  /// Return true if the file has at least one example and no issue.
+  pub fn is_valid(&self) -> bool {
+    self.examples > 0 && self.issues.is_empty()
+  }
+
+  /// Return the estimated number of tokens of the valid examples, billed once per
+  /// epoch.
+  pub fn total_tokens(&self) -> usize {
+    self.token_counts.iter().sum()
+  }
+}
@@ -0,0 +1,41 @@
+use std::fmt::Display;
+
+/// Problem found in a line of a training file. Lines are numbered from 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrainingIssue {
+  pub line: usize,
+  pub kind: TrainingIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrainingIssueKind {
+  /// The line is not a JSON object.
+  InvalidJson(String),
+  /// The example has no `messages` list or the list is empty.
+  MissingMessages,
+  /// The message at the given index cannot be read as a chat message, e.g. because
+  /// its role is unknown or its content is missing.
+  InvalidMessage {index: usize, reason: String},
+  /// The message at the given index has a key that is not a chat message field.
+  UnrecognizedKey {index: usize, key: String},
+  /// The example has no assistant message to learn from.
+  MissingAssistantMessage,
+  /// The example has more tokens than the maximum, it would be truncated.
+  TooManyTokens(usize),
+  /// The example is the same as the one at the given line.
+  Duplicate(usize),
+}
+
+impl Display for TrainingIssue {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match &self.kind {
+      TrainingIssueKind::InvalidJson(reason) => write!(f, "Line {}: invalid JSON: {}", self.line, reason),
+      TrainingIssueKind::MissingMessages => write!(f, "Line {}: missing messages list", self.line),
+      TrainingIssueKind::InvalidMessage {index, reason} => write!(f, "Line {}: invalid message {}: {}", self.line, index, reason),
+      TrainingIssueKind::UnrecognizedKey {index, key} => write!(f, "Line {}: unrecognized key {} in message {}", self.line, key, index),
+      TrainingIssueKind::MissingAssistantMessage => write!(f, "Line {}: missing assistant message", self.line),
+      TrainingIssueKind::TooManyTokens(tokens) => write!(f, "Line {}: too many tokens [Estimated tokens: {}]", self.line, tokens),
+      TrainingIssueKind::Duplicate(line) => write!(f, "Line {}: duplicate of line {}", self.line, line),
+    }
+  }
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::openai::chat::model::{ChatMessage, Role};
+use crate::openai::error::Error;
+use crate::openai::fine_tuning::model::{TrainingFileReport, TrainingIssue, TrainingIssueKind};
+
+/// Keys of a message in a training example. `weight` is specific to fine-tuning,
+/// it excludes an assistant message from the training when set to 0.
+const MESSAGE_KEYS: [&str; 7] = ["role", "content", "name", "function_call", "tool_calls", "tool_call_id", "weight"];
+
+/// Tokens added by the chat format for every message and to prime the reply.
+const TOKENS_PER_MESSAGE: usize = 3;
+const TOKENS_PER_NAME: usize = 1;
+const TOKENS_PER_REPLY: usize = 3;
+
+/// Local validator of a chat-format JSONL training file, to catch the problems the
+/// fine-tuning job would fail on before uploading the file.
+///
+/// The token counts are estimated with 4 characters per token plus the overhead of
+/// the chat format, they are close to the actual counts for English text.
+#[derive(Debug, Clone)]
+pub struct TrainingFileValidator {
+  max_tokens_per_example: usize,
+}
+
+impl Default for TrainingFileValidator {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl TrainingFileValidator {
+  pub fn new() -> TrainingFileValidator {
+    TrainingFileValidator {
+      max_tokens_per_example: 65536,
+    }
+  }
+
+  /// Set the maximum number of tokens of an example, i.e. the training context
+  /// length of the model, 65536 by default.
+  pub fn max_tokens_per_example(mut self, max_tokens_per_example: usize) -> TrainingFileValidator {
+    self.max_tokens_per_example = max_tokens_per_example;
+    self
+  }
+
+  /// Validate the content of a training file.
+  pub fn validate(&self, content: &str) -> TrainingFileReport {
+    let mut report: TrainingFileReport = TrainingFileReport::default();
+    let mut seen_examples: HashMap<String, usize> = HashMap::new();
+
+    for (index, line) in content.lines().enumerate() {
+      if line.trim().is_empty() {
+        continue;
+      }
+      let line_number: usize = index + 1;
+      report.examples += 1;
+
+      match self.validate_example(line) {
+        Ok((messages, tokens)) => {
+          if let Some(first_line) = seen_examples.get(&messages) {
+            report.issues.push(TrainingIssue {line: line_number, kind: TrainingIssueKind::Duplicate(*first_line)});
+          } else {
+            seen_examples.insert(messages, line_number);
+          }
+          if tokens > self.max_tokens_per_example {
+            report.issues.push(TrainingIssue {line: line_number, kind: TrainingIssueKind::TooManyTokens(tokens)});
+          }
+          report.token_counts.push(tokens);
+        },
+        Err(kind) => report.issues.push(TrainingIssue {line: line_number, kind}),
+      }
+    }
+    report
+  }
+
+  /// Read and validate the training file at the given path.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the file cannot be read.
+  ///
+  pub async fn validate_file<P: AsRef<Path>>(&self, path: P) -> Result<TrainingFileReport, Error> {
+    let content: String = tokio::fs::read_to_string(path).await?;
+    Ok(self.validate(&content))
+  }
+
+  /// Validate an example and return its messages in a canonical form, to find the
+  /// duplicates, along with its estimated number of tokens.
+  fn validate_example(&self, line: &str) -> Result<(String, usize), TrainingIssueKind> {
+    let example: serde_json::Value = serde_json::from_str(line)
+      .map_err(|error| TrainingIssueKind::InvalidJson(error.to_string()))?;
+    let messages: &Vec<serde_json::Value> = match example.get("messages").and_then(serde_json::Value::as_array) {
+      Some(messages) if !messages.is_empty() => messages,
+      _ => return Err(TrainingIssueKind::MissingMessages),
+    };
+
+    let mut tokens: usize = TOKENS_PER_REPLY;
+    let mut has_assistant_message: bool = false;
+    for (index, message) in messages.iter().enumerate() {
+      if let Some(key) = message.as_object().and_then(|fields| fields.keys().find(|key| !MESSAGE_KEYS.contains(&key.as_str()))) {
+        return Err(TrainingIssueKind::UnrecognizedKey {index, key: key.clone()});
+      }
+      let chat_message: ChatMessage = serde_json::from_value(message.clone())
+        .map_err(|error| TrainingIssueKind::InvalidMessage {index, reason: error.to_string()})?;
+      // The content is optional for the chat messages returned by the model, not for the examples
+      if !chat_message.has_content() {
+        return Err(TrainingIssueKind::InvalidMessage {index, reason: String::from("content is missing")});
+      }
+      has_assistant_message |= chat_message.role == Role::Assistant && chat_message.has_content();
+      tokens += TOKENS_PER_MESSAGE + estimate_tokens(&chat_message.content);
+      if let Some(name) = &chat_message.name {
+        tokens += TOKENS_PER_NAME + estimate_tokens(name);
+      }
+    }
+    if !has_assistant_message {
+      return Err(TrainingIssueKind::MissingAssistantMessage);
+    }
+    Ok((serde_json::Value::Array(messages.clone()).to_string(), tokens))
+  }
+}
+
+fn estimate_tokens(text: &str) -> usize {
+  text.chars().count().div_ceil(4)
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_validate_a_training_file() {
+    let content: &str = concat!(
+      r#"{"messages": [{"role": "system", "content": "You are a note taker."}, {"role": "user", "content": "Summarize"}, {"role": "assistant", "content": "Done", "weight": 1}]}"#, "\n",
+      r#"{"messages": [{"role": "user", "content": "Hello"}, {"role": "assistant", "content": "Hi"}]}"#, "\n",
+      "\n",
+    );
+    let report: TrainingFileReport = TrainingFileValidator::new().validate(content);
+    assert!(report.is_valid());
+    assert_eq!(report.examples, 2);
+    // 3 messages of 3 tokens, 6 + 3 + 1 content tokens and 3 tokens to prime the reply
+    assert_eq!(report.token_counts, vec![22, 12]);
+    assert_eq!(report.total_tokens(), 34);
+  }
+
+  #[test]
+  fn it_should_report_the_issues_of_a_training_file() {
+    let content: &str = concat!(
+      r#"{"messages": [{"role": "user", "content": "Hello"}, {"role": "assistant", "content": "Hi"}]}"#, "\n",
+      r#"{"messages": [{"role": "user", "content": "Hello"}]}"#, "\n",
+      r#"{"messages": [{"role": "robot", "content": "Hello"}]}"#, "\n",
+      r#"{"messages": [{"role": "user", "content": "Hello", "mood": "happy"}]}"#, "\n",
+      r#"{"prompt": "Hello", "completion": "Hi"}"#, "\n",
+      r#"{"messages": "#, "\n",
+      r#"{"messages": [{"role": "user", "content": "Hello"}, {"role": "assistant", "content": "Hi"}]}"#, "\n",
+      r#"{"messages": [{"role": "user", "content": "Tell me everything"}, {"role": "assistant", "content": "Everything"}]}"#, "\n",
+      r#"{"messages": [{"role": "user"}, {"role": "assistant"}]}"#, "\n",
+    );
+    let report: TrainingFileReport = TrainingFileValidator::new().max_tokens_per_example(15).validate(content);
+    assert!(!report.is_valid());
+    assert_eq!(report.examples, 9);
+    let kinds: Vec<(usize, &TrainingIssueKind)> = report.issues.iter().map(|issue| (issue.line, &issue.kind)).collect();
+    assert_eq!(kinds[0], (2, &TrainingIssueKind::MissingAssistantMessage));
+    assert!(matches!(kinds[1], (3, TrainingIssueKind::InvalidMessage {index: 0, ..})));
+    assert_eq!(kinds[2], (4, &TrainingIssueKind::UnrecognizedKey {index: 0, key: String::from("mood")}));
+    assert_eq!(kinds[3], (5, &TrainingIssueKind::MissingMessages));
+    assert!(matches!(kinds[4], (6, TrainingIssueKind::InvalidJson(_))));
+    assert_eq!(kinds[5], (7, &TrainingIssueKind::Duplicate(1)));
+    assert_eq!(kinds[6], (8, &TrainingIssueKind::TooManyTokens(17)));
+    assert_eq!(kinds[7], (9, &TrainingIssueKind::InvalidMessage {index: 0, reason: String::from("content is missing")}));
+    assert_eq!(report.issues[5].to_string(), "Line 7: duplicate of line 1");
+  }
+
+  #[test]
+  fn it_should_not_validate_an_empty_training_file() {
+    let report: TrainingFileReport = TrainingFileValidator::new().validate("\n");
+    assert_eq!(report.examples, 0);
+    assert!(!report.is_valid());
+  }
+}
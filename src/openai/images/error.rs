@@ -0,0 +1,47 @@
+use std::error::Error;
+use std::fmt::{Display, Debug};
+
+#[derive(Clone, PartialEq)]
+pub enum ImageError {
+  EmptyPrompt,
+  InvalidBase64Image,
+  MissingBase64Image,
+  MissingImage,
+  NValueOutOfRange(u8),
+}
+
+impl ImageError {
+  fn label(&self) -> &'static str {
+    match self {
+      ImageError::EmptyPrompt => "EmptyPrompt",
+      ImageError::InvalidBase64Image => "InvalidBase64Image",
+      ImageError::MissingBase64Image => "MissingBase64Image",
+      ImageError::MissingImage => "MissingImage",
+      ImageError::NValueOutOfRange(_) => "NValueOutOfRange",
+    }
+  }
+
+  fn error_message(&self) -> String {
+    match self {
+      ImageError::EmptyPrompt => String::from("Prompt cannot be empty."),
+      ImageError::InvalidBase64Image => String::from("Image is not valid base64."),
+      ImageError::MissingBase64Image => String::from("Image has no b64_json data, set the response format to b64_json."),
+      ImageError::MissingImage => String::from("Image is required."),
+      ImageError::NValueOutOfRange(n) => format!("N value must be between 1 and 10 [Given value: {}]", n),
+    }
+  }
+}
+
+impl Debug for ImageError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "[{}] {}", self.label(), self.error_message())
+  }
+}
+
+impl Display for ImageError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.error_message())
+  }
+}
+
+impl Error for ImageError {}
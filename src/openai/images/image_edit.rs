@@ -0,0 +1,189 @@
+use crate::openai::error::Error;
+use crate::openai::requestor::{Requestor, decode_json};
+use crate::openai::{Client, MultipartForm, Operation};
+use crate::openai::images::error::ImageError;
+use crate::openai::images::model::{ImageFile, ImageResponse, ImageSize, ResponseFormat};
+
+/// Edit of an image sent as `multipart/form-data`. The transparent areas of the
+/// mask, or of the image when there is no mask, are edited according to the prompt.
+#[derive(Debug, Clone, Default)]
+pub struct ImageEdit {
+  image: Option<ImageFile>,
+  mask: Option<ImageFile>,
+  prompt: String,
+  n: Option<u8>,
+  size: Option<ImageSize>,
+  response_format: Option<ResponseFormat>,
+  user: Option<String>,
+}
+
+impl ImageEdit {
+  pub fn new() -> ImageEdit {
+    ImageEdit::default()
+  }
+
+  /// Set the square PNG image to edit.
+  pub fn image(mut self, image: ImageFile) -> ImageEdit {
+    self.image = Some(image);
+    self
+  }
+
+  /// Set the PNG mask, of the same size as the image, whose transparent areas
+  /// indicate where the image should be edited.
+  pub fn mask(mut self, mask: ImageFile) -> ImageEdit {
+    self.mask = Some(mask);
+    self
+  }
+
+  pub fn prompt(mut self, prompt: String) -> Result<ImageEdit, ImageError> {
+    if prompt.is_empty() {
+      Err(ImageError::EmptyPrompt)
+    } else {
+      self.prompt = prompt;
+      Ok(self)
+    }
+  }
+
+  pub fn n(mut self, n: u8) -> Result<ImageEdit, ImageError> {
+    if !(1..=10).contains(&n) {
+      Err(ImageError::NValueOutOfRange(n))
+    } else {
+      self.n = Some(n);
+      Ok(self)
+    }
+  }
+
+  pub fn size(mut self, size: ImageSize) -> ImageEdit {
+    self.size = Some(size);
+    self
+  }
+
+  pub fn response_format(mut self, response_format: ResponseFormat) -> ImageEdit {
+    self.response_format = Some(response_format);
+    self
+  }
+
+  pub fn user(mut self, user: String) -> ImageEdit {
+    if user.is_empty() {
+      self.user = None;
+    } else {
+      self.user = Some(user);
+    }
+    self
+  }
+
+  /// Create the edited images.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the image is not set, if the prompt is
+  /// empty or if the request fails.
+  ///
+  pub async fn create(&self, client: &Client, model_id: &str, api_version: Option<&str>) -> Result<ImageResponse, Error> {
+    let form: MultipartForm = self.form(client, model_id)?;
+    let response = client.post_multipart(Operation::ImageEdits, &form, Some(model_id), api_version).await?;
+    decode_json::<ImageResponse>(response).await
+  }
+
+  fn form(&self, client: &Client, model_id: &str) -> Result<MultipartForm, Error> {
+    let image: &ImageFile = self.image.as_ref().ok_or(ImageError::MissingImage)?;
+    if self.prompt.is_empty() {
+      return Err(ImageError::EmptyPrompt.into());
+    }
+    let mut form: MultipartForm = MultipartForm::new()
+      .file("image", &image.file_name, image.bytes.clone(), Some("image/png"))
+      .text("prompt", &self.prompt);
+    if let Some(mask) = &self.mask {
+      form = form.file("mask", &mask.file_name, mask.bytes.clone(), Some("image/png"));
+    }
+    form = client.add_model_to_form(form, model_id);
+    if let Some(n) = self.n {
+      form = form.text("n", &n.to_string());
+    }
+    if let Some(size) = self.size {
+      form = form.text("size", size.as_str());
+    }
+    if let Some(response_format) = self.response_format {
+      form = form.text("response_format", response_format.as_str());
+    }
+    if let Some(user) = &self.user {
+      form = form.text("user", user);
+    }
+    Ok(form)
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, query_param};
+  use crate::openai::ApiType;
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_edit_an_image_with_a_mask() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/images/edits"))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_string(r#"{"created": 1698342300, "data": [{"url": "https://example.com/edit-1.png"}, {"url": "https://example.com/edit-2.png"}]}"#)
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let image_response: ImageResponse = ImageEdit::new()
+      .image(ImageFile::new("image.png", b"image-bytes".to_vec()))
+      .mask(ImageFile::new("mask.png", b"mask-bytes".to_vec()))
+      .prompt(String::from("Add a flamingo")).unwrap()
+      .n(2).unwrap()
+      .size(ImageSize::Size512x512)
+      .create(&client, "dall-e-2", None).await.unwrap();
+    assert_eq!(image_response.data.len(), 2);
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: String = String::from_utf8_lossy(&requests[0].body).to_string();
+    assert!(body.contains("name=\"image\"; filename=\"image.png\"\r\nContent-Type: image/png\r\n\r\nimage-bytes"));
+    assert!(body.contains("name=\"mask\"; filename=\"mask.png\"\r\nContent-Type: image/png\r\n\r\nmask-bytes"));
+    assert!(body.contains("name=\"prompt\"\r\n\r\nAdd a flamingo"));
+    assert!(body.contains("name=\"model\"\r\n\r\ndall-e-2"));
+    assert!(body.contains("name=\"n\"\r\n\r\n2"));
+    assert!(body.contains("name=\"size\"\r\n\r\n512x512"));
+  }
+
+  #[tokio::test]
+  async fn it_should_edit_an_image_with_azure_deployment() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/openai/deployments/dalle2/images/edits"))
+      .and(query_param("api-version", "2024-10-21"))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_string(r#"{"created": 1698342300, "data": [{"url": "https://example.com/edit-1.png"}]}"#)
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let result = ImageEdit::new()
+      .image(ImageFile::new("image.png", b"image-bytes".to_vec()))
+      .prompt(String::from("Add a flamingo")).unwrap()
+      .create(&client, "dalle2", None).await;
+    assert!(result.is_ok());
+    let requests = mock_server.received_requests().await.unwrap();
+    assert!(!String::from_utf8_lossy(&requests[0].body).contains("name=\"model\""));
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_image_is_missing() {
+    let client: Client = Client::new_openai_client(create_auth_with_given_api_key("12345abcd"));
+    let result = ImageEdit::new().prompt(String::from("Add a flamingo")).unwrap().create(&client, "dall-e-2", None).await;
+    assert!(matches!(result, Err(Error::ImageValidationError(ImageError::MissingImage))));
+  }
+}
@@ -0,0 +1,180 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::error::Error;
+use crate::openai::requestor::{Requestor, decode_json};
+use crate::openai::{Client, Operation};
+use crate::openai::images::error::ImageError;
+use crate::openai::images::model::{ImageQuality, ImageResponse, ImageSize, ImageStyle, ResponseFormat};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageGeneration {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  model: Option<String>,
+  prompt: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  n: Option<u8>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  size: Option<ImageSize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  quality: Option<ImageQuality>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  style: Option<ImageStyle>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  response_format: Option<ResponseFormat>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  user: Option<String>,
+}
+
+impl Default for ImageGeneration {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl ImageGeneration {
+  pub fn new() -> ImageGeneration {
+    ImageGeneration {
+      model: None,
+      prompt: String::new(),
+      n: None,
+      size: None,
+      quality: None,
+      style: None,
+      response_format: None,
+      user: None,
+    }
+  }
+
+  pub fn prompt(mut self, prompt: String) -> Result<ImageGeneration, ImageError> {
+    if prompt.is_empty() {
+      Err(ImageError::EmptyPrompt)
+    } else {
+      self.prompt = prompt;
+      Ok(self)
+    }
+  }
+
+  /// Set the number of images to generate, between 1 and 10. DALL·E 3 only
+  /// supports 1.
+  pub fn n(mut self, n: u8) -> Result<ImageGeneration, ImageError> {
+    if !(1..=10).contains(&n) {
+      Err(ImageError::NValueOutOfRange(n))
+    } else {
+      self.n = Some(n);
+      Ok(self)
+    }
+  }
+
+  pub fn size(mut self, size: ImageSize) -> ImageGeneration {
+    self.size = Some(size);
+    self
+  }
+
+  pub fn quality(mut self, quality: ImageQuality) -> ImageGeneration {
+    self.quality = Some(quality);
+    self
+  }
+
+  pub fn style(mut self, style: ImageStyle) -> ImageGeneration {
+    self.style = Some(style);
+    self
+  }
+
+  pub fn response_format(mut self, response_format: ResponseFormat) -> ImageGeneration {
+    self.response_format = Some(response_format);
+    self
+  }
+
+  pub fn user(mut self, user: String) -> ImageGeneration {
+    if user.is_empty() {
+      self.user = None;
+    } else {
+      self.user = Some(user);
+    }
+    self
+  }
+
+  /// Generate images from the prompt.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the prompt is empty or if the request fails.
+  ///
+  pub async fn create(&self, client: &Client, model_id: &str, api_version: Option<&str>) -> Result<ImageResponse, Error> {
+    if self.prompt.is_empty() {
+      return Err(ImageError::EmptyPrompt.into());
+    }
+    let mut request_body: serde_json::Value = serde_json::to_value(self)?;
+    client.add_model_to_body(&mut request_body, model_id);
+    let response = client.post(Operation::ImageGenerations, &serde_json::to_string(&request_body)?, Some(model_id), api_version).await?;
+    decode_json::<ImageResponse>(response).await
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, body_json, query_param};
+  use crate::openai::ApiType;
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_generate_images_with_openai() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/images/generations"))
+      .and(body_json(serde_json::json!({"model": "dall-e-3", "prompt": "A lighthouse", "n": 1, "size": "1792x1024", "quality": "hd", "style": "natural", "response_format": "b64_json"})))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_string(r#"{"created": 1698342300, "data": [{"b64_json": "iVBORw0KGgo=", "revised_prompt": "A lighthouse at dusk"}]}"#)
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let image_response: ImageResponse = ImageGeneration::new()
+      .prompt(String::from("A lighthouse")).unwrap()
+      .n(1).unwrap()
+      .size(ImageSize::Size1792x1024)
+      .quality(ImageQuality::Hd)
+      .style(ImageStyle::Natural)
+      .response_format(ResponseFormat::B64Json)
+      .create(&client, "dall-e-3", None).await.unwrap();
+    assert_eq!(image_response.data[0].revised_prompt, Some(String::from("A lighthouse at dusk")));
+    assert_eq!(image_response.data[0].to_bytes().unwrap(), vec![137, 80, 78, 71, 13, 10, 26, 10]);
+  }
+
+  #[tokio::test]
+  async fn it_should_generate_images_with_azure_deployment() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/openai/deployments/dalle3/images/generations"))
+      .and(query_param("api-version", "2024-10-21"))
+      .and(body_json(serde_json::json!({"prompt": "A lighthouse"})))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_string(r#"{"created": 1698342300, "data": [{"url": "https://example.com/image.png"}]}"#)
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let image_response: ImageResponse = ImageGeneration::new()
+      .prompt(String::from("A lighthouse")).unwrap()
+      .create(&client, "dalle3", None).await.unwrap();
+    assert_eq!(image_response.data[0].url, Some(String::from("https://example.com/image.png")));
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_prompt_is_empty() {
+    let client: Client = Client::new_openai_client(create_auth_with_given_api_key("12345abcd"));
+    let result = ImageGeneration::new().create(&client, "dall-e-3", None).await;
+    assert!(matches!(result, Err(Error::ImageValidationError(ImageError::EmptyPrompt))));
+    assert_eq!(ImageGeneration::new().n(11).unwrap_err(), ImageError::NValueOutOfRange(11));
+  }
+}
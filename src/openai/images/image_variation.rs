@@ -0,0 +1,135 @@
+use crate::openai::error::Error;
+use crate::openai::requestor::{Requestor, decode_json};
+use crate::openai::{Client, MultipartForm, Operation};
+use crate::openai::images::error::ImageError;
+use crate::openai::images::model::{ImageFile, ImageResponse, ImageSize, ResponseFormat};
+
+/// Variations of an image sent as `multipart/form-data`.
+#[derive(Debug, Clone, Default)]
+pub struct ImageVariation {
+  image: Option<ImageFile>,
+  n: Option<u8>,
+  size: Option<ImageSize>,
+  response_format: Option<ResponseFormat>,
+  user: Option<String>,
+}
+
+impl ImageVariation {
+  pub fn new() -> ImageVariation {
+    ImageVariation::default()
+  }
+
+  /// Set the square PNG image used as the base of the variations.
+  pub fn image(mut self, image: ImageFile) -> ImageVariation {
+    self.image = Some(image);
+    self
+  }
+
+  pub fn n(mut self, n: u8) -> Result<ImageVariation, ImageError> {
+    if !(1..=10).contains(&n) {
+      Err(ImageError::NValueOutOfRange(n))
+    } else {
+      self.n = Some(n);
+      Ok(self)
+    }
+  }
+
+  pub fn size(mut self, size: ImageSize) -> ImageVariation {
+    self.size = Some(size);
+    self
+  }
+
+  pub fn response_format(mut self, response_format: ResponseFormat) -> ImageVariation {
+    self.response_format = Some(response_format);
+    self
+  }
+
+  pub fn user(mut self, user: String) -> ImageVariation {
+    if user.is_empty() {
+      self.user = None;
+    } else {
+      self.user = Some(user);
+    }
+    self
+  }
+
+  /// Create the variations of the image.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the image is not set or if the request fails.
+  ///
+  pub async fn create(&self, client: &Client, model_id: &str, api_version: Option<&str>) -> Result<ImageResponse, Error> {
+    let form: MultipartForm = self.form(client, model_id)?;
+    let response = client.post_multipart(Operation::ImageVariations, &form, Some(model_id), api_version).await?;
+    decode_json::<ImageResponse>(response).await
+  }
+
+  fn form(&self, client: &Client, model_id: &str) -> Result<MultipartForm, Error> {
+    let image: &ImageFile = self.image.as_ref().ok_or(ImageError::MissingImage)?;
+    let mut form: MultipartForm = MultipartForm::new()
+      .file("image", &image.file_name, image.bytes.clone(), Some("image/png"));
+    form = client.add_model_to_form(form, model_id);
+    if let Some(n) = self.n {
+      form = form.text("n", &n.to_string());
+    }
+    if let Some(size) = self.size {
+      form = form.text("size", size.as_str());
+    }
+    if let Some(response_format) = self.response_format {
+      form = form.text("response_format", response_format.as_str());
+    }
+    if let Some(user) = &self.user {
+      form = form.text("user", user);
+    }
+    Ok(form)
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use base64::Engine;
+  use base64::engine::general_purpose::STANDARD;
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, query_param};
+  use crate::openai::ApiType;
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_create_variations_of_an_image() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/openai/deployments/dalle2/images/variations"))
+      .and(query_param("api-version", "2024-10-21"))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_json(serde_json::json!({"created": 1698342300, "data": [{"b64_json": STANDARD.encode(b"variation")}]}))
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let image_response: ImageResponse = ImageVariation::new()
+      .image(ImageFile::new("image.png", b"image-bytes".to_vec()))
+      .response_format(ResponseFormat::B64Json)
+      .create(&client, "dalle2", None).await.unwrap();
+    assert_eq!(image_response.data[0].to_bytes().unwrap(), b"variation".to_vec());
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: String = String::from_utf8_lossy(&requests[0].body).to_string();
+    assert!(body.contains("name=\"image\"; filename=\"image.png\""));
+    assert!(body.contains("name=\"response_format\"\r\n\r\nb64_json"));
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_image_is_missing() {
+    let client: Client = Client::new_openai_client(create_auth_with_given_api_key("12345abcd"));
+    let result = ImageVariation::new().create(&client, "dall-e-2", None).await;
+    assert!(matches!(result, Err(Error::ImageValidationError(ImageError::MissingImage))));
+  }
+}
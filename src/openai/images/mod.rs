@@ -0,0 +1,10 @@
+pub mod error;
+pub mod model;
+
+mod image_edit;
+mod image_generation;
+mod image_variation;
+
+pub use image_edit::ImageEdit;
+pub use image_generation::ImageGeneration;
+pub use image_variation::ImageVariation;
@@ -0,0 +1,76 @@
+use std::path::Path;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::{Serialize, Deserialize};
+
+use crate::openai::error::Error;
+use crate::openai::images::error::ImageError;
+
+/// Generated image, given as a URL or as base64 encoded PNG according to the
+/// response format of the request. DALL·E 3 also returns the revised prompt
+/// used to generate the image.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImageData {
+  pub url: Option<String>,
+  pub b64_json: Option<String>,
+  pub revised_prompt: Option<String>,
+}
+
+impl ImageData {
+  /// Decode the `b64_json` image into bytes.
+  ///
+  /// # Errors
+  ///
+  /// This function will return [`crate::openai::images::error::ImageError::MissingBase64Image`] if
+  /// the image is given as URL, or [`crate::openai::images::error::ImageError::InvalidBase64Image`]
+  /// if it is not valid base64.
+  ///
+  pub fn to_bytes(&self) -> Result<Vec<u8>, ImageError> {
+    let b64_json: &str = self.b64_json.as_deref().ok_or(ImageError::MissingBase64Image)?;
+    STANDARD.decode(b64_json).map_err(|_| ImageError::InvalidBase64Image)
+  }
+
+  /// Decode the `b64_json` image and write it to the given path, e.g. a PNG file.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the image cannot be decoded, see
+  /// [`Self::to_bytes()`], or if the file cannot be written.
+  ///
+  pub async fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+    tokio::fs::write(path, self.to_bytes()?).await?;
+    Ok(())
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const PNG_SIGNATURE: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+  #[tokio::test]
+  async fn it_should_decode_and_save_the_base64_image() {
+    let image_data: ImageData = ImageData {url: None, b64_json: Some(STANDARD.encode(PNG_SIGNATURE)), revised_prompt: None};
+    assert_eq!(image_data.to_bytes().unwrap(), PNG_SIGNATURE.to_vec());
+
+    let path = std::env::temp_dir().join(format!("openai-rust-image-{}.png", std::process::id()));
+    image_data.save(&path).await.unwrap();
+    assert_eq!(tokio::fs::read(&path).await.unwrap(), PNG_SIGNATURE.to_vec());
+    tokio::fs::remove_file(&path).await.unwrap();
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_base64_image_is_missing_or_invalid() {
+    let image_data: ImageData = ImageData {url: Some(String::from("https://example.com/image.png")), b64_json: None, revised_prompt: None};
+    assert_eq!(image_data.to_bytes(), Err(ImageError::MissingBase64Image));
+    let image_data: ImageData = ImageData {url: None, b64_json: Some(String::from("not base64!")), revised_prompt: None};
+    assert_eq!(image_data.to_bytes(), Err(ImageError::InvalidBase64Image));
+    assert!(matches!(image_data.save(std::env::temp_dir().join("never-written.png")).await, Err(Error::ImageValidationError(ImageError::InvalidBase64Image))));
+  }
+}
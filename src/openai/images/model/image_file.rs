@@ -0,0 +1,15 @@
+/// PNG image uploaded to edit it or to create variations of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageFile {
+  pub file_name: String,
+  pub bytes: Vec<u8>,
+}
+
+impl ImageFile {
+  pub fn new(file_name: &str, bytes: Vec<u8>) -> ImageFile {
+    ImageFile {
+      file_name: file_name.to_string(),
+      bytes,
+    }
+  }
+}
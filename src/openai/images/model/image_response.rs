@@ -0,0 +1,9 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::images::model::ImageData;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImageResponse {
+  pub created: u64,
+  pub data: Vec<ImageData>,
+}
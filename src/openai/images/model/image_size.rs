@@ -0,0 +1,29 @@
+use serde::{Serialize, Deserialize};
+
+/// Size of the generated images. DALL·E 2 supports the square sizes up to
+/// 1024x1024, DALL·E 3 supports 1024x1024, 1792x1024 and 1024x1792.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum ImageSize {
+  #[serde(rename = "256x256")]
+  Size256x256,
+  #[serde(rename = "512x512")]
+  Size512x512,
+  #[serde(rename = "1024x1024")]
+  Size1024x1024,
+  #[serde(rename = "1792x1024")]
+  Size1792x1024,
+  #[serde(rename = "1024x1792")]
+  Size1024x1792,
+}
+
+impl ImageSize {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      ImageSize::Size256x256 => "256x256",
+      ImageSize::Size512x512 => "512x512",
+      ImageSize::Size1024x1024 => "1024x1024",
+      ImageSize::Size1792x1024 => "1792x1024",
+      ImageSize::Size1024x1792 => "1024x1792",
+    }
+  }
+}
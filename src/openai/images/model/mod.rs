@@ -0,0 +1,15 @@
+mod image_data;
+mod image_file;
+mod image_quality;
+mod image_response;
+mod image_size;
+mod image_style;
+mod response_format;
+
+pub use image_data::ImageData;
+pub use image_file::ImageFile;
+pub use image_quality::ImageQuality;
+pub use image_response::ImageResponse;
+pub use image_size::ImageSize;
+pub use image_style::ImageStyle;
+pub use response_format::ResponseFormat;
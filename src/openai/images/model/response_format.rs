@@ -0,0 +1,17 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseFormat {
+  Url,
+  B64Json,
+}
+
+impl ResponseFormat {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      ResponseFormat::Url => "url",
+      ResponseFormat::B64Json => "b64_json",
+    }
+  }
+}
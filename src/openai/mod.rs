@@ -1,10 +1,30 @@
+mod api_error;
 mod api_type;
+pub mod audio;
 mod auth;
+pub mod batch;
 pub mod chat;
 mod client;
+mod client_builder;
+pub mod completions;
+pub mod credential;
+pub mod embeddings;
 pub mod error;
+mod event_stream;
+pub mod files;
+pub mod fine_tuning;
+pub mod images;
+pub mod models;
+pub mod moderations;
+mod multipart_form;
+mod operation;
 pub mod requestor;
+mod retry_policy;
 
 pub use api_type::ApiType;
 pub use auth::Auth;
-pub use client::Client;
\ No newline at end of file
+pub use client::Client;
+pub use client_builder::ClientBuilder;
+pub use multipart_form::MultipartForm;
+pub use operation::{AzureApiSurface, Operation};
+pub use retry_policy::RetryPolicy;
\ No newline at end of file
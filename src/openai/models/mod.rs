@@ -0,0 +1,5 @@
+pub mod model;
+
+mod models_api;
+
+pub use models_api::{delete, list, list_deployments, retrieve, retrieve_deployment};
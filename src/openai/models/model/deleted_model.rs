@@ -0,0 +1,8 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeletedModel {
+  pub id: String,
+  pub object: String,
+  pub deleted: bool,
+}
@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+
+/// Deployment of a model in an Azure OpenAI resource.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Deployment {
+  pub id: String,
+  pub object: String,
+  pub model: String,
+  pub status: String,
+  pub owner: Option<String>,
+  pub created_at: Option<u64>,
+  pub updated_at: Option<u64>,
+}
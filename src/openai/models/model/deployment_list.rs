@@ -0,0 +1,9 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::models::model::Deployment;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeploymentList {
+  pub object: String,
+  pub data: Vec<Deployment>,
+}
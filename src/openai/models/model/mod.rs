@@ -0,0 +1,15 @@
+mod deleted_model;
+mod deployment;
+mod deployment_list;
+mod model_capabilities;
+mod model_deprecation;
+mod model_list;
+mod model_object;
+
+pub use deleted_model::DeletedModel;
+pub use deployment::Deployment;
+pub use deployment_list::DeploymentList;
+pub use model_capabilities::ModelCapabilities;
+pub use model_deprecation::ModelDeprecation;
+pub use model_list::ModelList;
+pub use model_object::Model;
@@ -0,0 +1,15 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelCapabilities {
+  #[serde(default)]
+  pub fine_tune: bool,
+  #[serde(default)]
+  pub inference: bool,
+  #[serde(default)]
+  pub completion: bool,
+  #[serde(default)]
+  pub chat_completion: bool,
+  #[serde(default)]
+  pub embeddings: bool,
+}
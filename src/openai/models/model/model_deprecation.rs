@@ -0,0 +1,8 @@
+use serde::{Serialize, Deserialize};
+
+/// Unix timestamps after which the model cannot be fine-tuned or used for inference.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelDeprecation {
+  pub fine_tune: Option<u64>,
+  pub inference: Option<u64>,
+}
@@ -0,0 +1,9 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::models::model::Model;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelList {
+  pub object: String,
+  pub data: Vec<Model>,
+}
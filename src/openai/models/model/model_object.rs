@@ -0,0 +1,19 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::models::model::{ModelCapabilities, ModelDeprecation};
+
+/// Model available to the key. OpenAI sends `created` and `owned_by`, Azure
+/// OpenAI sends `created_at`, the status, the capabilities and the deprecation
+/// dates of the model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Model {
+  pub id: String,
+  pub object: String,
+  pub created: Option<u64>,
+  pub owned_by: Option<String>,
+  pub created_at: Option<u64>,
+  pub status: Option<String>,
+  pub lifecycle_status: Option<String>,
+  pub capabilities: Option<ModelCapabilities>,
+  pub deprecation: Option<ModelDeprecation>,
+}
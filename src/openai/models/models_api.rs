@@ -0,0 +1,176 @@
+use crate::openai::error::Error;
+use crate::openai::requestor::{Requestor, decode_json};
+use crate::openai::{Client, Operation};
+use crate::openai::models::model::{DeletedModel, Deployment, DeploymentList, Model, ModelList};
+
+/// List the models available to the client. With Azure OpenAI, these are the base
+/// and fine-tuned models that can be deployed in the resource.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails.
+///
+pub async fn list(client: &Client, api_version: Option<&str>) -> Result<ModelList, Error> {
+  let response = client.get(Operation::Models, &[], None, api_version).await?;
+  decode_json::<ModelList>(response).await
+}
+
+/// Retrieve the model with the given ID.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails, e.g. when the model
+/// does not exist.
+///
+pub async fn retrieve(client: &Client, model_id: &str, api_version: Option<&str>) -> Result<Model, Error> {
+  let response = client.get(Operation::Model(model_id), &[], None, api_version).await?;
+  decode_json::<Model>(response).await
+}
+
+/// Delete the fine-tuned model with the given ID. Only the fine-tuned models
+/// owned by the organization can be deleted.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails.
+///
+pub async fn delete(client: &Client, model_id: &str, api_version: Option<&str>) -> Result<DeletedModel, Error> {
+  let response = client.delete(Operation::Model(model_id), None, api_version).await?;
+  decode_json::<DeletedModel>(response).await
+}
+
+/// List the deployments of the Azure OpenAI resource. The deployments are only
+/// available on the data plane up to the API version `2023-05-15`.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails. OpenAI does not have
+/// deployments, so it returns a not found error.
+///
+pub async fn list_deployments(client: &Client, api_version: Option<&str>) -> Result<DeploymentList, Error> {
+  let response = client.get(Operation::Deployments, &[], None, api_version).await?;
+  decode_json::<DeploymentList>(response).await
+}
+
+/// Retrieve the deployment of the Azure OpenAI resource with the given ID.
+///
+/// # Errors
+///
+/// This function will return an error if the request fails.
+///
+pub async fn retrieve_deployment(client: &Client, deployment_id: &str, api_version: Option<&str>) -> Result<Deployment, Error> {
+  let response = client.get(Operation::Deployment(deployment_id), &[], None, api_version).await?;
+  decode_json::<Deployment>(response).await
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, query_param};
+  use crate::openai::ApiType;
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_list_openai_models() {
+    let body_response: &str = r#"{"object": "list", "data": [{"id": "gpt-4o", "object": "model", "created": 1715367049, "owned_by": "system"}, {"id": "ft:gpt-4o-mini:org::id", "object": "model", "created": 1721172717, "owned_by": "org"}]}"#;
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/models"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(body_response))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let model_list: ModelList = list(&client, None).await.unwrap();
+    assert_eq!(model_list.data.len(), 2);
+    assert_eq!(model_list.data[0].id, "gpt-4o");
+    assert_eq!(model_list.data[1].owned_by, Some(String::from("org")));
+  }
+
+  #[tokio::test]
+  async fn it_should_list_azure_models() {
+    let body_response: &str = r#"{"data": [{"status": "succeeded", "capabilities": {"fine_tune": true, "inference": true, "completion": false, "chat_completion": true, "embeddings": false}, "lifecycle_status": "generally-available", "deprecation": {"fine_tune": 1753833600, "inference": 1753833600}, "id": "gpt-35-turbo-0125", "created_at": 1706745600, "object": "model"}], "object": "list"}"#;
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/openai/models"))
+      .and(query_param("api-version", "2024-10-21"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(body_response))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let model_list: ModelList = list(&client, None).await.unwrap();
+    let model: &Model = &model_list.data[0];
+    assert_eq!(model.created_at, Some(1706745600));
+    assert!(model.capabilities.as_ref().unwrap().chat_completion);
+    assert_eq!(model.deprecation.as_ref().unwrap().inference, Some(1753833600));
+  }
+
+  #[tokio::test]
+  async fn it_should_retrieve_and_delete_a_model() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/models/ft:gpt-4o-mini:org::id"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"id": "ft:gpt-4o-mini:org::id", "object": "model", "created": 1721172717, "owned_by": "org"}"#))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("DELETE"))
+      .and(path("/models/ft:gpt-4o-mini:org::id"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"id": "ft:gpt-4o-mini:org::id", "object": "model", "deleted": true}"#))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let model: Model = retrieve(&client, "ft:gpt-4o-mini:org::id", None).await.unwrap();
+    assert_eq!(model.created, Some(1721172717));
+    let deleted_model: DeletedModel = delete(&client, "ft:gpt-4o-mini:org::id", None).await.unwrap();
+    assert!(deleted_model.deleted);
+  }
+
+  #[tokio::test]
+  async fn it_should_list_and_retrieve_azure_deployments() {
+    let deployment: &str = r#"{"scale_settings": {"scale_type": "standard"}, "model": "gpt-35-turbo", "owner": "organization-owner", "id": "gpt35", "status": "succeeded", "created_at": 1677879900, "updated_at": 1677879900, "object": "deployment"}"#;
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/openai/deployments"))
+      .and(query_param("api-version", "2023-05-15"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(format!(r#"{{"data": [{}], "object": "list"}}"#, deployment)))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("GET"))
+      .and(path("/openai/deployments/gpt35"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(deployment))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let deployment_list: DeploymentList = list_deployments(&client, None).await.unwrap();
+    assert_eq!(deployment_list.data[0].model, "gpt-35-turbo");
+    let deployment: Deployment = retrieve_deployment(&client, "gpt35", None).await.unwrap();
+    assert_eq!(deployment.status, "succeeded");
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_api_error_when_the_model_does_not_exist() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .respond_with(ResponseTemplate::new(404).set_body_string(r#"{"error": {"message": "The model 'unknown' does not exist", "type": "invalid_request_error", "param": "model", "code": "model_not_found"}}"#))
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let error: Error = retrieve(&client, "unknown", None).await.unwrap_err();
+    assert_eq!(error.api_error().unwrap().code, Some(String::from("model_not_found")));
+  }
+}
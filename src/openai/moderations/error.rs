@@ -0,0 +1,42 @@
+use std::error::Error;
+use std::fmt::{Display, Debug};
+
+use crate::openai::ApiType;
+
+#[derive(Clone, PartialEq)]
+pub enum ModerationError {
+  EmptyInput,
+  /// Moderations are only available with OpenAI, Azure OpenAI has no moderations
+  /// endpoint.
+  UnsupportedApiType(ApiType),
+}
+
+impl ModerationError {
+  fn label(&self) -> &'static str {
+    match self {
+      ModerationError::EmptyInput => "EmptyInput",
+      ModerationError::UnsupportedApiType(_) => "UnsupportedApiType",
+    }
+  }
+
+  fn error_message(&self) -> String {
+    match self {
+      ModerationError::EmptyInput => String::from("Input cannot be empty."),
+      ModerationError::UnsupportedApiType(api_type) => format!("Moderations are not available with the {:?} API type, use an OpenAI client.", api_type),
+    }
+  }
+}
+
+impl Debug for ModerationError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "[{}] {}", self.label(), self.error_message())
+  }
+}
+
+impl Display for ModerationError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.error_message())
+  }
+}
+
+impl Error for ModerationError {}
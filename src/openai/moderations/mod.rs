@@ -0,0 +1,9 @@
+pub mod error;
+pub mod model;
+
+mod moderation_request;
+
+pub use moderation_request::Moderation;
+
+#[cfg(test)]
+pub(crate) mod test_helpers;
@@ -0,0 +1,13 @@
+mod moderation_categories;
+mod moderation_category_scores;
+mod moderation_input;
+mod moderation_input_part;
+mod moderation_response;
+mod moderation_result;
+
+pub use moderation_categories::ModerationCategories;
+pub use moderation_category_scores::ModerationCategoryScores;
+pub use moderation_input::ModerationInput;
+pub use moderation_input_part::{ImageUrl, ModerationInputPart};
+pub use moderation_response::ModerationResponse;
+pub use moderation_result::ModerationResult;
@@ -0,0 +1,51 @@
+use serde::{Serialize, Deserialize};
+
+/// Categories of content and whether the input is flagged for each of them. The
+/// illicit categories are only reported by the omni models.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModerationCategories {
+  pub harassment: bool,
+  #[serde(rename = "harassment/threatening")]
+  pub harassment_threatening: bool,
+  pub hate: bool,
+  #[serde(rename = "hate/threatening")]
+  pub hate_threatening: bool,
+  pub illicit: Option<bool>,
+  #[serde(rename = "illicit/violent")]
+  pub illicit_violent: Option<bool>,
+  #[serde(rename = "self-harm")]
+  pub self_harm: bool,
+  #[serde(rename = "self-harm/intent")]
+  pub self_harm_intent: bool,
+  #[serde(rename = "self-harm/instructions")]
+  pub self_harm_instructions: bool,
+  pub sexual: bool,
+  #[serde(rename = "sexual/minors")]
+  pub sexual_minors: bool,
+  pub violence: bool,
+  #[serde(rename = "violence/graphic")]
+  pub violence_graphic: bool,
+}
+
+impl ModerationCategories {
+  /// Return the names of the flagged categories as named by the API, e.g.
+  /// `hate/threatening`.
+  pub fn flagged(&self) -> Vec<&'static str> {
+    let categories: [(&'static str, bool); 13] = [
+      ("harassment", self.harassment),
+      ("harassment/threatening", self.harassment_threatening),
+      ("hate", self.hate),
+      ("hate/threatening", self.hate_threatening),
+      ("illicit", self.illicit.unwrap_or(false)),
+      ("illicit/violent", self.illicit_violent.unwrap_or(false)),
+      ("self-harm", self.self_harm),
+      ("self-harm/intent", self.self_harm_intent),
+      ("self-harm/instructions", self.self_harm_instructions),
+      ("sexual", self.sexual),
+      ("sexual/minors", self.sexual_minors),
+      ("violence", self.violence),
+      ("violence/graphic", self.violence_graphic),
+    ];
+    categories.into_iter().filter(|(_, flagged)| *flagged).map(|(name, _)| name).collect()
+  }
+}
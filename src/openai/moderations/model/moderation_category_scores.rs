@@ -0,0 +1,28 @@
+use serde::{Serialize, Deserialize};
+
+/// Confidence of the model, between 0 and 1, that the input belongs to each
+/// category.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModerationCategoryScores {
+  pub harassment: f64,
+  #[serde(rename = "harassment/threatening")]
+  pub harassment_threatening: f64,
+  pub hate: f64,
+  #[serde(rename = "hate/threatening")]
+  pub hate_threatening: f64,
+  pub illicit: Option<f64>,
+  #[serde(rename = "illicit/violent")]
+  pub illicit_violent: Option<f64>,
+  #[serde(rename = "self-harm")]
+  pub self_harm: f64,
+  #[serde(rename = "self-harm/intent")]
+  pub self_harm_intent: f64,
+  #[serde(rename = "self-harm/instructions")]
+  pub self_harm_instructions: f64,
+  pub sexual: f64,
+  #[serde(rename = "sexual/minors")]
+  pub sexual_minors: f64,
+  pub violence: f64,
+  #[serde(rename = "violence/graphic")]
+  pub violence_graphic: f64,
+}
@@ -0,0 +1,24 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::moderations::model::ModerationInputPart;
+
+/// Input to classify: a single text, an array of texts, or an array of text and
+/// image parts classified together. Images are only supported by the omni models.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ModerationInput {
+  String(String),
+  StringArray(Vec<String>),
+  Multimodal(Vec<ModerationInputPart>),
+}
+
+impl ModerationInput {
+  /// Return true if there is nothing to classify or if one of the texts is empty.
+  pub fn is_empty(&self) -> bool {
+    match self {
+      ModerationInput::String(input) => input.is_empty(),
+      ModerationInput::StringArray(inputs) => inputs.is_empty() || inputs.iter().any(String::is_empty),
+      ModerationInput::Multimodal(parts) => parts.is_empty(),
+    }
+  }
+}
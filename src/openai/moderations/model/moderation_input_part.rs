@@ -0,0 +1,24 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ModerationInputPart {
+  Text {text: String},
+  ImageUrl {image_url: ImageUrl},
+}
+
+/// URL of an image, either a web URL or a base64 encoded data URL.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ImageUrl {
+  pub url: String,
+}
+
+impl ModerationInputPart {
+  pub fn text(text: &str) -> ModerationInputPart {
+    ModerationInputPart::Text {text: text.to_string()}
+  }
+
+  pub fn image_url(url: &str) -> ModerationInputPart {
+    ModerationInputPart::ImageUrl {image_url: ImageUrl {url: url.to_string()}}
+  }
+}
@@ -0,0 +1,19 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::moderations::model::ModerationResult;
+
+/// Classification of the input, with one result per text of the input or a single
+/// result for a multimodal input.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModerationResponse {
+  pub id: String,
+  pub model: String,
+  pub results: Vec<ModerationResult>,
+}
+
+impl ModerationResponse {
+  /// Return true if one of the results is flagged.
+  pub fn is_flagged(&self) -> bool {
+    self.results.iter().any(|result| result.flagged)
+  }
+}
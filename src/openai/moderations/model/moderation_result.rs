@@ -0,0 +1,15 @@
+use std::collections::HashMap;
+
+use serde::{Serialize, Deserialize};
+
+use crate::openai::moderations::model::{ModerationCategories, ModerationCategoryScores};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModerationResult {
+  pub flagged: bool,
+  pub categories: ModerationCategories,
+  pub category_scores: ModerationCategoryScores,
+  /// Input types, `text` or `image`, that each category was flagged for. Only
+  /// reported by the omni models.
+  pub category_applied_input_types: Option<HashMap<String, Vec<String>>>,
+}
@@ -0,0 +1,163 @@
+use serde::{Serialize, Deserialize};
+
+use crate::openai::error::Error;
+use crate::openai::requestor::{Requestor, decode_json};
+use crate::openai::{ApiType, Client, Operation};
+use crate::openai::moderations::error::ModerationError;
+use crate::openai::moderations::model::{ModerationInput, ModerationInputPart, ModerationResponse};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Moderation {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  model: Option<String>,
+  input: ModerationInput,
+}
+
+impl Default for Moderation {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Moderation {
+  pub fn new() -> Moderation {
+    Moderation {
+      model: None,
+      input: ModerationInput::StringArray(Vec::new()),
+    }
+  }
+
+  pub fn input(mut self, input: String) -> Moderation {
+    self.input = ModerationInput::String(input);
+    self
+  }
+
+  pub fn inputs(mut self, inputs: Vec<String>) -> Moderation {
+    self.input = ModerationInput::StringArray(inputs);
+    self
+  }
+
+  /// Set text and image parts classified together in a single result.
+  pub fn parts(mut self, parts: Vec<ModerationInputPart>) -> Moderation {
+    self.input = ModerationInput::Multimodal(parts);
+    self
+  }
+
+  /// Classify the input with the given moderation model, e.g.
+  /// `omni-moderation-latest`. The model is always sent in the body as moderation
+  /// is not bound to a deployment.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if the client is not an OpenAI client, if
+  /// the input or one of the inputs is empty, or if the request fails.
+  ///
+  pub async fn create(&self, client: &Client, model_id: &str, api_version: Option<&str>) -> Result<ModerationResponse, Error> {
+    Moderation::check_api_type(client)?;
+    if self.input.is_empty() {
+      return Err(ModerationError::EmptyInput.into());
+    }
+    let mut request_body: serde_json::Value = serde_json::to_value(self)?;
+    request_body["model"] = serde_json::Value::from(model_id);
+    let response = client.post(Operation::Moderations, &serde_json::to_string(&request_body)?, None, api_version).await?;
+    decode_json::<ModerationResponse>(response).await
+  }
+
+  /// Return an error if the client is an Azure OpenAI client, which has no
+  /// moderations endpoint.
+  pub(crate) fn check_api_type(client: &Client) -> Result<(), ModerationError> {
+    match client.get_api_type() {
+      ApiType::OpenAI => Ok(()),
+      api_type => Err(ModerationError::UnsupportedApiType(api_type)),
+    }
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use wiremock::{MockServer, Mock, ResponseTemplate};
+  use wiremock::matchers::{method, path, body_json};
+  use crate::openai::auth::create_auth_with_given_api_key;
+  use crate::openai::moderations::model::ModerationResult;
+  use crate::openai::moderations::test_helpers::moderation_response_body;
+  use super::*;
+
+  #[tokio::test]
+  async fn it_should_classify_texts() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/moderations"))
+      .and(body_json(serde_json::json!({"model": "omni-moderation-latest", "input": ["Hello", "I will hurt you"]})))
+      .respond_with(ResponseTemplate::new(200).set_body_string(moderation_response_body(&[false, true])))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let moderation_response: ModerationResponse = Moderation::new()
+      .inputs(vec![String::from("Hello"), String::from("I will hurt you")])
+      .create(&client, "omni-moderation-latest", None).await.unwrap();
+    assert!(moderation_response.is_flagged());
+    assert!(moderation_response.results[0].categories.flagged().is_empty());
+    let result: &ModerationResult = &moderation_response.results[1];
+    assert_eq!(result.categories.flagged(), vec!["violence", "violence/graphic"]);
+    assert_eq!(result.category_scores.violence, 0.93);
+    assert_eq!(result.category_applied_input_types.as_ref().unwrap()["violence"], vec![String::from("text")]);
+  }
+
+  #[tokio::test]
+  async fn it_should_classify_text_and_image_parts() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/moderations"))
+      .and(body_json(serde_json::json!({"model": "omni-moderation-latest", "input": [{"type": "text", "text": "Look"}, {"type": "image_url", "image_url": {"url": "https://example.com/image.png"}}]})))
+      .respond_with(ResponseTemplate::new(200).set_body_string(moderation_response_body(&[false])))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let moderation_response: ModerationResponse = Moderation::new()
+      .parts(vec![ModerationInputPart::text("Look"), ModerationInputPart::image_url("https://example.com/image.png")])
+      .create(&client, "omni-moderation-latest", None).await.unwrap();
+    assert!(!moderation_response.is_flagged());
+  }
+
+  #[tokio::test]
+  async fn it_should_classify_a_text_with_the_legacy_model() {
+    let body_response: &str = r#"{"id": "modr-456", "model": "text-moderation-007", "results": [{"flagged": false, "categories": {"harassment": false, "harassment/threatening": false, "hate": false, "hate/threatening": false, "self-harm": false, "self-harm/intent": false, "self-harm/instructions": false, "sexual": false, "sexual/minors": false, "violence": false, "violence/graphic": false}, "category_scores": {"harassment": 0.0, "harassment/threatening": 0.0, "hate": 0.0, "hate/threatening": 0.0, "self-harm": 0.0, "self-harm/intent": 0.0, "self-harm/instructions": 0.0, "sexual": 0.0, "sexual/minors": 0.0, "violence": 0.0, "violence/graphic": 0.0}}]}"#;
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/moderations"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(body_response))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let moderation_response: ModerationResponse = Moderation::new()
+      .input(String::from("Hello"))
+      .create(&client, "text-moderation-latest", None).await.unwrap();
+    assert_eq!(moderation_response.results[0].categories.illicit, None);
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_when_the_input_is_empty() {
+    let client: Client = Client::new_openai_client(create_auth_with_given_api_key("12345abcd"));
+    let result = Moderation::new().create(&client, "omni-moderation-latest", None).await;
+    assert!(matches!(result, Err(Error::ModerationValidationError(ModerationError::EmptyInput))));
+  }
+
+  #[tokio::test]
+  async fn it_should_return_an_error_with_an_azure_client() {
+    for api_type in [ApiType::Azure, ApiType::AzureAD] {
+      let client: Client = Client::new(create_auth_with_given_api_key("12345abcd"), "https://my-resource-name.openai.azure.com/", api_type.clone());
+      let result = Moderation::new().input(String::from("Hello")).create(&client, "omni-moderation-latest", None).await;
+      assert!(matches!(result, Err(Error::ModerationValidationError(ModerationError::UnsupportedApiType(unsupported))) if unsupported == api_type));
+    }
+  }
+}
@@ -0,0 +1,7 @@
+/// Return a moderation response with one result per given flag.
+pub fn moderation_response_body(flags: &[bool]) -> String {
+  let results: Vec<String> = flags.iter().map(|flagged| format!(
+    r#"{{"flagged": {flagged}, "categories": {{"harassment": false, "harassment/threatening": false, "hate": false, "hate/threatening": false, "illicit": false, "illicit/violent": false, "self-harm": false, "self-harm/intent": false, "self-harm/instructions": false, "sexual": false, "sexual/minors": false, "violence": {flagged}, "violence/graphic": {flagged}}}, "category_scores": {{"harassment": 0.01, "harassment/threatening": 0.01, "hate": 0.001, "hate/threatening": 0.0001, "illicit": 0.002, "illicit/violent": 0.0003, "self-harm": 0.0001, "self-harm/intent": 0.0001, "self-harm/instructions": 0.0001, "sexual": 0.0002, "sexual/minors": 0.00001, "violence": 0.93, "violence/graphic": 0.61}}, "category_applied_input_types": {{"violence": ["text"]}}}}"#
+  )).collect();
+  format!(r#"{{"id": "modr-123", "model": "omni-moderation-2024-09-26", "results": [{}]}}"#, results.join(", "))
+}
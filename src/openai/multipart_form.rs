@@ -0,0 +1,66 @@
+use bytes::Bytes;
+use reqwest::multipart::{Form, Part};
+
+use crate::openai::error::Error;
+
+/// Body of a `multipart/form-data` request such as a file, audio or image upload.
+/// Unlike `reqwest::multipart::Form`, it can be cloned so the request can be
+/// sent again when it is retried. The files are shared, not copied, by the clones.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MultipartForm {
+  parts: Vec<(String, MultipartPart)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum MultipartPart {
+  Text(String),
+  File {file_name: String, bytes: Bytes, mime_type: Option<String>},
+}
+
+impl MultipartForm {
+  pub fn new() -> MultipartForm {
+    MultipartForm {
+      parts: Vec::new(),
+    }
+  }
+
+  /// Add a text field to the form.
+  pub fn text(mut self, name: &str, value: &str) -> MultipartForm {
+    self.parts.push((name.to_string(), MultipartPart::Text(value.to_string())));
+    self
+  }
+
+  /// Add a file to the form with the given file name and content. The MIME type
+  /// is optional, the API usually infers the format from the file name.
+  pub fn file(mut self, name: &str, file_name: &str, bytes: impl Into<Bytes>, mime_type: Option<&str>) -> MultipartForm {
+    self.parts.push((name.to_string(), MultipartPart::File {
+      file_name: file_name.to_string(),
+      bytes: bytes.into(),
+      mime_type: mime_type.map(str::to_string),
+    }));
+    self
+  }
+
+  /// Create the form sent by `reqwest`.
+  ///
+  /// # Errors
+  ///
+  /// This function will return an error if a MIME type is invalid.
+  ///
+  pub(crate) fn to_form(&self) -> Result<Form, Error> {
+    let mut form: Form = Form::new();
+    for (name, part) in &self.parts {
+      form = match part {
+        MultipartPart::Text(value) => form.text(name.clone(), value.clone()),
+        MultipartPart::File {file_name, bytes, mime_type} => {
+          let mut file_part: Part = Part::stream(bytes.clone()).file_name(file_name.clone());
+          if let Some(mime_type) = mime_type {
+            file_part = file_part.mime_str(mime_type)?;
+          }
+          form.part(name.clone(), file_part)
+        },
+      };
+    }
+    Ok(form)
+  }
+}
@@ -0,0 +1,162 @@
+/// Default api-version of the operations available since the first GA versions
+/// of Azure OpenAI: chat completions, completions, embeddings and deployments.
+const AZURE_API_VERSION_2023_05_15: &str = "2023-05-15";
+/// Default api-version of the other operations, e.g. files, fine-tuning, batches,
+/// audio and DALL·E 3, which are not served by older versions.
+const AZURE_API_VERSION_2024_10_21: &str = "2024-10-21";
+
+/// Operation of the API. Each operation is routed to its path according to the
+/// API type of the client:
+/// - OpenAI: `{endpoint}{path}`, e.g. `https://api.openai.com/v1/chat/completions`.
+/// - Azure OpenAI, deployment-scoped operations (inference): `{endpoint}openai/deployments/{model_id}/{path}?api-version={api_version}`.
+/// - Azure OpenAI, resource-scoped operations (models, deployments, files, fine-tuning, batches):
+///   `{endpoint}openai/{path}?api-version={api_version}`.
+/// - Azure OpenAI v1 API ([`AzureApiSurface::V1`]): `{endpoint}openai/v1/{path}` for all the operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation<'a> {
+  ChatCompletions,
+  Completions,
+  Embeddings,
+  AudioSpeech,
+  AudioTranscriptions,
+  AudioTranslations,
+  ImageGenerations,
+  ImageEdits,
+  ImageVariations,
+  Moderations,
+  Models,
+  Model(&'a str),
+  /// Deployments of an Azure OpenAI resource, only available with Azure OpenAI.
+  Deployments,
+  Deployment(&'a str),
+  Files,
+  File(&'a str),
+  FileContent(&'a str),
+  FineTuningJobs,
+  FineTuningJob(&'a str),
+  FineTuningJobCancel(&'a str),
+  FineTuningJobEvents(&'a str),
+  FineTuningJobCheckpoints(&'a str),
+  Batches,
+  Batch(&'a str),
+  BatchCancel(&'a str),
+}
+
+/// API surface of Azure OpenAI used by the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AzureApiSurface {
+  /// Versioned API: inference operations are scoped to a deployment and every
+  /// request has an `api-version` query parameter.
+  #[default]
+  Deployments,
+  /// v1 API at `/openai/v1/`: the model (deployment name) is sent in the body as
+  /// with OpenAI, and the `api-version` is only sent when it is given.
+  V1,
+}
+
+impl Operation<'_> {
+  /// Get the path of the operation relative to the API root.
+  pub fn path(&self) -> String {
+    match self {
+      Operation::ChatCompletions => String::from("chat/completions"),
+      Operation::Completions => String::from("completions"),
+      Operation::Embeddings => String::from("embeddings"),
+      Operation::AudioSpeech => String::from("audio/speech"),
+      Operation::AudioTranscriptions => String::from("audio/transcriptions"),
+      Operation::AudioTranslations => String::from("audio/translations"),
+      Operation::ImageGenerations => String::from("images/generations"),
+      Operation::ImageEdits => String::from("images/edits"),
+      Operation::ImageVariations => String::from("images/variations"),
+      Operation::Moderations => String::from("moderations"),
+      Operation::Models => String::from("models"),
+      Operation::Model(model_id) => format!("models/{}", model_id),
+      Operation::Deployments => String::from("deployments"),
+      Operation::Deployment(deployment_id) => format!("deployments/{}", deployment_id),
+      Operation::Files => String::from("files"),
+      Operation::File(file_id) => format!("files/{}", file_id),
+      Operation::FileContent(file_id) => format!("files/{}/content", file_id),
+      Operation::FineTuningJobs => String::from("fine_tuning/jobs"),
+      Operation::FineTuningJob(job_id) => format!("fine_tuning/jobs/{}", job_id),
+      Operation::FineTuningJobCancel(job_id) => format!("fine_tuning/jobs/{}/cancel", job_id),
+      Operation::FineTuningJobEvents(job_id) => format!("fine_tuning/jobs/{}/events", job_id),
+      Operation::FineTuningJobCheckpoints(job_id) => format!("fine_tuning/jobs/{}/checkpoints", job_id),
+      Operation::Batches => String::from("batches"),
+      Operation::Batch(batch_id) => format!("batches/{}", batch_id),
+      Operation::BatchCancel(batch_id) => format!("batches/{}/cancel", batch_id),
+    }
+  }
+
+  /// Get the api-version used for the operation with the versioned Azure OpenAI
+  /// API when it is not given.
+  pub fn default_azure_api_version(&self) -> &'static str {
+    match self {
+      Operation::ChatCompletions
+      | Operation::Completions
+      | Operation::Embeddings
+      | Operation::Deployments
+      | Operation::Deployment(_) => AZURE_API_VERSION_2023_05_15,
+      _ => AZURE_API_VERSION_2024_10_21,
+    }
+  }
+
+  /// Return true if the operation is scoped to a deployment with the versioned
+  /// Azure OpenAI API, i.e. the inference operations.
+  pub fn is_deployment_scoped(&self) -> bool {
+    matches!(self,
+      Operation::ChatCompletions
+      | Operation::Completions
+      | Operation::Embeddings
+      | Operation::AudioSpeech
+      | Operation::AudioTranscriptions
+      | Operation::AudioTranslations
+      | Operation::ImageGenerations
+      | Operation::ImageEdits
+      | Operation::ImageVariations
+    )
+  }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_return_the_path_of_every_operation() {
+    let operations: Vec<(Operation, &str, bool, &str)> = vec![
+      (Operation::ChatCompletions, "chat/completions", true, "2023-05-15"),
+      (Operation::Completions, "completions", true, "2023-05-15"),
+      (Operation::Embeddings, "embeddings", true, "2023-05-15"),
+      (Operation::AudioSpeech, "audio/speech", true, "2024-10-21"),
+      (Operation::AudioTranscriptions, "audio/transcriptions", true, "2024-10-21"),
+      (Operation::AudioTranslations, "audio/translations", true, "2024-10-21"),
+      (Operation::ImageGenerations, "images/generations", true, "2024-10-21"),
+      (Operation::ImageEdits, "images/edits", true, "2024-10-21"),
+      (Operation::ImageVariations, "images/variations", true, "2024-10-21"),
+      (Operation::Moderations, "moderations", false, "2024-10-21"),
+      (Operation::Models, "models", false, "2024-10-21"),
+      (Operation::Model("gpt-4"), "models/gpt-4", false, "2024-10-21"),
+      (Operation::Deployments, "deployments", false, "2023-05-15"),
+      (Operation::Deployment("gpt-4"), "deployments/gpt-4", false, "2023-05-15"),
+      (Operation::Files, "files", false, "2024-10-21"),
+      (Operation::File("file-1"), "files/file-1", false, "2024-10-21"),
+      (Operation::FileContent("file-1"), "files/file-1/content", false, "2024-10-21"),
+      (Operation::FineTuningJobs, "fine_tuning/jobs", false, "2024-10-21"),
+      (Operation::FineTuningJob("ftjob-1"), "fine_tuning/jobs/ftjob-1", false, "2024-10-21"),
+      (Operation::FineTuningJobCancel("ftjob-1"), "fine_tuning/jobs/ftjob-1/cancel", false, "2024-10-21"),
+      (Operation::FineTuningJobEvents("ftjob-1"), "fine_tuning/jobs/ftjob-1/events", false, "2024-10-21"),
+      (Operation::FineTuningJobCheckpoints("ftjob-1"), "fine_tuning/jobs/ftjob-1/checkpoints", false, "2024-10-21"),
+      (Operation::Batches, "batches", false, "2024-10-21"),
+      (Operation::Batch("batch-1"), "batches/batch-1", false, "2024-10-21"),
+      (Operation::BatchCancel("batch-1"), "batches/batch-1/cancel", false, "2024-10-21"),
+    ];
+    for (operation, path, is_deployment_scoped, default_azure_api_version) in operations {
+      assert_eq!(operation.path(), path);
+      assert_eq!(operation.is_deployment_scoped(), is_deployment_scoped, "{:?}", operation);
+      assert_eq!(operation.default_azure_api_version(), default_azure_api_version, "{:?}", operation);
+    }
+  }
+}
@@ -1,39 +1,150 @@
+use std::time::Duration;
+
 use async_trait::async_trait;
-use reqwest::RequestBuilder;
-use crate::openai::{Client, ApiType};
-use crate::openai::error::Error;
+use futures::StreamExt;
+use reqwest::{Method, RequestBuilder};
+use serde::de::DeserializeOwned;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use crate::openai::{Client, ApiType, MultipartForm, Operation};
+use crate::openai::error::{ApiError, Error};
+use crate::openai::retry_policy::retry_after;
+
 
+/// Body of a request sent by the requestor.
+enum RequestBody<'a> {
+  Empty,
+  Json(&'a str),
+  Multipart(&'a MultipartForm),
+}
 
 #[async_trait]
 pub trait Requestor {
-  async fn post(&self, api_path: &str, body: &str, model_id: Option<&str>, api_version: Option<&str>) -> Result<reqwest::Response, Box<dyn std::error::Error>>;
+  /// Send a POST request with the given JSON body.
+  async fn post(&self, operation: Operation<'_>, body: &str, model_id: Option<&str>, api_version: Option<&str>) -> Result<reqwest::Response, Error>;
+  /// Send a POST request with the given `multipart/form-data` body.
+  async fn post_multipart(&self, operation: Operation<'_>, form: &MultipartForm, model_id: Option<&str>, api_version: Option<&str>) -> Result<reqwest::Response, Error>;
+  /// Send a GET request with the given query parameters, e.g. `after` and `limit`
+  /// to paginate the lists.
+  async fn get(&self, operation: Operation<'_>, query: &[(&str, String)], model_id: Option<&str>, api_version: Option<&str>) -> Result<reqwest::Response, Error>;
+  async fn delete(&self, operation: Operation<'_>, model_id: Option<&str>, api_version: Option<&str>) -> Result<reqwest::Response, Error>;
 }
 
 #[async_trait]
 impl Requestor for Client {
-  async fn post(&self, api_path: &str, body: &str, model_id: Option<&str>, api_version: Option<&str>) -> Result<reqwest::Response, Box<dyn std::error::Error>> {
-    let api_uri = self.generate_api_uri(api_path, model_id, api_version)?;
+  async fn post(&self, operation: Operation<'_>, body: &str, model_id: Option<&str>, api_version: Option<&str>) -> Result<reqwest::Response, Error> {
+    self.send(Method::POST, operation, &[], RequestBody::Json(body), model_id, api_version).await
+  }
 
-    let mut request_builder: RequestBuilder = self.http_client.post(api_uri)
-      .header(reqwest::header::CONTENT_TYPE, "application/json");
-    
-    // API Key is required for Azure and OpenAI. For Azure AD, managed identity is used.
-    if self.api_type == ApiType::Azure {
-      request_builder = request_builder.header("api-key", self.get_api_key());
-    } else if self.api_type == ApiType::OpenAI {
-      request_builder = request_builder.bearer_auth(self.get_api_key());
-    }
-    
-    let response = request_builder
-      .body(body.to_string())
-      .send()
-      .await?;
-    if !response.status().is_success() {
-      Err(Box::new(Error::ApiError {status: response.status().as_u16(), message: response.text().await?}))
-    } else {
-      Ok(response)
+  async fn post_multipart(&self, operation: Operation<'_>, form: &MultipartForm, model_id: Option<&str>, api_version: Option<&str>) -> Result<reqwest::Response, Error> {
+    self.send(Method::POST, operation, &[], RequestBody::Multipart(form), model_id, api_version).await
+  }
+
+  async fn get(&self, operation: Operation<'_>, query: &[(&str, String)], model_id: Option<&str>, api_version: Option<&str>) -> Result<reqwest::Response, Error> {
+    self.send(Method::GET, operation, query, RequestBody::Empty, model_id, api_version).await
+  }
+
+  async fn delete(&self, operation: Operation<'_>, model_id: Option<&str>, api_version: Option<&str>) -> Result<reqwest::Response, Error> {
+    self.send(Method::DELETE, operation, &[], RequestBody::Empty, model_id, api_version).await
+  }
+}
+
+/// Decode the JSON body of the response into the given type.
+///
+/// # Errors
+///
+/// This function will return an error if the body cannot be read or if it cannot be
+/// deserialized into the given type, in this case the type of the error is
+/// [`crate::openai::error::Error::SerializationError`].
+///
+pub async fn decode_json<T: DeserializeOwned>(response: reqwest::Response) -> Result<T, Error> {
+  let body = response.bytes().await?;
+  Ok(serde_json::from_slice::<T>(&body)?)
+}
+
+/// Write the body of the response to the given writer as it is received, without
+/// holding the whole body in memory. Return the number of bytes written.
+///
+/// # Errors
+///
+/// This function will return an error if the body cannot be read or written.
+///
+pub async fn write_body<W: AsyncWrite + Unpin>(response: reqwest::Response, writer: &mut W) -> Result<u64, Error> {
+  let mut bytes_stream = response.bytes_stream();
+  let mut written: u64 = 0;
+  while let Some(chunk) = bytes_stream.next().await {
+    let chunk = chunk?;
+    writer.write_all(&chunk).await?;
+    written += chunk.len() as u64;
+  }
+  writer.flush().await?;
+  Ok(written)
+}
+
+impl Client {
+  /// Send the request with the given method, query parameters and body, retrying it
+  /// according to the retry policy. The body and the credential header are set on
+  /// every attempt as the credential may have been refreshed in the meantime.
+  async fn send(&self, method: Method, operation: Operation<'_>, query: &[(&str, String)], body: RequestBody<'_>, model_id: Option<&str>, api_version: Option<&str>) -> Result<reqwest::Response, Error> {
+    let api_uri = self.generate_api_uri(&operation, model_id, api_version)?;
+    let max_attempts: u32 = self.retry_policy.get_max_attempts();
+    let mut attempts: u32 = 0;
+
+    loop {
+      attempts += 1;
+      let mut request_builder: RequestBuilder = self.http_client.request(method.clone(), &api_uri)
+        .headers(self.default_headers.clone());
+      if !query.is_empty() {
+        request_builder = request_builder.query(query);
+      }
+      request_builder = match body {
+        RequestBody::Empty => request_builder,
+        RequestBody::Json(body) => request_builder
+          .header(reqwest::header::CONTENT_TYPE, "application/json")
+          .body(body.to_string()),
+        // The content type with the boundary is set by reqwest
+        RequestBody::Multipart(form) => request_builder.multipart(form.to_form()?),
+      };
+      if let Some(timeout) = self.timeout {
+        request_builder = request_builder.timeout(timeout);
+      }
+      request_builder = self.authenticate(request_builder).await?;
+
+      let retry_delay = match request_builder.send().await {
+        Ok(response) if response.status().is_success() => return Ok(response),
+        Ok(response) => {
+          let status: u16 = response.status().as_u16();
+          let retry_after: Option<Duration> = retry_after(response.headers());
+          let api_error: ApiError = ApiError::from_response(status, &response.text().await?, attempts);
+          // An exceeded quota is returned with HTTP 429 but it will not succeed when retried
+          if attempts >= max_attempts || !self.retry_policy.is_retriable_status(status) || api_error.is_insufficient_quota() {
+            return Err(Error::ApiError(Box::new(api_error)));
+          }
+          self.retry_policy.delay(attempts, retry_after)
+        },
+        Err(error) => {
+          if attempts >= max_attempts || !self.retry_policy.is_retriable_error(&error) {
+            return Err(Error::TransportError {source: error, attempts});
+          }
+          self.retry_policy.delay(attempts, None)
+        },
+      };
+      tokio::time::sleep(retry_delay).await;
     }
   }
+
+  /// Set the credential header of the request. The header of the credential provider is used when it is
+  /// set. Otherwise, API Key is required for Azure and OpenAI, and no credential is sent for Azure AD.
+  async fn authenticate(&self, request_builder: RequestBuilder) -> Result<RequestBuilder, Error> {
+    let request_builder: RequestBuilder = match self.auth.get_credential_header().await? {
+      Some(credential_header) => request_builder.header(credential_header.name, credential_header.value),
+      None => match self.api_type {
+        ApiType::Azure => request_builder.header("api-key", self.get_api_key()),
+        ApiType::OpenAI => request_builder.bearer_auth(self.get_api_key()),
+        ApiType::AzureAD => request_builder,
+      },
+    };
+    Ok(request_builder)
+  }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -44,9 +155,13 @@ impl Requestor for Client {
 
 #[cfg(test)]
 mod test {
+  use std::time::Instant;
   use wiremock::{MockServer, Mock, ResponseTemplate};
-  use wiremock::matchers::{method, path, bearer_token, header, body_string};
+  use wiremock::matchers::{method, path, bearer_token, header, body_string, query_param};
+  use crate::openai::{Auth, RetryPolicy};
   use crate::openai::auth::create_auth_with_given_api_key;
+  use crate::openai::credential::ManagedIdentityCredential;
+  use crate::openai::credential::client_secret_credential::{create_client_secret_credential, mount_token_endpoint};
   use super::*;
 
   #[tokio::test]
@@ -57,7 +172,7 @@ mod test {
 
     let mock_server = MockServer::start().await;
     Mock::given(method("POST"))
-      .and(path("/engines/chat/completions"))
+      .and(path("/chat/completions"))
       .and(bearer_token(&api_key))
       .and(header(reqwest::header::CONTENT_TYPE, "application/json"))
       .and(body_string(body_request.clone()))
@@ -70,7 +185,7 @@ mod test {
 
     let auth = create_auth_with_given_api_key(&api_key);
     let openai_client = Client::new(auth, &mock_server.uri(), ApiType::OpenAI);
-    let response = openai_client.post("chat/completions", &body_request, None, None).await;
+    let response = openai_client.post(Operation::ChatCompletions, &body_request, None, None).await;
     assert!(response.is_ok());
     // let response_as_string = response.unwrap();
     // assert_eq!(response_as_string, body_response);
@@ -99,7 +214,7 @@ mod test {
     
     let auth = create_auth_with_given_api_key(&api_key);
     let openai_client = Client::new(auth, &mock_server.uri(), ApiType::Azure);
-    let response = openai_client.post("chat/completions", &body_request, Some(&azure_model_id), Some(&azure_api_version)).await;
+    let response = openai_client.post(Operation::ChatCompletions, &body_request, Some(&azure_model_id), Some(&azure_api_version)).await;
     assert!(response.is_ok());
     // let response_as_string = response.unwrap();
     // assert_eq!(response_as_string, body_response);
@@ -127,16 +242,266 @@ mod test {
     
     let auth = create_auth_with_given_api_key(&api_key);
     let openai_client = Client::new(auth, &mock_server.uri(), ApiType::AzureAD);
-    let response = openai_client.post("chat/completions", &body_request, Some(&azure_model_id), Some(&azure_api_version)).await;
+    let response = openai_client.post(Operation::ChatCompletions, &body_request, Some(&azure_model_id), Some(&azure_api_version)).await;
     assert!(response.is_ok());
     // let response_as_string = response.unwrap();
     // assert_eq!(response_as_string, body_response);
     
     let requests = mock_server.received_requests().await.unwrap();
     assert_eq!(requests.len(), 1);
-    let request = requests.get(0).unwrap();
+    let request = requests.first().unwrap();
     for header in request.headers.iter() {
       assert_ne!(header.0.as_str(), "api-key");
     }
   }
+
+  #[tokio::test]
+  async fn it_should_post_to_azure_open_api_using_azure_ad_access_token() {
+    let azure_api_version: String = String::from("2023-05-15");
+    let azure_model_id: String = String::from("model-deployment-id");
+    let body_request: String = String::from(r#"{"prompt": "Once upon a time", "max_tokens": 5}"#);
+
+    let mock_server = MockServer::start().await;
+    mount_token_endpoint(&mock_server, "access-token", 3600, 1).await;
+    Mock::given(method("POST"))
+      .and(path("/openai/deployments/model-deployment-id/chat/completions"))
+      .and(bearer_token("access-token"))
+      .and(body_string(body_request.clone()))
+      .respond_with(ResponseTemplate::new(200))
+      .expect(2)
+      .mount(&mock_server)
+      .await;
+
+    let auth = Auth::from_azure_ad_credential(create_client_secret_credential(&mock_server.uri()));
+    let openai_client = Client::new(auth, &mock_server.uri(), ApiType::AzureAD);
+    let response = openai_client.post(Operation::ChatCompletions, &body_request, Some(&azure_model_id), Some(&azure_api_version)).await;
+    assert!(response.is_ok());
+    let response = openai_client.post(Operation::ChatCompletions, &body_request, Some(&azure_model_id), Some(&azure_api_version)).await;
+    assert!(response.is_ok());
+  }
+
+  #[tokio::test]
+  async fn it_should_post_to_azure_open_api_using_managed_identity_access_token() {
+    let azure_model_id: String = String::from("model-deployment-id");
+    let body_request: String = String::from(r#"{"prompt": "Once upon a time", "max_tokens": 5}"#);
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/metadata/identity/oauth2/token"))
+      .and(header("Metadata", "true"))
+      .respond_with(ResponseTemplate::new(200)
+        .set_body_json(serde_json::json!({"access_token": "managed-identity-token", "expires_in": "3599"}))
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("POST"))
+      .and(path("/openai/deployments/model-deployment-id/chat/completions"))
+      .and(bearer_token("managed-identity-token"))
+      .respond_with(ResponseTemplate::new(200))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let credential = ManagedIdentityCredential::new().endpoint(&format!("{}/metadata/identity/oauth2/token", mock_server.uri()));
+    let openai_client = Client::new(Auth::from_managed_identity_credential(credential), &mock_server.uri(), ApiType::AzureAD);
+    let response = openai_client.post(Operation::ChatCompletions, &body_request, Some(&azure_model_id), None).await;
+    assert!(response.is_ok());
+  }
+
+  fn create_client_with_retry_policy(api_endpoint: &str, max_attempts: u32) -> Client {
+    let retry_policy: RetryPolicy = RetryPolicy::new()
+      .max_attempts(max_attempts)
+      .base_delay(Duration::from_millis(10));
+    Client::new(create_auth_with_given_api_key("12345abcd"), api_endpoint, ApiType::OpenAI)
+      .retry_policy(retry_policy)
+  }
+
+  #[tokio::test]
+  async fn it_should_retry_after_the_delay_given_by_the_retry_after_ms_header() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .respond_with(ResponseTemplate::new(429)
+        .insert_header("retry-after-ms", "200")
+      )
+      .up_to_n_times(1)
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("POST"))
+      .respond_with(ResponseTemplate::new(200))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let openai_client = create_client_with_retry_policy(&mock_server.uri(), 3);
+    let start: Instant = Instant::now();
+    let response = openai_client.post(Operation::ChatCompletions, "{}", None, None).await;
+    assert!(response.is_ok());
+    assert!(start.elapsed() >= Duration::from_millis(200));
+  }
+
+  #[tokio::test]
+  async fn it_should_return_the_attempts_when_the_retries_are_exhausted() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .respond_with(ResponseTemplate::new(503)
+        .set_body_string("Service unavailable")
+      )
+      .expect(3)
+      .mount(&mock_server)
+      .await;
+
+    let openai_client = create_client_with_retry_policy(&mock_server.uri(), 3);
+    let error = openai_client.post(Operation::ChatCompletions, "{}", None, None).await.unwrap_err();
+    assert_eq!(error.api_error(), Some(&ApiError::from_response(503, "Service unavailable", 3)));
+  }
+
+  #[tokio::test]
+  async fn it_should_not_retry_when_the_status_is_not_retriable() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .respond_with(ResponseTemplate::new(400)
+        .set_body_string("Bad request")
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let openai_client = create_client_with_retry_policy(&mock_server.uri(), 3);
+    let error = openai_client.post(Operation::ChatCompletions, "{}", None, None).await.unwrap_err();
+    assert_eq!(error.api_error(), Some(&ApiError::from_response(400, "Bad request", 1)));
+  }
+
+  #[tokio::test]
+  async fn it_should_not_retry_when_the_quota_is_exceeded() {
+    let body_response: &str = r#"{"error": {"message": "You exceeded your current quota", "type": "insufficient_quota", "param": null, "code": "insufficient_quota"}}"#;
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .respond_with(ResponseTemplate::new(429)
+        .set_body_string(body_response)
+      )
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let openai_client = create_client_with_retry_policy(&mock_server.uri(), 3);
+    let error = openai_client.post(Operation::ChatCompletions, "{}", None, None).await.unwrap_err();
+    assert!(error.is_insufficient_quota());
+    assert_eq!(error.api_error().unwrap().attempts, 1);
+    assert_eq!(error.to_string(), "(HTTP 429) insufficient_quota: You exceeded your current quota [Attempts: 1]");
+  }
+
+  #[tokio::test]
+  async fn it_should_retry_on_connection_errors() {
+    // Nothing listens on the port 1, so the connection is refused
+    let openai_client = create_client_with_retry_policy("http://127.0.0.1:1", 2);
+    let error = openai_client.post(Operation::ChatCompletions, "{}", None, None).await.unwrap_err();
+    assert!(matches!(error, Error::TransportError {attempts: 2, ..}));
+  }
+
+  #[tokio::test]
+  async fn it_should_get_from_azure_open_api_without_body() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/openai/models"))
+      .and(query_param("api-version", "2024-10-21"))
+      .and(header("api-key", "12345abcd"))
+      .respond_with(ResponseTemplate::new(200))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let openai_client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let response = openai_client.get(Operation::Models, &[], None, Some("2024-10-21")).await;
+    assert!(response.is_ok());
+    let requests = mock_server.received_requests().await.unwrap();
+    assert!(requests[0].body.is_empty());
+    assert!(!requests[0].headers.contains_key(&wiremock::http::HeaderName::from("content-type")));
+  }
+
+  #[tokio::test]
+  async fn it_should_delete_from_openai_api() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+      .and(path("/models/ft:gpt-4o-mini:org:custom:id"))
+      .and(bearer_token("12345abcd"))
+      .respond_with(ResponseTemplate::new(200))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let openai_client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let response = openai_client.delete(Operation::Model("ft:gpt-4o-mini:org:custom:id"), None, None).await;
+    assert!(response.is_ok());
+  }
+
+  #[tokio::test]
+  async fn it_should_get_with_query_parameters_and_api_version() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .and(path("/openai/files"))
+      .and(query_param("api-version", "2024-10-21"))
+      .and(query_param("purpose", "fine-tune"))
+      .and(query_param("limit", "10"))
+      .respond_with(ResponseTemplate::new(200))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let openai_client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::Azure);
+    let query: Vec<(&str, String)> = vec![("purpose", String::from("fine-tune")), ("limit", 10.to_string())];
+    let response = openai_client.get(Operation::Files, &query, None, Some("2024-10-21")).await;
+    assert!(response.is_ok());
+  }
+
+  #[tokio::test]
+  async fn it_should_post_multipart_form_and_send_it_again_when_retried() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/files"))
+      .respond_with(ResponseTemplate::new(503))
+      .up_to_n_times(1)
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+    Mock::given(method("POST"))
+      .and(path("/files"))
+      .and(bearer_token("12345abcd"))
+      .respond_with(ResponseTemplate::new(200))
+      .expect(1)
+      .mount(&mock_server)
+      .await;
+
+    let form: MultipartForm = MultipartForm::new()
+      .text("purpose", "fine-tune")
+      .file("file", "training.jsonl", b"{\"prompt\": \"Hello\"}".to_vec(), Some("application/jsonl"));
+    let openai_client = create_client_with_retry_policy(&mock_server.uri(), 2);
+    let response = openai_client.post_multipart(Operation::Files, &form, None, None).await;
+    assert!(response.is_ok());
+
+    let requests = mock_server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 2);
+    for request in requests {
+      let content_type: String = request.headers.get(&wiremock::http::HeaderName::from("content-type")).unwrap().last().to_string();
+      assert!(content_type.starts_with("multipart/form-data; boundary="));
+      let body: String = String::from_utf8(request.body).unwrap();
+      assert!(body.contains("name=\"purpose\"\r\n\r\nfine-tune"));
+      assert!(body.contains("name=\"file\"; filename=\"training.jsonl\"\r\nContent-Type: application/jsonl\r\n\r\n{\"prompt\": \"Hello\"}"));
+    }
+  }
+
+  #[tokio::test]
+  async fn it_should_return_a_serialization_error_when_the_response_cannot_be_decoded() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+      .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"object": "list"}"#))
+      .mount(&mock_server)
+      .await;
+
+    let openai_client = Client::new(create_auth_with_given_api_key("12345abcd"), &mock_server.uri(), ApiType::OpenAI);
+    let response = openai_client.get(Operation::Models, &[], None, None).await.unwrap();
+    let result = decode_json::<crate::openai::models::model::ModelList>(response).await;
+    assert!(matches!(result, Err(Error::SerializationError(_))));
+  }
 }
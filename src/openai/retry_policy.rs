@@ -0,0 +1,229 @@
+use std::time::{Duration, SystemTime};
+
+use rand::Rng;
+use reqwest::header::HeaderMap;
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(500);
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(60);
+const DEFAULT_JITTER: f64 = 0.25;
+const DEFAULT_RETRIABLE_STATUS_CODES: [u16; 6] = [408, 429, 500, 502, 503, 504];
+
+/// Policy used by the requestor to retry the failed requests with an
+/// exponential backoff. The delay before the next attempt is given by the
+/// `retry-after-ms` or `Retry-After` header of the response when it is set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+  max_attempts: u32,
+  base_delay: Duration,
+  max_delay: Duration,
+  jitter: f64,
+  retriable_status_codes: Vec<u16>,
+  retry_on_connection_errors: bool,
+  retry_on_timeouts: bool,
+}
+
+impl RetryPolicy {
+  /// Create the default policy: 3 attempts, delay starting at 500 ms and capped
+  /// at 60 s with 25% of jitter, retry on HTTP 408, 429, 500, 502, 503 and 504,
+  /// on connection errors and on timeouts.
+  pub fn new() -> RetryPolicy {
+    RetryPolicy {
+      max_attempts: DEFAULT_MAX_ATTEMPTS,
+      base_delay: DEFAULT_BASE_DELAY,
+      max_delay: DEFAULT_MAX_DELAY,
+      jitter: DEFAULT_JITTER,
+      retriable_status_codes: DEFAULT_RETRIABLE_STATUS_CODES.to_vec(),
+      retry_on_connection_errors: true,
+      retry_on_timeouts: true,
+    }
+  }
+
+  /// Create a policy that never retries.
+  pub fn no_retry() -> RetryPolicy {
+    RetryPolicy::new().max_attempts(1)
+  }
+
+  /// Set the maximum number of attempts, including the first one. A value of 0
+  /// is handled as 1.
+  pub fn max_attempts(mut self, max_attempts: u32) -> RetryPolicy {
+    self.max_attempts = max_attempts.max(1);
+    self
+  }
+
+  /// Set the delay before the first retry. The delay is doubled at each retry.
+  pub fn base_delay(mut self, base_delay: Duration) -> RetryPolicy {
+    self.base_delay = base_delay;
+    self
+  }
+
+  /// Set the maximum delay between two attempts. It also caps the delay
+  /// requested by the `Retry-After` headers.
+  pub fn max_delay(mut self, max_delay: Duration) -> RetryPolicy {
+    self.max_delay = max_delay;
+    self
+  }
+
+  /// Set the jitter as the fraction of the delay that is randomly removed. The
+  /// value is clamped between 0.0 and 1.0.
+  pub fn jitter(mut self, jitter: f64) -> RetryPolicy {
+    self.jitter = jitter.clamp(0.0, 1.0);
+    self
+  }
+
+  pub fn retriable_status_codes(mut self, retriable_status_codes: Vec<u16>) -> RetryPolicy {
+    self.retriable_status_codes = retriable_status_codes;
+    self
+  }
+
+  pub fn retry_on_connection_errors(mut self, retry_on_connection_errors: bool) -> RetryPolicy {
+    self.retry_on_connection_errors = retry_on_connection_errors;
+    self
+  }
+
+  pub fn retry_on_timeouts(mut self, retry_on_timeouts: bool) -> RetryPolicy {
+    self.retry_on_timeouts = retry_on_timeouts;
+    self
+  }
+
+  pub fn get_max_attempts(&self) -> u32 {
+    self.max_attempts
+  }
+
+  pub(crate) fn is_retriable_status(&self, status: u16) -> bool {
+    self.retriable_status_codes.contains(&status)
+  }
+
+  pub(crate) fn is_retriable_error(&self, error: &reqwest::Error) -> bool {
+    (self.retry_on_connection_errors && error.is_connect()) || (self.retry_on_timeouts && error.is_timeout())
+  }
+
+  /// Get the delay before the next attempt after the given failed attempt
+  /// (starting at 1). The delay requested by the server takes precedence over
+  /// the exponential backoff.
+  pub(crate) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+    if let Some(retry_after) = retry_after {
+      return retry_after.min(self.max_delay);
+    }
+    let exponent: u32 = attempt.saturating_sub(1).min(31);
+    let delay: Duration = self.base_delay.saturating_mul(2_u32.pow(exponent)).min(self.max_delay);
+    if self.jitter > 0.0 {
+      delay.mul_f64(1.0 - rand::thread_rng().gen_range(0.0..=self.jitter))
+    } else {
+      delay
+    }
+  }
+}
+
+impl Default for RetryPolicy {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Get the delay requested by the server with the `retry-after-ms` header sent
+/// by Azure OpenAI or with the standard `Retry-After` header, given in seconds
+/// or as an HTTP date.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+  let header_value = |name: &str| headers.get(name).and_then(|value| value.to_str().ok()).map(|value| value.trim());
+  if let Some(retry_after_ms) = header_value("retry-after-ms").and_then(|value| value.parse::<f64>().ok()) {
+    if retry_after_ms.is_finite() && retry_after_ms >= 0.0 {
+      // A delay too large for a duration is saturated, it is capped by the max delay anyway
+      return Some(Duration::try_from_secs_f64(retry_after_ms / 1000.0).unwrap_or(Duration::MAX));
+    }
+  }
+  let retry_after: &str = header_value(reqwest::header::RETRY_AFTER.as_str())?;
+  if let Ok(seconds) = retry_after.parse::<u64>() {
+    return Some(Duration::from_secs(seconds));
+  }
+  let date: SystemTime = httpdate::parse_http_date(retry_after).ok()?;
+  Some(date.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    TESTS                                   */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+  use reqwest::header::HeaderValue;
+  use super::*;
+
+  #[test]
+  fn it_should_double_the_delay_at_each_attempt_up_to_the_max_delay() {
+    let retry_policy: RetryPolicy = RetryPolicy::new()
+      .base_delay(Duration::from_secs(1))
+      .max_delay(Duration::from_secs(5))
+      .jitter(0.0);
+    assert_eq!(retry_policy.delay(1, None), Duration::from_secs(1));
+    assert_eq!(retry_policy.delay(2, None), Duration::from_secs(2));
+    assert_eq!(retry_policy.delay(3, None), Duration::from_secs(4));
+    assert_eq!(retry_policy.delay(4, None), Duration::from_secs(5));
+    assert_eq!(retry_policy.delay(100, None), Duration::from_secs(5));
+  }
+
+  #[test]
+  fn it_should_apply_jitter_to_the_delay() {
+    let retry_policy: RetryPolicy = RetryPolicy::new()
+      .base_delay(Duration::from_secs(4))
+      .jitter(0.5);
+    for _ in 0..100 {
+      let delay: Duration = retry_policy.delay(1, None);
+      assert!(delay >= Duration::from_secs(2) && delay <= Duration::from_secs(4));
+    }
+  }
+
+  #[test]
+  fn it_should_use_the_retry_after_delay_capped_by_the_max_delay() {
+    let retry_policy: RetryPolicy = RetryPolicy::new().max_delay(Duration::from_secs(10));
+    assert_eq!(retry_policy.delay(1, Some(Duration::from_secs(3))), Duration::from_secs(3));
+    assert_eq!(retry_policy.delay(1, Some(Duration::from_secs(30))), Duration::from_secs(10));
+  }
+
+  #[test]
+  fn it_should_use_at_least_one_attempt() {
+    assert_eq!(RetryPolicy::new().max_attempts(0).get_max_attempts(), 1);
+    assert_eq!(RetryPolicy::no_retry().get_max_attempts(), 1);
+  }
+
+  #[test]
+  fn it_should_read_the_retry_after_ms_header_first() {
+    let mut headers: HeaderMap = HeaderMap::new();
+    headers.insert("retry-after-ms", HeaderValue::from_static("1500"));
+    headers.insert("retry-after", HeaderValue::from_static("2"));
+    assert_eq!(retry_after(&headers), Some(Duration::from_millis(1500)));
+  }
+
+  #[test]
+  fn it_should_saturate_a_retry_after_ms_header_too_large_for_a_duration() {
+    let mut headers: HeaderMap = HeaderMap::new();
+    headers.insert("retry-after-ms", HeaderValue::from_static("1e23"));
+    assert_eq!(retry_after(&headers), Some(Duration::MAX));
+    let retry_policy: RetryPolicy = RetryPolicy::new().max_delay(Duration::from_secs(60));
+    assert_eq!(retry_policy.delay(1, retry_after(&headers)), Duration::from_secs(60));
+  }
+
+  #[test]
+  fn it_should_read_the_retry_after_header_in_seconds() {
+    let mut headers: HeaderMap = HeaderMap::new();
+    headers.insert("retry-after", HeaderValue::from_static("2"));
+    assert_eq!(retry_after(&headers), Some(Duration::from_secs(2)));
+  }
+
+  #[test]
+  fn it_should_read_the_retry_after_header_as_http_date() {
+    let date: String = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(30));
+    let mut headers: HeaderMap = HeaderMap::new();
+    headers.insert("retry-after", HeaderValue::from_str(&date).unwrap());
+    let delay: Duration = retry_after(&headers).unwrap();
+    assert!(delay > Duration::from_secs(25) && delay <= Duration::from_secs(30));
+  }
+
+  #[test]
+  fn it_should_not_return_a_delay_without_valid_retry_after_header() {
+    let mut headers: HeaderMap = HeaderMap::new();
+    assert_eq!(retry_after(&headers), None);
+    headers.insert("retry-after", HeaderValue::from_static("soon"));
+    assert_eq!(retry_after(&headers), None);
+  }
+}